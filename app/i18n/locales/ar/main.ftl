@@ -60,7 +60,7 @@ license = الترخيص
 custom-license = ترخيص مخصص
 subject-icon = أيقونة { $icon }
 copy-icon-svg = نسخ SVG لـ { $icon }
-error-generating-pdf = خطأ في إنشاء PDF باستخدام مكتبة PDFKit:
+error-generating-pdf = خطأ في إنشاء PDF:
 view-icon = عرض { $icon }
 copy-hex-color = نسخ اللون السداسي عشري
 discord = Discord
@@ -93,3 +93,85 @@ libraries = المكتبات
 copy-icon-modal-url = نسخ رابط نافذة الأيقونة
 copy-icon-url = نسخ رابط الأيقونة
 copy-icon-svg-path = نسخ مسار SVG للأيقونة
+no-icons-found = لم يتم العثور على أيقونات لـ "{ $search }"
+did-you-mean = هل تقصد:
+submit-a-request-for = أرسل طلبًا لـ { $brand }
+select-icons = تحديد الأيقونات
+n-selected-icons = { $n } محددة
+select-all-results = تحديد كل النتائج
+clear-selection = مسح التحديد
+download-zip = تنزيل ZIP
+select-icon = تحديد { $icon }
+favorites = المفضلة
+add-to-favorites = إضافة { $icon } إلى المفضلة
+remove-from-favorites = إزالة { $icon } من المفضلة
+no-favorites = لا توجد أيقونات مفضلة بعد. استخدم زر النجمة في الأيقونات لإضافتها هنا.
+collection-of-n-icons = مجموعة من { $n } أيقونات
+n-collection-icons-not-found = لم يتم العثور على { $n } من أيقونات المجموعة
+invalid-collection = رابط المجموعة غير صالح، يتم عرض جميع الأيقونات.
+download-all = تنزيل الكل
+show-all-icons = عرض جميع الأيقونات
+copy-collection-link = نسخ رابط المجموعة
+filters = عوامل التصفية
+has-brand-guidelines = لها إرشادات العلامة التجارية
+has-license = لها ترخيص
+license-type = الترخيص: { $type }
+has-localized-names = لها أسماء مترجمة
+clear-filters = مسح عوامل التصفية
+list = قائمة
+preview = معاينة
+slug = المعرّف
+monochrome = أحادي اللون
+brand-color-background = على لون العلامة التجارية
+brand-color-fill = بلون العلامة التجارية
+also-known-as = يُعرف أيضًا باسم
+old-names = الأسماء السابقة
+localized-names = الأسماء المترجمة
+brands-sharing-the-icon = علامات تجارية تشترك في هذه الأيقونة
+details = التفاصيل
+snippets = مقتطفات الشيفرة
+copy-snippet = نسخ المقتطف
+export = تصدير
+size = الحجم
+fill = التعبئة
+background = الخلفية
+padding = الحشو
+brand-color = لون العلامة التجارية
+black = أسود
+white = أبيض
+custom-color = لون مخصص
+transparent = شفاف
+solid-color = لون خالص
+webp = WebP
+favicon-pack = حزمة أيقونات المواقع
+download-svg-sprite = تنزيل SVG sprite
+vector-drawable = Android VectorDrawable
+xaml = XAML
+eps = EPS
+template = القالب
+pull-request-card = بطاقة طلب الدمج
+open-graph-card = بطاقة Open Graph
+square-card = بطاقة مربعة
+dark-light-card = بطاقة المقارنة بين الداكن والفاتح
+badge-label = التسمية
+badge-message = الرسالة
+badge-style = النمط
+copy-shields-url = نسخ رابط shields.io
+compare = مقارنة
+no-comparison = بدون
+overlay-comparison = تراكب
+side-by-side-comparison = جنبًا إلى جنب
+current-path = الحالي
+edited-path = المعدّل
+added-areas = المضاف
+removed-areas = المحذوف
+current-path-not-found = تعذّر تحميل الأيقونة الحالية للعلامة التجارية
+path-size-change = الحجم: { $before } ← { $after } بايت ({ $difference })
+path-bbox-change = المربع المحيط: { $before } ← { $after }
+undo = تراجع
+redo = إعادة
+history = السجل
+initial-state = الحالة الأولية
+brand-changed = العلامة التجارية: { $before } ← { $after }
+color-changed = اللون: #{ $before } ← #{ $after }
+path-changed = المسار: { $before } ← { $after } بايت
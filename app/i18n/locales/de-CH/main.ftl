@@ -60,7 +60,7 @@ license = Lizenz
 custom-license = Benutzerdefinierte Lizenz
 subject-icon = { $icon } Icon
 copy-icon-svg = { $icon } SVG kopieren
-error-generating-pdf = Fehler beim PDF erstellen mit PDFKit Bibliothek:
+error-generating-pdf = Fehler beim PDF erstellen:
 view-icon = { $icon } anzeigen
 copy-hex-color = HEX Farbe kopieren
 discord = Discord
@@ -93,3 +93,85 @@ libraries = Bibliotheken
 copy-icon-modal-url = Icon Modal URL kopieren
 copy-icon-url = Icon URL kopieren
 copy-icon-svg-path = Icon SVG Pfad kopieren
+no-icons-found = Keine Icons für "{ $search }" gefunden
+did-you-mean = Meinten Sie:
+submit-a-request-for = Anfrage für { $brand } einreichen
+select-icons = Icons auswählen
+n-selected-icons = { $n } ausgewählt
+select-all-results = Alle Ergebnisse auswählen
+clear-selection = Auswahl aufheben
+download-zip = ZIP herunterladen
+select-icon = { $icon } auswählen
+favorites = Favoriten
+add-to-favorites = { $icon } zu den Favoriten hinzufügen
+remove-from-favorites = { $icon } aus den Favoriten entfernen
+no-favorites = Noch keine Favoriten. Verwende den Stern-Button der Icons, um sie hier hinzuzufügen.
+collection-of-n-icons = Sammlung mit { $n } Icons
+n-collection-icons-not-found = { $n } Icons der Sammlung wurden nicht gefunden
+invalid-collection = Der Sammlungslink ist ungültig, es werden alle Icons angezeigt.
+download-all = Alle herunterladen
+show-all-icons = Alle Icons anzeigen
+copy-collection-link = Sammlungslink kopieren
+filters = Filter
+has-brand-guidelines = Mit Markenrichtlinien
+has-license = Mit Lizenz
+license-type = Lizenz: { $type }
+has-localized-names = Mit lokalisierten Namen
+clear-filters = Filter zurücksetzen
+list = Liste
+preview = Vorschau
+slug = Slug
+monochrome = Einfarbig
+brand-color-background = Auf Markenfarbe
+brand-color-fill = In Markenfarbe
+also-known-as = Auch bekannt als
+old-names = Frühere Namen
+localized-names = Lokalisierte Namen
+brands-sharing-the-icon = Marken mit demselben Symbol
+details = Details
+snippets = Code-Schnipsel
+copy-snippet = Schnipsel kopieren
+export = Exportieren
+size = Grösse
+fill = Füllung
+background = Hintergrund
+padding = Innenabstand
+brand-color = Markenfarbe
+black = Schwarz
+white = Weiss
+custom-color = Eigene Farbe
+transparent = Transparent
+solid-color = Volltonfarbe
+webp = WebP
+favicon-pack = Favicon-Paket
+download-svg-sprite = SVG-Sprite herunterladen
+vector-drawable = Android-VectorDrawable
+xaml = XAML
+eps = EPS
+template = Vorlage
+pull-request-card = Pull-Request-Karte
+open-graph-card = Open-Graph-Karte
+square-card = Quadratische Karte
+dark-light-card = Vergleichskarte dunkel/hell
+badge-label = Beschriftung
+badge-message = Nachricht
+badge-style = Stil
+copy-shields-url = shields.io-URL kopieren
+compare = Vergleichen
+no-comparison = Keiner
+overlay-comparison = Überlagert
+side-by-side-comparison = Nebeneinander
+current-path = Aktuell
+edited-path = Bearbeitet
+added-areas = Hinzugefügt
+removed-areas = Entfernt
+current-path-not-found = Das aktuelle Symbol der Marke kann nicht geladen werden
+path-size-change = Grösse: { $before } → { $after } Bytes ({ $difference })
+path-bbox-change = Begrenzungsrahmen: { $before } → { $after }
+undo = Rückgängig
+redo = Wiederholen
+history = Verlauf
+initial-state = Ausgangszustand
+brand-changed = Marke: { $before } → { $after }
+color-changed = Farbe: #{ $before } → #{ $after }
+path-changed = Pfad: { $before } → { $after } Bytes
@@ -60,7 +60,7 @@ license = Άδεια
 custom-license = Προσαρμοσμένη άδεια
 subject-icon = Εικονίδιο { $icon }
 copy-icon-svg = Αντιγραφή SVG { $icon }
-error-generating-pdf = Σφάλμα κατά τη δημιουργία PDF με τη βιβλιοθήκη PDFKit:
+error-generating-pdf = Σφάλμα κατά τη δημιουργία PDF:
 view-icon = Προβολή { $icon }
 copy-hex-color = Αντιγραφή δεκαεξαδικού χρώματος
 discord = Discord
@@ -93,3 +93,85 @@ libraries = Βιβλιοθήκες
 copy-icon-modal-url = Αντιγραφή URL παραθύρου εικονιδίου
 copy-icon-url = Αντιγραφή URL εικονιδίου
 copy-icon-svg-path = Αντιγραφή διαδρομής SVG εικονιδίου
+no-icons-found = Δεν βρέθηκαν εικονίδια για "{ $search }"
+did-you-mean = Μήπως εννοούσατε:
+submit-a-request-for = Υποβολή αιτήματος για { $brand }
+select-icons = Επιλογή εικονιδίων
+n-selected-icons = { $n } επιλεγμένα
+select-all-results = Επιλογή όλων των αποτελεσμάτων
+clear-selection = Εκκαθάριση επιλογής
+download-zip = Λήψη ZIP
+select-icon = Επιλογή { $icon }
+favorites = Αγαπημένα
+add-to-favorites = Προσθήκη του { $icon } στα αγαπημένα
+remove-from-favorites = Αφαίρεση του { $icon } από τα αγαπημένα
+no-favorites = Δεν υπάρχουν ακόμη αγαπημένα. Χρησιμοποιήστε το κουμπί με το αστέρι των εικονιδίων για να τα προσθέσετε εδώ.
+collection-of-n-icons = Συλλογή { $n } εικονιδίων
+n-collection-icons-not-found = Δεν βρέθηκαν { $n } εικονίδια της συλλογής
+invalid-collection = Ο σύνδεσμος της συλλογής δεν είναι έγκυρος, εμφανίζονται όλα τα εικονίδια.
+download-all = Λήψη όλων
+show-all-icons = Εμφάνιση όλων των εικονιδίων
+copy-collection-link = Αντιγραφή συνδέσμου συλλογής
+filters = Φίλτρα
+has-brand-guidelines = Με οδηγίες μάρκας
+has-license = Με άδεια
+license-type = Άδεια: { $type }
+has-localized-names = Με τοπικά ονόματα
+clear-filters = Εκκαθάριση φίλτρων
+list = Λίστα
+preview = Προεπισκόπηση
+slug = Slug
+monochrome = Μονόχρωμο
+brand-color-background = Σε χρώμα μάρκας
+brand-color-fill = Σε χρώμα μάρκας
+also-known-as = Γνωστό και ως
+old-names = Παλιά ονόματα
+localized-names = Τοπικά ονόματα
+brands-sharing-the-icon = Μάρκες που μοιράζονται το εικονίδιο
+details = Λεπτομέρειες
+snippets = Αποσπάσματα κώδικα
+copy-snippet = Αντιγραφή αποσπάσματος
+export = Εξαγωγή
+size = Μέγεθος
+fill = Γέμισμα
+background = Φόντο
+padding = Περιθώριο
+brand-color = Χρώμα μάρκας
+black = Μαύρο
+white = Λευκό
+custom-color = Προσαρμοσμένο χρώμα
+transparent = Διαφανές
+solid-color = Συμπαγές χρώμα
+webp = WebP
+favicon-pack = πακέτο favicon
+download-svg-sprite = Λήψη SVG sprite
+vector-drawable = Android VectorDrawable
+xaml = XAML
+eps = EPS
+template = Πρότυπο
+pull-request-card = Κάρτα pull request
+open-graph-card = Κάρτα Open Graph
+square-card = Τετράγωνη κάρτα
+dark-light-card = Κάρτα σύγκρισης σκούρου/ανοιχτού
+badge-label = Ετικέτα
+badge-message = Μήνυμα
+badge-style = Στυλ
+copy-shields-url = Αντιγραφή URL του shields.io
+compare = Σύγκριση
+no-comparison = Καμία
+overlay-comparison = Επικάλυψη
+side-by-side-comparison = Δίπλα δίπλα
+current-path = Τρέχον
+edited-path = Επεξεργασμένο
+added-areas = Προστέθηκαν
+removed-areas = Αφαιρέθηκαν
+current-path-not-found = Δεν είναι δυνατή η φόρτωση του τρέχοντος εικονιδίου της μάρκας
+path-size-change = Μέγεθος: { $before } → { $after } bytes ({ $difference })
+path-bbox-change = Πλαίσιο οριοθέτησης: { $before } → { $after }
+undo = Αναίρεση
+redo = Ακύρωση αναίρεσης
+history = Ιστορικό
+initial-state = Αρχική κατάσταση
+brand-changed = Μάρκα: { $before } → { $after }
+color-changed = Χρώμα: #{ $before } → #{ $after }
+path-changed = Διαδρομή: { $before } → { $after } bytes
@@ -60,7 +60,7 @@ license = License
 custom-license = Custom license
 subject-icon = { $icon } icon
 copy-icon-svg = Copy { $icon } SVG
-error-generating-pdf = Error generating PDF with PDFKit library:
+error-generating-pdf = Error generating PDF:
 view-icon = View { $icon }
 copy-hex-color = Copy hex color
 discord = Discord
@@ -93,3 +93,85 @@ libraries = Libraries
 copy-icon-modal-url = Copy icon modal URL
 copy-icon-url = Copy icon URL
 copy-icon-svg-path = Copy icon SVG path
+no-icons-found = No icons found for "{ $search }"
+did-you-mean = Did you mean:
+submit-a-request-for = Submit a request for { $brand }
+select-icons = Select icons
+n-selected-icons = { $n } selected
+select-all-results = Select all results
+clear-selection = Clear selection
+download-zip = Download ZIP
+select-icon = Select { $icon }
+favorites = Favorites
+add-to-favorites = Add { $icon } to favorites
+remove-from-favorites = Remove { $icon } from favorites
+no-favorites = No favorites yet. Use the star button of the icons to add them here.
+collection-of-n-icons = Collection of { $n } icons
+n-collection-icons-not-found = { $n } icons of the collection were not found
+invalid-collection = The collection link is not valid, showing all the icons.
+download-all = Download all
+show-all-icons = Show all icons
+copy-collection-link = Copy collection link
+filters = Filters
+has-brand-guidelines = Has brand guidelines
+has-license = Has license
+license-type = License: { $type }
+has-localized-names = Has localized names
+clear-filters = Clear filters
+list = List
+preview = Preview
+slug = Slug
+monochrome = Monochrome
+brand-color-background = On brand color
+brand-color-fill = In brand color
+also-known-as = Also known as
+old-names = Old names
+localized-names = Localized names
+brands-sharing-the-icon = Brands sharing the icon
+details = Details
+snippets = Snippets
+copy-snippet = Copy snippet
+export = Export
+size = Size
+fill = Fill
+background = Background
+padding = Padding
+brand-color = Brand color
+black = Black
+white = White
+custom-color = Custom color
+transparent = Transparent
+solid-color = Solid color
+webp = WebP
+favicon-pack = favicon pack
+download-svg-sprite = Download SVG sprite
+vector-drawable = Android VectorDrawable
+xaml = XAML
+eps = EPS
+template = Template
+pull-request-card = Pull request card
+open-graph-card = Open Graph card
+square-card = Square card
+dark-light-card = Dark/light comparison card
+badge-label = Label
+badge-message = Message
+badge-style = Style
+copy-shields-url = Copy shields.io URL
+compare = Compare
+no-comparison = None
+overlay-comparison = Overlay
+side-by-side-comparison = Side by side
+current-path = Current
+edited-path = Edited
+added-areas = Added
+removed-areas = Removed
+current-path-not-found = The current icon of the brand can't be loaded
+path-size-change = Size: { $before } → { $after } bytes ({ $difference })
+path-bbox-change = Bounding box: { $before } → { $after }
+undo = Undo
+redo = Redo
+history = History
+initial-state = Initial state
+brand-changed = Brand: { $before } → { $after }
+color-changed = Color: #{ $before } → #{ $after }
+path-changed = Path: { $before } → { $after } bytes
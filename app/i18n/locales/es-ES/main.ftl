@@ -60,7 +60,7 @@ license = Licencia
 custom-license = Licencia personalizada
 subject-icon = Icono de { $icon }
 copy-icon-svg = Copiar SVG de { $icon }
-error-generating-pdf = Error generando PDF con la librería PDFKit:
+error-generating-pdf = Error generando PDF:
 view-icon = Ver { $icon }
 copy-hex-color = Copiar color hexadecimal
 discord = Discord
@@ -93,3 +93,85 @@ libraries = Bibliotecas
 copy-icon-modal-url = Copiar URL del modal de icono
 copy-icon-url = Copiar URL del icono
 copy-icon-svg-path = Copiar path SVG del icono
+no-icons-found = No se encontraron iconos para "{ $search }"
+did-you-mean = ¿Quisiste decir?:
+submit-a-request-for = Envía una solicitud para { $brand }
+select-icons = Seleccionar iconos
+n-selected-icons = { $n } seleccionados
+select-all-results = Seleccionar todos los resultados
+clear-selection = Borrar selección
+download-zip = Descargar ZIP
+select-icon = Seleccionar { $icon }
+favorites = Favoritos
+add-to-favorites = Añadir { $icon } a favoritos
+remove-from-favorites = Quitar { $icon } de favoritos
+no-favorites = Aún no hay favoritos. Usa el botón de estrella de los iconos para añadirlos aquí.
+collection-of-n-icons = Colección de { $n } iconos
+n-collection-icons-not-found = No se encontraron { $n } iconos de la colección
+invalid-collection = El enlace de la colección no es válido, se muestran todos los iconos.
+download-all = Descargar todos
+show-all-icons = Mostrar todos los iconos
+copy-collection-link = Copiar enlace de la colección
+filters = Filtros
+has-brand-guidelines = Con guía de marca
+has-license = Con licencia
+license-type = Licencia: { $type }
+has-localized-names = Con nombres traducidos
+clear-filters = Borrar filtros
+list = Lista
+preview = Vista previa
+slug = Slug
+monochrome = Monocromo
+brand-color-background = Sobre el color de la marca
+brand-color-fill = Con el color de la marca
+also-known-as = También conocido como
+old-names = Nombres antiguos
+localized-names = Nombres localizados
+brands-sharing-the-icon = Marcas que comparten el icono
+details = Detalles
+snippets = Fragmentos
+copy-snippet = Copiar fragmento
+export = Exportar
+size = Tamaño
+fill = Relleno
+background = Fondo
+padding = Margen interior
+brand-color = Color de la marca
+black = Negro
+white = Blanco
+custom-color = Color personalizado
+transparent = Transparente
+solid-color = Color sólido
+webp = WebP
+favicon-pack = paquete de favicons
+download-svg-sprite = Descargar sprite SVG
+vector-drawable = VectorDrawable de Android
+xaml = XAML
+eps = EPS
+template = Plantilla
+pull-request-card = Tarjeta de pull request
+open-graph-card = Tarjeta Open Graph
+square-card = Tarjeta cuadrada
+dark-light-card = Tarjeta de comparación oscuro/claro
+badge-label = Etiqueta
+badge-message = Mensaje
+badge-style = Estilo
+copy-shields-url = Copiar URL de shields.io
+compare = Comparar
+no-comparison = Ninguna
+overlay-comparison = Superpuestos
+side-by-side-comparison = Lado a lado
+current-path = Actual
+edited-path = Editada
+added-areas = Añadido
+removed-areas = Eliminado
+current-path-not-found = No se puede cargar el icono actual de la marca
+path-size-change = Tamaño: { $before } → { $after } bytes ({ $difference })
+path-bbox-change = Caja delimitadora: { $before } → { $after }
+undo = Deshacer
+redo = Rehacer
+history = Historial
+initial-state = Estado inicial
+brand-changed = Marca: { $before } → { $after }
+color-changed = Color: #{ $before } → #{ $after }
+path-changed = Ruta: { $before } → { $after } bytes
@@ -60,7 +60,7 @@ license = Lisenssi
 custom-license = Mukautettu lisenssi
 subject-icon = { $icon } kuvake
 copy-icon-svg = Kopioi { $icon } SVG
-error-generating-pdf = Virhe PDF:n luomisessa PDFKit-kirjastolla:
+error-generating-pdf = Virhe PDF:n luomisessa:
 view-icon = Näytä { $icon }
 copy-hex-color = Kopioi heksamääräinen väri
 discord = Discord
@@ -93,3 +93,85 @@ libraries = Kirjastot
 copy-icon-modal-url = Kopioi kuvakkeen modaalin URL
 copy-icon-url = Kopioi kuvakkeen URL
 copy-icon-svg-path = Kopioi kuvakkeen SVG-polku
+no-icons-found = Kuvakkeita ei löytynyt haulla "{ $search }"
+did-you-mean = Tarkoititko:
+submit-a-request-for = Lähetä pyyntö kuvakkeesta { $brand }
+select-icons = Valitse kuvakkeita
+n-selected-icons = { $n } valittu
+select-all-results = Valitse kaikki tulokset
+clear-selection = Tyhjennä valinta
+download-zip = Lataa ZIP
+select-icon = Valitse { $icon }
+favorites = Suosikit
+add-to-favorites = Lisää { $icon } suosikkeihin
+remove-from-favorites = Poista { $icon } suosikeista
+no-favorites = Ei vielä suosikkeja. Lisää kuvakkeita tänne niiden tähtipainikkeella.
+collection-of-n-icons = { $n } kuvakkeen kokoelma
+n-collection-icons-not-found = Kokoelman { $n } kuvaketta ei löytynyt
+invalid-collection = Kokoelman linkki ei ole kelvollinen, näytetään kaikki kuvakkeet.
+download-all = Lataa kaikki
+show-all-icons = Näytä kaikki kuvakkeet
+copy-collection-link = Kopioi kokoelman linkki
+filters = Suodattimet
+has-brand-guidelines = Brändiohjeet
+has-license = Lisenssi
+license-type = Lisenssi: { $type }
+has-localized-names = Lokalisoidut nimet
+clear-filters = Tyhjennä suodattimet
+list = Luettelo
+preview = Esikatselu
+slug = Slug
+monochrome = Yksivärinen
+brand-color-background = Brändivärin päällä
+brand-color-fill = Brändivärillä
+also-known-as = Tunnetaan myös nimellä
+old-names = Vanhat nimet
+localized-names = Lokalisoidut nimet
+brands-sharing-the-icon = Samaa kuvaketta käyttävät brändit
+details = Tiedot
+snippets = Koodinpätkät
+copy-snippet = Kopioi koodinpätkä
+export = Vie
+size = Koko
+fill = Täyttö
+background = Tausta
+padding = Reunus
+brand-color = Brändin väri
+black = Musta
+white = Valkoinen
+custom-color = Mukautettu väri
+transparent = Läpinäkyvä
+solid-color = Yhtenäinen väri
+webp = WebP
+favicon-pack = favicon-paketti
+download-svg-sprite = Lataa SVG-sprite
+vector-drawable = Android VectorDrawable
+xaml = XAML
+eps = EPS
+template = Malli
+pull-request-card = Pull request -kortti
+open-graph-card = Open Graph -kortti
+square-card = Neliönmuotoinen kortti
+dark-light-card = Tumma/vaalea-vertailukortti
+badge-label = Nimiö
+badge-message = Viesti
+badge-style = Tyyli
+copy-shields-url = Kopioi shields.io-URL
+compare = Vertaa
+no-comparison = Ei mitään
+overlay-comparison = Päällekkäin
+side-by-side-comparison = Rinnakkain
+current-path = Nykyinen
+edited-path = Muokattu
+added-areas = Lisätty
+removed-areas = Poistettu
+current-path-not-found = Brändin nykyistä kuvaketta ei voi ladata
+path-size-change = Koko: { $before } → { $after } tavua ({ $difference })
+path-bbox-change = Rajauslaatikko: { $before } → { $after }
+undo = Kumoa
+redo = Tee uudelleen
+history = Historia
+initial-state = Alkutila
+brand-changed = Brändi: { $before } → { $after }
+color-changed = Väri: #{ $before } → #{ $after }
+path-changed = Polku: { $before } → { $after } tavua
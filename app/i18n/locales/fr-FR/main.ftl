@@ -60,7 +60,7 @@ license = Licence
 custom-license = Licence personnalisée
 subject-icon = { $icon } icon
 copy-icon-svg = Copier le SVG de { $icon }
-error-generating-pdf = Erreur de génération du PDF avec la bibliothèque PDFKit:
+error-generating-pdf = Erreur de génération du PDF:
 view-icon = Voir { $icon }
 copy-hex-color = Copier la couleur hexadécimale
 discord = Discord
@@ -93,3 +93,85 @@ libraries = Bibliothèques
 copy-icon-modal-url = Copier l'URL du modal de l'icône
 copy-icon-url = Copier l'URL de l'icône
 copy-icon-svg-path = Copier le chemin SVG de l'icône
+no-icons-found = Aucune icône trouvée pour « { $search } »
+did-you-mean = Vouliez-vous dire :
+submit-a-request-for = Soumettre une demande pour { $brand }
+select-icons = Sélectionner des icônes
+n-selected-icons = { $n } sélectionnées
+select-all-results = Sélectionner tous les résultats
+clear-selection = Effacer la sélection
+download-zip = Télécharger le ZIP
+select-icon = Sélectionner { $icon }
+favorites = Favoris
+add-to-favorites = Ajouter { $icon } aux favoris
+remove-from-favorites = Retirer { $icon } des favoris
+no-favorites = Aucun favori pour l'instant. Utilisez le bouton étoile des icônes pour les ajouter ici.
+collection-of-n-icons = Collection de { $n } icônes
+n-collection-icons-not-found = { $n } icônes de la collection sont introuvables
+invalid-collection = Le lien de la collection n'est pas valide, toutes les icônes sont affichées.
+download-all = Tout télécharger
+show-all-icons = Afficher toutes les icônes
+copy-collection-link = Copier le lien de la collection
+filters = Filtres
+has-brand-guidelines = Avec charte graphique
+has-license = Avec licence
+license-type = Licence : { $type }
+has-localized-names = Avec noms localisés
+clear-filters = Effacer les filtres
+list = Liste
+preview = Aperçu
+slug = Slug
+monochrome = Monochrome
+brand-color-background = Sur la couleur de la marque
+brand-color-fill = Avec la couleur de la marque
+also-known-as = Aussi connu sous le nom de
+old-names = Anciens noms
+localized-names = Noms localisés
+brands-sharing-the-icon = Marques partageant l'icône
+details = Détails
+snippets = Extraits de code
+copy-snippet = Copier l'extrait
+export = Exporter
+size = Taille
+fill = Remplissage
+background = Arrière-plan
+padding = Marge intérieure
+brand-color = Couleur de la marque
+black = Noir
+white = Blanc
+custom-color = Couleur personnalisée
+transparent = Transparent
+solid-color = Couleur unie
+webp = WebP
+favicon-pack = pack de favicons
+download-svg-sprite = Télécharger le sprite SVG
+vector-drawable = VectorDrawable Android
+xaml = XAML
+eps = EPS
+template = Modèle
+pull-request-card = Carte de pull request
+open-graph-card = Carte Open Graph
+square-card = Carte carrée
+dark-light-card = Carte de comparaison sombre/clair
+badge-label = Libellé
+badge-message = Message
+badge-style = Style
+copy-shields-url = Copier l'URL shields.io
+compare = Comparer
+no-comparison = Aucune
+overlay-comparison = Superposés
+side-by-side-comparison = Côte à côte
+current-path = Actuel
+edited-path = Modifié
+added-areas = Ajouté
+removed-areas = Supprimé
+current-path-not-found = L'icône actuelle de la marque ne peut pas être chargée
+path-size-change = Taille : { $before } → { $after } octets ({ $difference })
+path-bbox-change = Boîte englobante : { $before } → { $after }
+undo = Annuler
+redo = Rétablir
+history = Historique
+initial-state = État initial
+brand-changed = Marque : { $before } → { $after }
+color-changed = Couleur : #{ $before } → #{ $after }
+path-changed = Chemin : { $before } → { $after } octets
@@ -60,7 +60,7 @@ license = लाइसेंस
 custom-license = कस्टम लाइसेंस
 subject-icon = { $icon } आइकन
 copy-icon-svg = { $icon } SVG कॉपी करें
-error-generating-pdf = PDFKit लाइब्रेरी के साथ PDF बनाने में त्रुटि:
+error-generating-pdf = PDF बनाने में त्रुटि:
 view-icon = { $icon } देखें
 copy-hex-color = हेक्स कलर कॉपी करें
 discord = Discord
@@ -93,3 +93,85 @@ libraries = लाइब्रेरी
 copy-icon-modal-url = आइकन मोडल URL कॉपी करें
 copy-icon-url = आइकन URL कॉपी करें
 copy-icon-svg-path = आइकन SVG पथ कॉपी करें
+no-icons-found = "{ $search }" के लिए कोई आइकन नहीं मिला
+did-you-mean = क्या आपका मतलब था:
+submit-a-request-for = { $brand } के लिए अनुरोध सबमिट करें
+select-icons = आइकन चुनें
+n-selected-icons = { $n } चयनित
+select-all-results = सभी परिणाम चुनें
+clear-selection = चयन साफ़ करें
+download-zip = ZIP डाउनलोड करें
+select-icon = { $icon } चुनें
+favorites = पसंदीदा
+add-to-favorites = { $icon } को पसंदीदा में जोड़ें
+remove-from-favorites = { $icon } को पसंदीदा से हटाएँ
+no-favorites = अभी तक कोई पसंदीदा नहीं। आइकनों को यहाँ जोड़ने के लिए उनके तारा बटन का उपयोग करें।
+collection-of-n-icons = { $n } आइकनों का संग्रह
+n-collection-icons-not-found = संग्रह के { $n } आइकन नहीं मिले
+invalid-collection = संग्रह लिंक मान्य नहीं है, सभी आइकन दिखाए जा रहे हैं।
+download-all = सभी डाउनलोड करें
+show-all-icons = सभी आइकन दिखाएँ
+copy-collection-link = संग्रह लिंक कॉपी करें
+filters = फ़िल्टर
+has-brand-guidelines = ब्रांड दिशानिर्देश हैं
+has-license = लाइसेंस है
+license-type = लाइसेंस: { $type }
+has-localized-names = स्थानीय नाम हैं
+clear-filters = फ़िल्टर साफ़ करें
+list = सूची
+preview = पूर्वावलोकन
+slug = स्लग
+monochrome = एकवर्णी
+brand-color-background = ब्रांड रंग पर
+brand-color-fill = ब्रांड रंग में
+also-known-as = इस नाम से भी जाना जाता है
+old-names = पुराने नाम
+localized-names = स्थानीयकृत नाम
+brands-sharing-the-icon = इस आइकन को साझा करने वाले ब्रांड
+details = विवरण
+snippets = कोड स्निपेट
+copy-snippet = स्निपेट कॉपी करें
+export = निर्यात
+size = आकार
+fill = भराव
+background = पृष्ठभूमि
+padding = पैडिंग
+brand-color = ब्रांड का रंग
+black = काला
+white = सफ़ेद
+custom-color = कस्टम रंग
+transparent = पारदर्शी
+solid-color = ठोस रंग
+webp = WebP
+favicon-pack = फ़ेविकॉन पैक
+download-svg-sprite = SVG स्प्राइट डाउनलोड करें
+vector-drawable = Android VectorDrawable
+xaml = XAML
+eps = EPS
+template = टेम्पलेट
+pull-request-card = पुल रिक्वेस्ट कार्ड
+open-graph-card = Open Graph कार्ड
+square-card = वर्गाकार कार्ड
+dark-light-card = गहरा/हल्का तुलना कार्ड
+badge-label = लेबल
+badge-message = संदेश
+badge-style = शैली
+copy-shields-url = shields.io URL कॉपी करें
+compare = तुलना
+no-comparison = कोई नहीं
+overlay-comparison = ओवरले
+side-by-side-comparison = साथ-साथ
+current-path = वर्तमान
+edited-path = संपादित
+added-areas = जोड़ा गया
+removed-areas = हटाया गया
+current-path-not-found = ब्रांड का वर्तमान आइकन लोड नहीं किया जा सकता
+path-size-change = आकार: { $before } → { $after } बाइट ({ $difference })
+path-bbox-change = बाउंडिंग बॉक्स: { $before } → { $after }
+undo = पूर्ववत करें
+redo = फिर से करें
+history = इतिहास
+initial-state = प्रारंभिक स्थिति
+brand-changed = ब्रांड: { $before } → { $after }
+color-changed = रंग: #{ $before } → #{ $after }
+path-changed = पथ: { $before } → { $after } बाइट
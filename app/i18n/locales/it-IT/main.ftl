@@ -60,7 +60,7 @@ license = Licenza
 custom-license = Licenza personalizzata
 subject-icon = Icona { $icon }
 copy-icon-svg = Copia SVG di { $icon }
-error-generating-pdf = Errore durante la generazione del PDF con la libreria PDFKit:
+error-generating-pdf = Errore durante la generazione del PDF:
 view-icon = Visualizza { $icon }
 copy-hex-color = Copia colore esadecimale
 discord = Discord
@@ -93,3 +93,85 @@ libraries = Librerie
 copy-icon-modal-url = Copia URL del modal dell'icona
 copy-icon-url = Copia URL dell'icona
 copy-icon-svg-path = Copia percorso SVG dell'icona
+no-icons-found = Nessuna icona trovata per "{ $search }"
+did-you-mean = Forse cercavi:
+submit-a-request-for = Invia una richiesta per { $brand }
+select-icons = Seleziona icone
+n-selected-icons = { $n } selezionate
+select-all-results = Seleziona tutti i risultati
+clear-selection = Cancella selezione
+download-zip = Scarica ZIP
+select-icon = Seleziona { $icon }
+favorites = Preferiti
+add-to-favorites = Aggiungi { $icon } ai preferiti
+remove-from-favorites = Rimuovi { $icon } dai preferiti
+no-favorites = Ancora nessun preferito. Usa il pulsante a stella delle icone per aggiungerle qui.
+collection-of-n-icons = Raccolta di { $n } icone
+n-collection-icons-not-found = { $n } icone della raccolta non sono state trovate
+invalid-collection = Il link della raccolta non è valido, vengono mostrate tutte le icone.
+download-all = Scarica tutte
+show-all-icons = Mostra tutte le icone
+copy-collection-link = Copia link della raccolta
+filters = Filtri
+has-brand-guidelines = Con linee guida del marchio
+has-license = Con licenza
+license-type = Licenza: { $type }
+has-localized-names = Con nomi localizzati
+clear-filters = Cancella filtri
+list = Elenco
+preview = Anteprima
+slug = Slug
+monochrome = Monocromatico
+brand-color-background = Sul colore del marchio
+brand-color-fill = Nel colore del marchio
+also-known-as = Conosciuto anche come
+old-names = Nomi precedenti
+localized-names = Nomi localizzati
+brands-sharing-the-icon = Marchi che condividono l'icona
+details = Dettagli
+snippets = Frammenti di codice
+copy-snippet = Copia frammento
+export = Esporta
+size = Dimensione
+fill = Riempimento
+background = Sfondo
+padding = Spaziatura interna
+brand-color = Colore del marchio
+black = Nero
+white = Bianco
+custom-color = Colore personalizzato
+transparent = Trasparente
+solid-color = Colore pieno
+webp = WebP
+favicon-pack = pacchetto di favicon
+download-svg-sprite = Scarica sprite SVG
+vector-drawable = VectorDrawable di Android
+xaml = XAML
+eps = EPS
+template = Modello
+pull-request-card = Scheda della pull request
+open-graph-card = Scheda Open Graph
+square-card = Scheda quadrata
+dark-light-card = Scheda di confronto scuro/chiaro
+badge-label = Etichetta
+badge-message = Messaggio
+badge-style = Stile
+copy-shields-url = Copia URL di shields.io
+compare = Confronta
+no-comparison = Nessuno
+overlay-comparison = Sovrapposti
+side-by-side-comparison = Affiancati
+current-path = Attuale
+edited-path = Modificato
+added-areas = Aggiunto
+removed-areas = Rimosso
+current-path-not-found = Impossibile caricare l'icona attuale del marchio
+path-size-change = Dimensione: { $before } → { $after } byte ({ $difference })
+path-bbox-change = Riquadro di delimitazione: { $before } → { $after }
+undo = Annulla
+redo = Ripeti
+history = Cronologia
+initial-state = Stato iniziale
+brand-changed = Marchio: { $before } → { $after }
+color-changed = Colore: #{ $before } → #{ $after }
+path-changed = Percorso: { $before } → { $after } byte
@@ -60,7 +60,7 @@ license = ライセンス
 custom-license = カスタムライセンス
 subject-icon = { $icon } アイコン
 copy-icon-svg = { $icon } SVG をコピー
-error-generating-pdf = PDFKit ライブラリで PDF を生成中にエラーが発生しました:
+error-generating-pdf = PDF を生成中にエラーが発生しました:
 view-icon = { $icon } を見る
 copy-hex-color = 16 進数の色をコピー
 discord = Discord
@@ -93,3 +93,85 @@ libraries = ライブラリ
 copy-icon-modal-url = アイコン モーダル URL をコピー
 copy-icon-url = アイコン URL をコピー
 copy-icon-svg-path = アイコン SVG パスをコピー
+no-icons-found = 「{ $search }」のアイコンが見つかりません
+did-you-mean = もしかして:
+submit-a-request-for = { $brand } のリクエストを送信
+select-icons = アイコンを選択
+n-selected-icons = { $n } 件選択中
+select-all-results = すべての結果を選択
+clear-selection = 選択を解除
+download-zip = ZIP をダウンロード
+select-icon = { $icon } を選択
+favorites = お気に入り
+add-to-favorites = { $icon } をお気に入りに追加
+remove-from-favorites = { $icon } をお気に入りから削除
+no-favorites = お気に入りはまだありません。アイコンの星ボタンでここに追加できます。
+collection-of-n-icons = { $n } 個のアイコンのコレクション
+n-collection-icons-not-found = コレクションのアイコン { $n } 個が見つかりませんでした
+invalid-collection = コレクションのリンクが無効です。すべてのアイコンを表示しています。
+download-all = すべてダウンロード
+show-all-icons = すべてのアイコンを表示
+copy-collection-link = コレクションのリンクをコピー
+filters = フィルター
+has-brand-guidelines = ブランドガイドラインあり
+has-license = ライセンスあり
+license-type = ライセンス: { $type }
+has-localized-names = ローカライズ名あり
+clear-filters = フィルターをクリア
+list = リスト
+preview = プレビュー
+slug = スラッグ
+monochrome = モノクロ
+brand-color-background = ブランドカラーの背景
+brand-color-fill = ブランドカラーで塗りつぶし
+also-known-as = 別名
+old-names = 旧名
+localized-names = ローカライズされた名前
+brands-sharing-the-icon = このアイコンを共有するブランド
+details = 詳細
+snippets = スニペット
+copy-snippet = スニペットをコピー
+export = エクスポート
+size = サイズ
+fill = 塗りつぶし
+background = 背景
+padding = 余白
+brand-color = ブランドカラー
+black = 黒
+white = 白
+custom-color = カスタムカラー
+transparent = 透明
+solid-color = 単色
+webp = WebP
+favicon-pack = ファビコンパック
+download-svg-sprite = SVG スプライトをダウンロード
+vector-drawable = Android VectorDrawable
+xaml = XAML
+eps = EPS
+template = テンプレート
+pull-request-card = プルリクエストカード
+open-graph-card = Open Graph カード
+square-card = 正方形カード
+dark-light-card = ダーク/ライト比較カード
+badge-label = ラベル
+badge-message = メッセージ
+badge-style = スタイル
+copy-shields-url = shields.io の URL をコピー
+compare = 比較
+no-comparison = なし
+overlay-comparison = 重ねて表示
+side-by-side-comparison = 並べて表示
+current-path = 現在
+edited-path = 編集後
+added-areas = 追加
+removed-areas = 削除
+current-path-not-found = ブランドの現在のアイコンを読み込めません
+path-size-change = サイズ: { $before } → { $after } バイト ({ $difference })
+path-bbox-change = バウンディングボックス: { $before } → { $after }
+undo = 元に戻す
+redo = やり直す
+history = 履歴
+initial-state = 初期状態
+brand-changed = ブランド: { $before } → { $after }
+color-changed = 色: #{ $before } → #{ $after }
+path-changed = パス: { $before } → { $after } バイト
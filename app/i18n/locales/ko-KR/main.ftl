@@ -60,7 +60,7 @@ license = 특허
 custom-license = 맞춤형 특허
 subject-icon = { $icon } 아이콘
 copy-icon-svg = { $icon } SVG 복사
-error-generating-pdf = PDFKit 라이브러리로 PDF를 생성하는 동안 오류가 발생했습니다:
+error-generating-pdf = PDF를 생성하는 동안 오류가 발생했습니다:
 view-icon = { $icon } 보기
 copy-hex-color = 16진수 색상 복사
 discord = Discord
@@ -93,3 +93,85 @@ libraries = 라이브러리
 copy-icon-modal-url = 아이콘 모달 URL 복사
 copy-icon-url = 아이콘 URL 복사
 copy-icon-svg-path = 아이콘 SVG 경로 복사
+no-icons-found = "{ $search }"에 대한 아이콘을 찾을 수 없습니다
+did-you-mean = 이것을 찾으셨나요:
+submit-a-request-for = { $brand } 요청 제출
+select-icons = 아이콘 선택
+n-selected-icons = { $n }개 선택됨
+select-all-results = 모든 결과 선택
+clear-selection = 선택 해제
+download-zip = ZIP 다운로드
+select-icon = { $icon } 선택
+favorites = 즐겨찾기
+add-to-favorites = { $icon }을(를) 즐겨찾기에 추가
+remove-from-favorites = { $icon }을(를) 즐겨찾기에서 제거
+no-favorites = 아직 즐겨찾기가 없습니다. 아이콘의 별 버튼으로 여기에 추가하세요.
+collection-of-n-icons = 아이콘 { $n }개 컬렉션
+n-collection-icons-not-found = 컬렉션의 아이콘 { $n }개를 찾을 수 없습니다
+invalid-collection = 컬렉션 링크가 유효하지 않아 모든 아이콘을 표시합니다.
+download-all = 모두 다운로드
+show-all-icons = 모든 아이콘 보기
+copy-collection-link = 컬렉션 링크 복사
+filters = 필터
+has-brand-guidelines = 브랜드 가이드라인 있음
+has-license = 라이선스 있음
+license-type = 라이선스: { $type }
+has-localized-names = 현지화된 이름 있음
+clear-filters = 필터 지우기
+list = 목록
+preview = 미리보기
+slug = 슬러그
+monochrome = 단색
+brand-color-background = 브랜드 색상 배경
+brand-color-fill = 브랜드 색상으로 채우기
+also-known-as = 다른 이름
+old-names = 이전 이름
+localized-names = 현지화된 이름
+brands-sharing-the-icon = 아이콘을 공유하는 브랜드
+details = 세부 정보
+snippets = 코드 조각
+copy-snippet = 코드 조각 복사
+export = 내보내기
+size = 크기
+fill = 채우기
+background = 배경
+padding = 여백
+brand-color = 브랜드 색상
+black = 검정
+white = 흰색
+custom-color = 사용자 지정 색상
+transparent = 투명
+solid-color = 단색 배경
+webp = WebP
+favicon-pack = 파비콘 팩
+download-svg-sprite = SVG 스프라이트 다운로드
+vector-drawable = Android VectorDrawable
+xaml = XAML
+eps = EPS
+template = 템플릿
+pull-request-card = 풀 리퀘스트 카드
+open-graph-card = Open Graph 카드
+square-card = 정사각형 카드
+dark-light-card = 다크/라이트 비교 카드
+badge-label = 레이블
+badge-message = 메시지
+badge-style = 스타일
+copy-shields-url = shields.io URL 복사
+compare = 비교
+no-comparison = 없음
+overlay-comparison = 겹쳐 보기
+side-by-side-comparison = 나란히 보기
+current-path = 현재
+edited-path = 편집됨
+added-areas = 추가됨
+removed-areas = 제거됨
+current-path-not-found = 브랜드의 현재 아이콘을 불러올 수 없습니다
+path-size-change = 크기: { $before } → { $after } 바이트 ({ $difference })
+path-bbox-change = 경계 상자: { $before } → { $after }
+undo = 실행 취소
+redo = 다시 실행
+history = 기록
+initial-state = 초기 상태
+brand-changed = 브랜드: { $before } → { $after }
+color-changed = 색상: #{ $before } → #{ $after }
+path-changed = 경로: { $before } → { $after } 바이트
@@ -60,7 +60,7 @@ license = Lisens
 custom-license = Tilpasset lisens
 subject-icon = { $icon } ikon
 copy-icon-svg = Kopier { $icon } SVG
-error-generating-pdf = Feil ved generering av PDF med PDFKit-biblioteket:
+error-generating-pdf = Feil ved generering av PDF:
 view-icon = Vis { $icon }
 copy-hex-color = Kopier hex-farge
 discord = Discord
@@ -93,3 +93,85 @@ libraries = Biblioteker
 copy-icon-modal-url = Kopier ikon modal-URL
 copy-icon-url = Kopier ikon-URL
 copy-icon-svg-path = Kopier ikon SVG-sti
+no-icons-found = Fant ingen ikoner for "{ $search }"
+did-you-mean = Mente du:
+submit-a-request-for = Send inn en forespørsel for { $brand }
+select-icons = Velg ikoner
+n-selected-icons = { $n } valgt
+select-all-results = Velg alle resultater
+clear-selection = Fjern valg
+download-zip = Last ned ZIP
+select-icon = Velg { $icon }
+favorites = Favoritter
+add-to-favorites = Legg til { $icon } i favoritter
+remove-from-favorites = Fjern { $icon } fra favoritter
+no-favorites = Ingen favoritter ennå. Bruk stjerneknappen på ikonene for å legge dem til her.
+collection-of-n-icons = Samling med { $n } ikoner
+n-collection-icons-not-found = { $n } ikoner i samlingen ble ikke funnet
+invalid-collection = Lenken til samlingen er ugyldig, viser alle ikonene.
+download-all = Last ned alle
+show-all-icons = Vis alle ikoner
+copy-collection-link = Kopier lenke til samlingen
+filters = Filtre
+has-brand-guidelines = Har merkevareretningslinjer
+has-license = Har lisens
+license-type = Lisens: { $type }
+has-localized-names = Har lokaliserte navn
+clear-filters = Fjern filtre
+list = Liste
+preview = Forhåndsvisning
+slug = Slug
+monochrome = Monokrom
+brand-color-background = På merkevarefarge
+brand-color-fill = I merkevarefarge
+also-known-as = Også kjent som
+old-names = Tidligere navn
+localized-names = Lokaliserte navn
+brands-sharing-the-icon = Merker som deler ikonet
+details = Detaljer
+snippets = Kodesnutter
+copy-snippet = Kopier kodesnutt
+export = Eksporter
+size = Størrelse
+fill = Fyll
+background = Bakgrunn
+padding = Utfylling
+brand-color = Merkefarge
+black = Svart
+white = Hvit
+custom-color = Egendefinert farge
+transparent = Gjennomsiktig
+solid-color = Ensfarget
+webp = WebP
+favicon-pack = favikonpakke
+download-svg-sprite = Last ned SVG-sprite
+vector-drawable = Android VectorDrawable
+xaml = XAML
+eps = EPS
+template = Mal
+pull-request-card = Pull request-kort
+open-graph-card = Open Graph-kort
+square-card = Kvadratisk kort
+dark-light-card = Sammenligningskort mørk/lys
+badge-label = Etikett
+badge-message = Melding
+badge-style = Stil
+copy-shields-url = Kopier shields.io-URL
+compare = Sammenlign
+no-comparison = Ingen
+overlay-comparison = Overlagt
+side-by-side-comparison = Side om side
+current-path = Nåværende
+edited-path = Redigert
+added-areas = Lagt til
+removed-areas = Fjernet
+current-path-not-found = Merkets nåværende ikon kan ikke lastes inn
+path-size-change = Størrelse: { $before } → { $after } byte ({ $difference })
+path-bbox-change = Avgrensningsboks: { $before } → { $after }
+undo = Angre
+redo = Gjør om
+history = Historikk
+initial-state = Utgangstilstand
+brand-changed = Merke: { $before } → { $after }
+color-changed = Farge: #{ $before } → #{ $after }
+path-changed = Bane: { $before } → { $after } byte
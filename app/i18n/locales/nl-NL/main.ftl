@@ -60,7 +60,7 @@ license = Licentie
 custom-license = Aangepaste licentie
 subject-icon = { $icon } icoon
 copy-icon-svg = { $icon } SVG kopiëren
-error-generating-pdf = Fout bij genereren PDF met PDFKit bibliotheek:
+error-generating-pdf = Fout bij genereren PDF:
 view-icon = { $icon } bekijken
 copy-hex-color = Hex-kleur kopiëren
 discord = Discord
@@ -93,3 +93,85 @@ libraries = Bibliotheken
 copy-icon-modal-url = Icoon modal URL kopiëren
 copy-icon-url = Icoon URL kopiëren
 copy-icon-svg-path = Icoon SVG-pad kopiëren
+no-icons-found = Geen iconen gevonden voor "{ $search }"
+did-you-mean = Bedoelde je:
+submit-a-request-for = Dien een verzoek in voor { $brand }
+select-icons = Iconen selecteren
+n-selected-icons = { $n } geselecteerd
+select-all-results = Alle resultaten selecteren
+clear-selection = Selectie wissen
+download-zip = ZIP downloaden
+select-icon = { $icon } selecteren
+favorites = Favorieten
+add-to-favorites = { $icon } toevoegen aan favorieten
+remove-from-favorites = { $icon } verwijderen uit favorieten
+no-favorites = Nog geen favorieten. Gebruik de sterknop van de iconen om ze hier toe te voegen.
+collection-of-n-icons = Collectie van { $n } iconen
+n-collection-icons-not-found = { $n } iconen van de collectie zijn niet gevonden
+invalid-collection = De collectielink is ongeldig, alle iconen worden getoond.
+download-all = Alles downloaden
+show-all-icons = Alle iconen tonen
+copy-collection-link = Collectielink kopiëren
+filters = Filters
+has-brand-guidelines = Met merkrichtlijnen
+has-license = Met licentie
+license-type = Licentie: { $type }
+has-localized-names = Met gelokaliseerde namen
+clear-filters = Filters wissen
+list = Lijst
+preview = Voorbeeld
+slug = Slug
+monochrome = Monochroom
+brand-color-background = Op merkkleur
+brand-color-fill = In merkkleur
+also-known-as = Ook bekend als
+old-names = Oude namen
+localized-names = Gelokaliseerde namen
+brands-sharing-the-icon = Merken die het icoon delen
+details = Details
+snippets = Codefragmenten
+copy-snippet = Fragment kopiëren
+export = Exporteren
+size = Grootte
+fill = Vulling
+background = Achtergrond
+padding = Opvulling
+brand-color = Merkkleur
+black = Zwart
+white = Wit
+custom-color = Aangepaste kleur
+transparent = Transparant
+solid-color = Effen kleur
+webp = WebP
+favicon-pack = faviconpakket
+download-svg-sprite = SVG-sprite downloaden
+vector-drawable = Android VectorDrawable
+xaml = XAML
+eps = EPS
+template = Sjabloon
+pull-request-card = Pull request-kaart
+open-graph-card = Open Graph-kaart
+square-card = Vierkante kaart
+dark-light-card = Vergelijkingskaart donker/licht
+badge-label = Label
+badge-message = Bericht
+badge-style = Stijl
+copy-shields-url = Kopieer shields.io-URL
+compare = Vergelijken
+no-comparison = Geen
+overlay-comparison = Over elkaar
+side-by-side-comparison = Naast elkaar
+current-path = Huidig
+edited-path = Bewerkt
+added-areas = Toegevoegd
+removed-areas = Verwijderd
+current-path-not-found = Het huidige pictogram van het merk kan niet worden geladen
+path-size-change = Grootte: { $before } → { $after } bytes ({ $difference })
+path-bbox-change = Omsluitend kader: { $before } → { $after }
+undo = Ongedaan maken
+redo = Opnieuw
+history = Geschiedenis
+initial-state = Beginstatus
+brand-changed = Merk: { $before } → { $after }
+color-changed = Kleur: #{ $before } → #{ $after }
+path-changed = Pad: { $before } → { $after } bytes
@@ -60,7 +60,7 @@ license = Licencja
 custom-license = Niestandardowa licencja
 subject-icon = Ikona { $icon }
 copy-icon-svg = Kopiuj SVG { $icon }
-error-generating-pdf = Błąd podczas generowania PDF za pomocą biblioteki PDFKit:
+error-generating-pdf = Błąd podczas generowania PDF:
 view-icon = Zobacz { $icon }
 copy-hex-color = Kopiuj kolor hex
 discord = Discord
@@ -93,3 +93,85 @@ libraries = Biblioteki
 copy-icon-modal-url = Kopiuj URL modalu ikony
 copy-icon-url = Kopiuj URL ikony
 copy-icon-svg-path = Kopiuj ścieżkę SVG ikony
+no-icons-found = Nie znaleziono ikon dla "{ $search }"
+did-you-mean = Czy chodziło Ci o:
+submit-a-request-for = Wyślij prośbę o { $brand }
+select-icons = Zaznacz ikony
+n-selected-icons = Zaznaczono: { $n }
+select-all-results = Zaznacz wszystkie wyniki
+clear-selection = Wyczyść zaznaczenie
+download-zip = Pobierz ZIP
+select-icon = Zaznacz { $icon }
+favorites = Ulubione
+add-to-favorites = Dodaj { $icon } do ulubionych
+remove-from-favorites = Usuń { $icon } z ulubionych
+no-favorites = Brak ulubionych. Użyj przycisku gwiazdki przy ikonach, aby dodać je tutaj.
+collection-of-n-icons = Kolekcja ikon: { $n }
+n-collection-icons-not-found = Nie znaleziono ikon z kolekcji: { $n }
+invalid-collection = Link do kolekcji jest nieprawidłowy, wyświetlane są wszystkie ikony.
+download-all = Pobierz wszystkie
+show-all-icons = Pokaż wszystkie ikony
+copy-collection-link = Kopiuj link do kolekcji
+filters = Filtry
+has-brand-guidelines = Z wytycznymi marki
+has-license = Z licencją
+license-type = Licencja: { $type }
+has-localized-names = Z nazwami lokalnymi
+clear-filters = Wyczyść filtry
+list = Lista
+preview = Podgląd
+slug = Slug
+monochrome = Monochromatyczny
+brand-color-background = Na kolorze marki
+brand-color-fill = W kolorze marki
+also-known-as = Znany również jako
+old-names = Dawne nazwy
+localized-names = Zlokalizowane nazwy
+brands-sharing-the-icon = Marki używające tej ikony
+details = Szczegóły
+snippets = Fragmenty kodu
+copy-snippet = Kopiuj fragment
+export = Eksport
+size = Rozmiar
+fill = Wypełnienie
+background = Tło
+padding = Margines wewnętrzny
+brand-color = Kolor marki
+black = Czarny
+white = Biały
+custom-color = Własny kolor
+transparent = Przezroczyste
+solid-color = Jednolity kolor
+webp = WebP
+favicon-pack = pakiet favicon
+download-svg-sprite = Pobierz sprite SVG
+vector-drawable = Android VectorDrawable
+xaml = XAML
+eps = EPS
+template = Szablon
+pull-request-card = Karta pull requesta
+open-graph-card = Karta Open Graph
+square-card = Kwadratowa karta
+dark-light-card = Karta porównania ciemny/jasny
+badge-label = Etykieta
+badge-message = Wiadomość
+badge-style = Styl
+copy-shields-url = Kopiuj URL shields.io
+compare = Porównaj
+no-comparison = Brak
+overlay-comparison = Nałożone
+side-by-side-comparison = Obok siebie
+current-path = Obecna
+edited-path = Edytowana
+added-areas = Dodane
+removed-areas = Usunięte
+current-path-not-found = Nie można wczytać obecnej ikony marki
+path-size-change = Rozmiar: { $before } → { $after } bajtów ({ $difference })
+path-bbox-change = Ramka ograniczająca: { $before } → { $after }
+undo = Cofnij
+redo = Ponów
+history = Historia
+initial-state = Stan początkowy
+brand-changed = Marka: { $before } → { $after }
+color-changed = Kolor: #{ $before } → #{ $after }
+path-changed = Ścieżka: { $before } → { $after } bajtów
@@ -60,7 +60,7 @@ license = Licença
 custom-license = Licença personalizada
 subject-icon = Ícone { $icon }
 copy-icon-svg = Copiar { $icon } SVG
-error-generating-pdf = Erro ao gerar PDF com a biblioteca PDFKit:
+error-generating-pdf = Erro ao gerar PDF:
 view-icon = Visualizar { $icon }
 copy-hex-color = Copiar cor hexadecimal
 discord = Discord
@@ -93,3 +93,85 @@ libraries = Bibliotecas
 copy-icon-modal-url = Copiar URL do modal do ícone
 copy-icon-url = Copiar URL do ícone
 copy-icon-svg-path = Copiar caminho SVG do ícone
+no-icons-found = Nenhum ícone encontrado para "{ $search }"
+did-you-mean = Quis dizer:
+submit-a-request-for = Submeter um pedido para { $brand }
+select-icons = Selecionar ícones
+n-selected-icons = { $n } selecionados
+select-all-results = Selecionar todos os resultados
+clear-selection = Limpar seleção
+download-zip = Transferir ZIP
+select-icon = Selecionar { $icon }
+favorites = Favoritos
+add-to-favorites = Adicionar { $icon } aos favoritos
+remove-from-favorites = Remover { $icon } dos favoritos
+no-favorites = Ainda não há favoritos. Use o botão de estrela dos ícones para os adicionar aqui.
+collection-of-n-icons = Coleção de { $n } ícones
+n-collection-icons-not-found = Não foram encontrados { $n } ícones da coleção
+invalid-collection = A ligação da coleção não é válida, a mostrar todos os ícones.
+download-all = Transferir todos
+show-all-icons = Mostrar todos os ícones
+copy-collection-link = Copiar ligação da coleção
+filters = Filtros
+has-brand-guidelines = Com diretrizes da marca
+has-license = Com licença
+license-type = Licença: { $type }
+has-localized-names = Com nomes localizados
+clear-filters = Limpar filtros
+list = Lista
+preview = Pré-visualização
+slug = Slug
+monochrome = Monocromático
+brand-color-background = Sobre a cor da marca
+brand-color-fill = Na cor da marca
+also-known-as = Também conhecido como
+old-names = Nomes antigos
+localized-names = Nomes localizados
+brands-sharing-the-icon = Marcas que partilham o ícone
+details = Detalhes
+snippets = Excertos de código
+copy-snippet = Copiar excerto
+export = Exportar
+size = Tamanho
+fill = Preenchimento
+background = Fundo
+padding = Margem interior
+brand-color = Cor da marca
+black = Preto
+white = Branco
+custom-color = Cor personalizada
+transparent = Transparente
+solid-color = Cor sólida
+webp = WebP
+favicon-pack = pacote de favicons
+download-svg-sprite = Transferir sprite SVG
+vector-drawable = VectorDrawable do Android
+xaml = XAML
+eps = EPS
+template = Modelo
+pull-request-card = Cartão de pull request
+open-graph-card = Cartão Open Graph
+square-card = Cartão quadrado
+dark-light-card = Cartão de comparação escuro/claro
+badge-label = Etiqueta
+badge-message = Mensagem
+badge-style = Estilo
+copy-shields-url = Copiar URL do shields.io
+compare = Comparar
+no-comparison = Nenhuma
+overlay-comparison = Sobrepostos
+side-by-side-comparison = Lado a lado
+current-path = Atual
+edited-path = Editada
+added-areas = Adicionado
+removed-areas = Removido
+current-path-not-found = Não é possível carregar o ícone atual da marca
+path-size-change = Tamanho: { $before } → { $after } bytes ({ $difference })
+path-bbox-change = Caixa delimitadora: { $before } → { $after }
+undo = Anular
+redo = Refazer
+history = Histórico
+initial-state = Estado inicial
+brand-changed = Marca: { $before } → { $after }
+color-changed = Cor: #{ $before } → #{ $after }
+path-changed = Caminho: { $before } → { $after } bytes
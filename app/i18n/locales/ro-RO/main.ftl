@@ -60,7 +60,7 @@ license = Licență
 custom-license = Licență personalizată
 subject-icon = Pictograma { $icon }
 copy-icon-svg = Copiază SVG { $icon }
-error-generating-pdf = Eroare la generarea PDF cu biblioteca PDFKit:
+error-generating-pdf = Eroare la generarea PDF:
 view-icon = Vizualizează { $icon }
 copy-hex-color = Copiază culoarea hex
 discord = Discord
@@ -93,3 +93,85 @@ libraries = Biblioteci
 copy-icon-modal-url = Copiază URL-ul modalului pictogramei
 copy-icon-url = Copiază URL-ul pictogramei
 copy-icon-svg-path = Copiază calea SVG a pictogramei
+no-icons-found = Nu s-au găsit pictograme pentru "{ $search }"
+did-you-mean = Ați vrut să spuneți:
+submit-a-request-for = Trimiteți o cerere pentru { $brand }
+select-icons = Selectați pictograme
+n-selected-icons = { $n } selectate
+select-all-results = Selectați toate rezultatele
+clear-selection = Ștergeți selecția
+download-zip = Descărcați ZIP
+select-icon = Selectați { $icon }
+favorites = Favorite
+add-to-favorites = Adăugați { $icon } la favorite
+remove-from-favorites = Eliminați { $icon } din favorite
+no-favorites = Încă nu există favorite. Folosiți butonul stea al pictogramelor pentru a le adăuga aici.
+collection-of-n-icons = Colecție de { $n } pictograme
+n-collection-icons-not-found = { $n } pictograme din colecție nu au fost găsite
+invalid-collection = Linkul colecției nu este valid, se afișează toate pictogramele.
+download-all = Descărcați tot
+show-all-icons = Afișați toate pictogramele
+copy-collection-link = Copiați linkul colecției
+filters = Filtre
+has-brand-guidelines = Cu ghid de brand
+has-license = Cu licență
+license-type = Licență: { $type }
+has-localized-names = Cu nume localizate
+clear-filters = Ștergeți filtrele
+list = Listă
+preview = Previzualizare
+slug = Slug
+monochrome = Monocrom
+brand-color-background = Pe culoarea mărcii
+brand-color-fill = În culoarea mărcii
+also-known-as = Cunoscut și ca
+old-names = Nume vechi
+localized-names = Nume localizate
+brands-sharing-the-icon = Mărci care folosesc aceeași pictogramă
+details = Detalii
+snippets = Fragmente de cod
+copy-snippet = Copiază fragmentul
+export = Exportă
+size = Dimensiune
+fill = Umplere
+background = Fundal
+padding = Spațiere
+brand-color = Culoarea mărcii
+black = Negru
+white = Alb
+custom-color = Culoare personalizată
+transparent = Transparent
+solid-color = Culoare solidă
+webp = WebP
+favicon-pack = pachet de favicon-uri
+download-svg-sprite = Descărcați sprite SVG
+vector-drawable = VectorDrawable Android
+xaml = XAML
+eps = EPS
+template = Șablon
+pull-request-card = Card pentru pull request
+open-graph-card = Card Open Graph
+square-card = Card pătrat
+dark-light-card = Card de comparație întunecat/luminos
+badge-label = Etichetă
+badge-message = Mesaj
+badge-style = Stil
+copy-shields-url = Copiază URL-ul shields.io
+compare = Compară
+no-comparison = Niciuna
+overlay-comparison = Suprapuse
+side-by-side-comparison = Alăturate
+current-path = Actuală
+edited-path = Editată
+added-areas = Adăugat
+removed-areas = Eliminat
+current-path-not-found = Pictograma actuală a mărcii nu poate fi încărcată
+path-size-change = Dimensiune: { $before } → { $after } octeți ({ $difference })
+path-bbox-change = Casetă de încadrare: { $before } → { $after }
+undo = Anulează
+redo = Refă
+history = Istoric
+initial-state = Starea inițială
+brand-changed = Marcă: { $before } → { $after }
+color-changed = Culoare: #{ $before } → #{ $after }
+path-changed = Cale: { $before } → { $after } octeți
@@ -60,7 +60,7 @@ license = Лицензия
 custom-license = Пользовательская лицензия
 subject-icon = { $icon } значок
 copy-icon-svg = Копировать { $icon } SVG
-error-generating-pdf = Ошибка создания PDF с помощью библиотеки PDFKit:
+error-generating-pdf = Ошибка создания PDF:
 view-icon = Посмотреть { $icon }
 copy-hex-color = Копировать шестнадцатеричный цвет
 discord = Discord
@@ -93,3 +93,85 @@ libraries = Библиотеки
 copy-icon-modal-url = Копировать URL-адрес модального значка
 copy-icon-url = Копировать URL значка
 copy-icon-svg-path = Копировать путь SVG значка
+no-icons-found = Иконки для «{ $search }» не найдены
+did-you-mean = Возможно, вы имели в виду:
+submit-a-request-for = Отправить запрос на { $brand }
+select-icons = Выбрать иконки
+n-selected-icons = Выбрано: { $n }
+select-all-results = Выбрать все результаты
+clear-selection = Сбросить выбор
+download-zip = Скачать ZIP
+select-icon = Выбрать { $icon }
+favorites = Избранное
+add-to-favorites = Добавить { $icon } в избранное
+remove-from-favorites = Удалить { $icon } из избранного
+no-favorites = Избранного пока нет. Используйте кнопку со звездой на иконках, чтобы добавить их сюда.
+collection-of-n-icons = Коллекция иконок: { $n }
+n-collection-icons-not-found = Не найдено иконок коллекции: { $n }
+invalid-collection = Ссылка на коллекцию недействительна, показаны все иконки.
+download-all = Скачать все
+show-all-icons = Показать все иконки
+copy-collection-link = Копировать ссылку на коллекцию
+filters = Фильтры
+has-brand-guidelines = Есть руководство по бренду
+has-license = Есть лицензия
+license-type = Лицензия: { $type }
+has-localized-names = Есть локализованные названия
+clear-filters = Сбросить фильтры
+list = Список
+preview = Предпросмотр
+slug = Слаг
+monochrome = Монохромный
+brand-color-background = На цвете бренда
+brand-color-fill = Цветом бренда
+also-known-as = Также известен как
+old-names = Прежние названия
+localized-names = Локализованные названия
+brands-sharing-the-icon = Бренды с этой же иконкой
+details = Подробности
+snippets = Фрагменты кода
+copy-snippet = Копировать фрагмент
+export = Экспорт
+size = Размер
+fill = Заливка
+background = Фон
+padding = Отступ
+brand-color = Цвет бренда
+black = Чёрный
+white = Белый
+custom-color = Свой цвет
+transparent = Прозрачный
+solid-color = Сплошной цвет
+webp = WebP
+favicon-pack = набор фавиконок
+download-svg-sprite = Скачать SVG-спрайт
+vector-drawable = Android VectorDrawable
+xaml = XAML
+eps = EPS
+template = Шаблон
+pull-request-card = Карточка пул-реквеста
+open-graph-card = Карточка Open Graph
+square-card = Квадратная карточка
+dark-light-card = Карточка сравнения тёмного и светлого
+badge-label = Метка
+badge-message = Сообщение
+badge-style = Стиль
+copy-shields-url = Копировать URL shields.io
+compare = Сравнить
+no-comparison = Нет
+overlay-comparison = Наложение
+side-by-side-comparison = Рядом
+current-path = Текущий
+edited-path = Изменённый
+added-areas = Добавлено
+removed-areas = Удалено
+current-path-not-found = Не удаётся загрузить текущую иконку бренда
+path-size-change = Размер: { $before } → { $after } байт ({ $difference })
+path-bbox-change = Ограничивающая рамка: { $before } → { $after }
+undo = Отменить
+redo = Повторить
+history = История
+initial-state = Исходное состояние
+brand-changed = Бренд: { $before } → { $after }
+color-changed = Цвет: #{ $before } → #{ $after }
+path-changed = Путь: { $before } → { $after } байт
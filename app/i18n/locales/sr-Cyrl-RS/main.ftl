@@ -62,7 +62,7 @@ license = Лиценца
 custom-license = Прилагођена лиценца
 subject-icon = иконица { $icon }
 copy-icon-svg = Копирај { $icon } SVG
-error-generating-pdf = Грешка при генерисању PDF-а помоћу PDFKit библиотеке:
+error-generating-pdf = Грешка при генерисању PDF-а:
 view-icon = Погледај { $icon }
 copy-hex-color = Копирај hex боју
 discord = Discord
@@ -95,3 +95,85 @@ libraries = Библиотеке
 copy-icon-modal-url = Копирај URL модала за иконицу
 copy-icon-url = Копирај URL иконице
 copy-icon-svg-path = Копирај SVG путању иконице
+no-icons-found = Нема пронађених иконица за "{ $search }"
+did-you-mean = Да ли сте мислили:
+submit-a-request-for = Пошаљите захтев за { $brand }
+select-icons = Изаберите иконице
+n-selected-icons = Изабрано: { $n }
+select-all-results = Изаберите све резултате
+clear-selection = Поништите избор
+download-zip = Преузмите ZIP
+select-icon = Изаберите { $icon }
+favorites = Омиљене
+add-to-favorites = Додајте { $icon } у омиљене
+remove-from-favorites = Уклоните { $icon } из омиљених
+no-favorites = Још нема омиљених. Користите дугме са звездицом на иконицама да их додате овде.
+collection-of-n-icons = Колекција иконица: { $n }
+n-collection-icons-not-found = Није пронађено иконица из колекције: { $n }
+invalid-collection = Веза ка колекцији није важећа, приказују се све иконице.
+download-all = Преузмите све
+show-all-icons = Прикажи све иконице
+copy-collection-link = Копирај везу ка колекцији
+filters = Филтери
+has-brand-guidelines = Има смернице бренда
+has-license = Има лиценцу
+license-type = Лиценца: { $type }
+has-localized-names = Има локализована имена
+clear-filters = Обриши филтере
+list = Листа
+preview = Преглед
+slug = Слаг
+monochrome = Једнобојно
+brand-color-background = На боји бренда
+brand-color-fill = Бојом бренда
+also-known-as = Познат и као
+old-names = Стари називи
+localized-names = Локализовани називи
+brands-sharing-the-icon = Брендови који деле иконицу
+details = Детаљи
+snippets = Исечци кода
+copy-snippet = Копирај исечак
+export = Извоз
+size = Величина
+fill = Испуна
+background = Позадина
+padding = Унутрашња маргина
+brand-color = Боја бренда
+black = Црна
+white = Бела
+custom-color = Прилагођена боја
+transparent = Провидна
+solid-color = Пуна боја
+webp = WebP
+favicon-pack = пакет фавикона
+download-svg-sprite = Преузмите SVG спрајт
+vector-drawable = Android VectorDrawable
+xaml = XAML
+eps = EPS
+template = Шаблон
+pull-request-card = Картица захтева за спајање
+open-graph-card = Open Graph картица
+square-card = Квадратна картица
+dark-light-card = Картица поређења тамно/светло
+badge-label = Ознака
+badge-message = Порука
+badge-style = Стил
+copy-shields-url = Копирај shields.io URL
+compare = Упореди
+no-comparison = Без
+overlay-comparison = Преклопљено
+side-by-side-comparison = Једно поред другог
+current-path = Тренутна
+edited-path = Измењена
+added-areas = Додато
+removed-areas = Уклоњено
+current-path-not-found = Тренутна иконица бренда не може да се учита
+path-size-change = Величина: { $before } → { $after } бајтова ({ $difference })
+path-bbox-change = Гранични оквир: { $before } → { $after }
+undo = Опозови
+redo = Понови
+history = Историја
+initial-state = Почетно стање
+brand-changed = Бренд: { $before } → { $after }
+color-changed = Боја: #{ $before } → #{ $after }
+path-changed = Путања: { $before } → { $after } бајтова
@@ -62,7 +62,7 @@ license = Licenca
 custom-license = Prilagođena licenca
 subject-icon = ikonica { $icon }
 copy-icon-svg = Kopiraj { $icon } SVG
-error-generating-pdf = Greška pri generisanju PDF-a pomoću PDFKit biblioteke:
+error-generating-pdf = Greška pri generisanju PDF-a:
 view-icon = Pogledaj { $icon }
 copy-hex-color = Kopiraj hex boju
 discord = Discord
@@ -95,3 +95,85 @@ libraries = Biblioteke
 copy-icon-modal-url = Kopiraj URL modala za ikonicu
 copy-icon-url = Kopiraj URL ikonice
 copy-icon-svg-path = Kopiraj SVG putanju ikonice
+no-icons-found = Nema pronađenih ikonica za "{ $search }"
+did-you-mean = Da li ste mislili:
+submit-a-request-for = Pošaljite zahtev za { $brand }
+select-icons = Izaberite ikonice
+n-selected-icons = Izabrano: { $n }
+select-all-results = Izaberite sve rezultate
+clear-selection = Poništite izbor
+download-zip = Preuzmite ZIP
+select-icon = Izaberite { $icon }
+favorites = Omiljene
+add-to-favorites = Dodajte { $icon } u omiljene
+remove-from-favorites = Uklonite { $icon } iz omiljenih
+no-favorites = Još nema omiljenih. Koristite dugme sa zvezdicom na ikonicama da ih dodate ovde.
+collection-of-n-icons = Kolekcija ikonica: { $n }
+n-collection-icons-not-found = Nije pronađeno ikonica iz kolekcije: { $n }
+invalid-collection = Veza ka kolekciji nije važeća, prikazuju se sve ikonice.
+download-all = Preuzmite sve
+show-all-icons = Prikaži sve ikonice
+copy-collection-link = Kopiraj vezu ka kolekciji
+filters = Filteri
+has-brand-guidelines = Ima smernice brenda
+has-license = Ima licencu
+license-type = Licenca: { $type }
+has-localized-names = Ima lokalizovana imena
+clear-filters = Obriši filtere
+list = Lista
+preview = Pregled
+slug = Slag
+monochrome = Jednobojno
+brand-color-background = Na boji brenda
+brand-color-fill = Bojom brenda
+also-known-as = Poznat i kao
+old-names = Stari nazivi
+localized-names = Lokalizovani nazivi
+brands-sharing-the-icon = Brendovi koji dele ikonicu
+details = Detalji
+snippets = Isečci koda
+copy-snippet = Kopiraj isečak
+export = Izvoz
+size = Veličina
+fill = Ispuna
+background = Pozadina
+padding = Unutrašnja margina
+brand-color = Boja brenda
+black = Crna
+white = Bela
+custom-color = Prilagođena boja
+transparent = Providna
+solid-color = Puna boja
+webp = WebP
+favicon-pack = paket favikona
+download-svg-sprite = Preuzmite SVG sprajt
+vector-drawable = Android VectorDrawable
+xaml = XAML
+eps = EPS
+template = Šablon
+pull-request-card = Kartica zahteva za spajanje
+open-graph-card = Open Graph kartica
+square-card = Kvadratna kartica
+dark-light-card = Kartica poređenja tamno/svetlo
+badge-label = Oznaka
+badge-message = Poruka
+badge-style = Stil
+copy-shields-url = Kopiraj shields.io URL
+compare = Uporedi
+no-comparison = Bez
+overlay-comparison = Preklopljeno
+side-by-side-comparison = Jedno pored drugog
+current-path = Trenutna
+edited-path = Izmenjena
+added-areas = Dodato
+removed-areas = Uklonjeno
+current-path-not-found = Trenutna ikonica brenda ne može da se učita
+path-size-change = Veličina: { $before } → { $after } bajtova ({ $difference })
+path-bbox-change = Granični okvir: { $before } → { $after }
+undo = Opozovi
+redo = Ponovi
+history = Istorija
+initial-state = Početno stanje
+brand-changed = Brend: { $before } → { $after }
+color-changed = Boja: #{ $before } → #{ $after }
+path-changed = Putanja: { $before } → { $after } bajtova
@@ -60,7 +60,7 @@ license = Licens
 custom-license = Anpassad licens
 subject-icon = { $icon } ikon
 copy-icon-svg = Kopiera { $icon } SVG
-error-generating-pdf = Fel vid generering av PDF med PDFKit-biblioteket:
+error-generating-pdf = Fel vid generering av PDF:
 view-icon = Visa { $icon }
 copy-hex-color = Kopiera hex-färg
 discord = Discord
@@ -93,3 +93,85 @@ libraries = Bibliotek
 copy-icon-modal-url = Kopiera ikon modal-URL
 copy-icon-url = Kopiera ikon-URL
 copy-icon-svg-path = Kopiera ikon SVG-sökväg
+no-icons-found = Inga ikoner hittades för "{ $search }"
+did-you-mean = Menade du:
+submit-a-request-for = Skicka en förfrågan om { $brand }
+select-icons = Välj ikoner
+n-selected-icons = { $n } valda
+select-all-results = Välj alla resultat
+clear-selection = Rensa markering
+download-zip = Ladda ner ZIP
+select-icon = Välj { $icon }
+favorites = Favoriter
+add-to-favorites = Lägg till { $icon } i favoriter
+remove-from-favorites = Ta bort { $icon } från favoriter
+no-favorites = Inga favoriter ännu. Använd stjärnknappen på ikonerna för att lägga till dem här.
+collection-of-n-icons = Samling med { $n } ikoner
+n-collection-icons-not-found = { $n } ikoner i samlingen hittades inte
+invalid-collection = Länken till samlingen är ogiltig, visar alla ikoner.
+download-all = Ladda ner alla
+show-all-icons = Visa alla ikoner
+copy-collection-link = Kopiera länk till samlingen
+filters = Filter
+has-brand-guidelines = Har varumärkesriktlinjer
+has-license = Har licens
+license-type = Licens: { $type }
+has-localized-names = Har lokaliserade namn
+clear-filters = Rensa filter
+list = Lista
+preview = Förhandsvisning
+slug = Slug
+monochrome = Monokrom
+brand-color-background = På varumärkesfärg
+brand-color-fill = I varumärkesfärg
+also-known-as = Även känd som
+old-names = Tidigare namn
+localized-names = Lokaliserade namn
+brands-sharing-the-icon = Varumärken som delar ikonen
+details = Detaljer
+snippets = Kodsnuttar
+copy-snippet = Kopiera kodsnutt
+export = Exportera
+size = Storlek
+fill = Fyllning
+background = Bakgrund
+padding = Utfyllnad
+brand-color = Varumärkesfärg
+black = Svart
+white = Vit
+custom-color = Anpassad färg
+transparent = Genomskinlig
+solid-color = Enfärgad
+webp = WebP
+favicon-pack = faviconpaket
+download-svg-sprite = Ladda ner SVG-sprite
+vector-drawable = Android VectorDrawable
+xaml = XAML
+eps = EPS
+template = Mall
+pull-request-card = Pull request-kort
+open-graph-card = Open Graph-kort
+square-card = Kvadratiskt kort
+dark-light-card = Jämförelsekort mörkt/ljust
+badge-label = Etikett
+badge-message = Meddelande
+badge-style = Stil
+copy-shields-url = Kopiera shields.io-URL
+compare = Jämför
+no-comparison = Ingen
+overlay-comparison = Överlagrade
+side-by-side-comparison = Sida vid sida
+current-path = Nuvarande
+edited-path = Redigerad
+added-areas = Tillagt
+removed-areas = Borttaget
+current-path-not-found = Varumärkets nuvarande ikon kan inte laddas
+path-size-change = Storlek: { $before } → { $after } byte ({ $difference })
+path-bbox-change = Avgränsningsram: { $before } → { $after }
+undo = Ångra
+redo = Gör om
+history = Historik
+initial-state = Ursprungligt tillstånd
+brand-changed = Varumärke: { $before } → { $after }
+color-changed = Färg: #{ $before } → #{ $after }
+path-changed = Sökväg: { $before } → { $after } byte
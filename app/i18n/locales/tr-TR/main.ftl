@@ -60,7 +60,7 @@ license = Lisans
 custom-license = Özel lisans
 subject-icon = { $icon } ikonu
 copy-icon-svg = { $icon } SVG'sini kopyala
-error-generating-pdf = PDFKit kütüphanesi ile PDF oluşturma hatası:
+error-generating-pdf = PDF oluşturma hatası:
 view-icon = { $icon } görüntüle
 copy-hex-color = Hex rengini kopyala
 discord = Discord
@@ -93,3 +93,85 @@ libraries = Kütüphaneler
 copy-icon-modal-url = İkon modal URL'sini kopyala
 copy-icon-url = İkon URL'sini kopyala
 copy-icon-svg-path = İkon SVG yolunu kopyala
+no-icons-found = "{ $search }" için simge bulunamadı
+did-you-mean = Bunu mu demek istediniz:
+submit-a-request-for = { $brand } için istek gönder
+select-icons = Simge seç
+n-selected-icons = { $n } seçildi
+select-all-results = Tüm sonuçları seç
+clear-selection = Seçimi temizle
+download-zip = ZIP indir
+select-icon = { $icon } seç
+favorites = Favoriler
+add-to-favorites = { $icon } simgesini favorilere ekle
+remove-from-favorites = { $icon } simgesini favorilerden kaldır
+no-favorites = Henüz favori yok. Simgeleri buraya eklemek için yıldız düğmesini kullanın.
+collection-of-n-icons = { $n } simgelik koleksiyon
+n-collection-icons-not-found = Koleksiyondaki { $n } simge bulunamadı
+invalid-collection = Koleksiyon bağlantısı geçersiz, tüm simgeler gösteriliyor.
+download-all = Tümünü indir
+show-all-icons = Tüm simgeleri göster
+copy-collection-link = Koleksiyon bağlantısını kopyala
+filters = Filtreler
+has-brand-guidelines = Marka yönergeleri var
+has-license = Lisansı var
+license-type = Lisans: { $type }
+has-localized-names = Yerelleştirilmiş adları var
+clear-filters = Filtreleri temizle
+list = Liste
+preview = Önizleme
+slug = Slug
+monochrome = Tek renkli
+brand-color-background = Marka rengi üzerinde
+brand-color-fill = Marka renginde
+also-known-as = Diğer adıyla
+old-names = Eski adlar
+localized-names = Yerelleştirilmiş adlar
+brands-sharing-the-icon = Simgeyi paylaşan markalar
+details = Ayrıntılar
+snippets = Kod parçacıkları
+copy-snippet = Parçacığı kopyala
+export = Dışa aktar
+size = Boyut
+fill = Dolgu
+background = Arka plan
+padding = İç boşluk
+brand-color = Marka rengi
+black = Siyah
+white = Beyaz
+custom-color = Özel renk
+transparent = Saydam
+solid-color = Düz renk
+webp = WebP
+favicon-pack = favicon paketi
+download-svg-sprite = SVG sprite indir
+vector-drawable = Android VectorDrawable
+xaml = XAML
+eps = EPS
+template = Şablon
+pull-request-card = Pull request kartı
+open-graph-card = Open Graph kartı
+square-card = Kare kart
+dark-light-card = Koyu/açık karşılaştırma kartı
+badge-label = Etiket
+badge-message = Mesaj
+badge-style = Stil
+copy-shields-url = shields.io URL'sini kopyala
+compare = Karşılaştır
+no-comparison = Yok
+overlay-comparison = Üst üste
+side-by-side-comparison = Yan yana
+current-path = Mevcut
+edited-path = Düzenlenmiş
+added-areas = Eklenen
+removed-areas = Kaldırılan
+current-path-not-found = Markanın mevcut simgesi yüklenemiyor
+path-size-change = Boyut: { $before } → { $after } bayt ({ $difference })
+path-bbox-change = Sınırlayıcı kutu: { $before } → { $after }
+undo = Geri al
+redo = Yinele
+history = Geçmiş
+initial-state = Başlangıç durumu
+brand-changed = Marka: { $before } → { $after }
+color-changed = Renk: #{ $before } → #{ $after }
+path-changed = Yol: { $before } → { $after } bayt
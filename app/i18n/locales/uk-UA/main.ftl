@@ -60,7 +60,7 @@ license = Ліцензія
 custom-license = Власна ліцензія
 subject-icon = значок { $icon }
 copy-icon-svg = Копіювати { $icon } SVG
-error-generating-pdf = Помилка створення PDF-файлу за допомогою бібліотеки PDFKit:
+error-generating-pdf = Помилка створення PDF-файлу:
 view-icon = Переглянути { $icon }
 copy-hex-color = Копіювати hex колір
 discord = Discord
@@ -93,3 +93,85 @@ libraries = Бібліотеки
 copy-icon-modal-url = Копіювати URL модального вікна значка
 copy-icon-url = Копіювати URL значка
 copy-icon-svg-path = Копіювати шлях SVG значка
+no-icons-found = Іконок для «{ $search }» не знайдено
+did-you-mean = Можливо, ви мали на увазі:
+submit-a-request-for = Надіслати запит на { $brand }
+select-icons = Вибрати іконки
+n-selected-icons = Вибрано: { $n }
+select-all-results = Вибрати всі результати
+clear-selection = Скасувати вибір
+download-zip = Завантажити ZIP
+select-icon = Вибрати { $icon }
+favorites = Обране
+add-to-favorites = Додати { $icon } до обраного
+remove-from-favorites = Видалити { $icon } з обраного
+no-favorites = Обраного поки немає. Скористайтеся кнопкою із зіркою на іконках, щоб додати їх сюди.
+collection-of-n-icons = Колекція іконок: { $n }
+n-collection-icons-not-found = Не знайдено іконок колекції: { $n }
+invalid-collection = Посилання на колекцію недійсне, показано всі іконки.
+download-all = Завантажити всі
+show-all-icons = Показати всі іконки
+copy-collection-link = Копіювати посилання на колекцію
+filters = Фільтри
+has-brand-guidelines = Є настанови бренду
+has-license = Є ліцензія
+license-type = Ліцензія: { $type }
+has-localized-names = Є локалізовані назви
+clear-filters = Скинути фільтри
+list = Список
+preview = Попередній перегляд
+slug = Слаг
+monochrome = Монохромний
+brand-color-background = На кольорі бренду
+brand-color-fill = Кольором бренду
+also-known-as = Також відомий як
+old-names = Колишні назви
+localized-names = Локалізовані назви
+brands-sharing-the-icon = Бренди зі спільною іконкою
+details = Подробиці
+snippets = Фрагменти коду
+copy-snippet = Копіювати фрагмент
+export = Експорт
+size = Розмір
+fill = Заливка
+background = Тло
+padding = Відступ
+brand-color = Колір бренду
+black = Чорний
+white = Білий
+custom-color = Власний колір
+transparent = Прозорий
+solid-color = Суцільний колір
+webp = WebP
+favicon-pack = набір фавіконок
+download-svg-sprite = Завантажити SVG-спрайт
+vector-drawable = Android VectorDrawable
+xaml = XAML
+eps = EPS
+template = Шаблон
+pull-request-card = Картка пул-реквесту
+open-graph-card = Картка Open Graph
+square-card = Квадратна картка
+dark-light-card = Картка порівняння темного і світлого
+badge-label = Мітка
+badge-message = Повідомлення
+badge-style = Стиль
+copy-shields-url = Копіювати URL shields.io
+compare = Порівняти
+no-comparison = Немає
+overlay-comparison = Накладання
+side-by-side-comparison = Поруч
+current-path = Поточний
+edited-path = Змінений
+added-areas = Додано
+removed-areas = Видалено
+current-path-not-found = Не вдається завантажити поточну іконку бренду
+path-size-change = Розмір: { $before } → { $after } байт ({ $difference })
+path-bbox-change = Обмежувальна рамка: { $before } → { $after }
+undo = Скасувати
+redo = Повторити
+history = Історія
+initial-state = Початковий стан
+brand-changed = Бренд: { $before } → { $after }
+color-changed = Колір: #{ $before } → #{ $after }
+path-changed = Шлях: { $before } → { $after } байт
@@ -60,7 +60,7 @@ license = 执照
 custom-license = 定制许可证
 subject-icon = { $icon } 图标
 copy-icon-svg = 复制 { $icon } SVG
-error-generating-pdf = 使用 PDFKit 库生成 PDF 时出错：
+error-generating-pdf = 生成 PDF 时出错：
 view-icon = 看法 { $icon }
 copy-hex-color = 复制十六进制颜色
 discord = Discord
@@ -93,3 +93,85 @@ libraries = 第三方库
 copy-icon-modal-url = 复制图标详情 URL
 copy-icon-url = 复制图标 URL
 copy-icon-svg-path = 复制图标 SVG 路径
+no-icons-found = 未找到“{ $search }”的图标
+did-you-mean = 您是不是要找：
+submit-a-request-for = 提交 { $brand } 的请求
+select-icons = 选择图标
+n-selected-icons = 已选择 { $n } 个
+select-all-results = 选择所有结果
+clear-selection = 清除选择
+download-zip = 下载 ZIP
+select-icon = 选择 { $icon }
+favorites = 收藏
+add-to-favorites = 将 { $icon } 添加到收藏
+remove-from-favorites = 将 { $icon } 从收藏中移除
+no-favorites = 还没有收藏。使用图标的星形按钮将其添加到这里。
+collection-of-n-icons = 包含 { $n } 个图标的合集
+n-collection-icons-not-found = 合集中有 { $n } 个图标未找到
+invalid-collection = 合集链接无效，正在显示所有图标。
+download-all = 全部下载
+show-all-icons = 显示所有图标
+copy-collection-link = 复制合集链接
+filters = 筛选
+has-brand-guidelines = 有品牌指南
+has-license = 有许可证
+license-type = 许可证：{ $type }
+has-localized-names = 有本地化名称
+clear-filters = 清除筛选
+list = 列表
+preview = 预览
+slug = 标识符
+monochrome = 单色
+brand-color-background = 品牌色背景
+brand-color-fill = 品牌色填充
+also-known-as = 又称
+old-names = 旧名称
+localized-names = 本地化名称
+brands-sharing-the-icon = 共用此图标的品牌
+details = 详情
+snippets = 代码片段
+copy-snippet = 复制代码片段
+export = 导出
+size = 尺寸
+fill = 填充
+background = 背景
+padding = 内边距
+brand-color = 品牌颜色
+black = 黑色
+white = 白色
+custom-color = 自定义颜色
+transparent = 透明
+solid-color = 纯色
+webp = WebP
+favicon-pack = 网站图标包
+download-svg-sprite = 下载 SVG 雪碧图
+vector-drawable = Android VectorDrawable
+xaml = XAML
+eps = EPS
+template = 模板
+pull-request-card = 拉取请求卡片
+open-graph-card = Open Graph 卡片
+square-card = 方形卡片
+dark-light-card = 深色/浅色对比卡片
+badge-label = 标签
+badge-message = 消息
+badge-style = 样式
+copy-shields-url = 复制 shields.io 网址
+compare = 比较
+no-comparison = 无
+overlay-comparison = 叠加
+side-by-side-comparison = 并排
+current-path = 当前
+edited-path = 已编辑
+added-areas = 新增
+removed-areas = 移除
+current-path-not-found = 无法加载该品牌的当前图标
+path-size-change = 大小：{ $before } → { $after } 字节（{ $difference }）
+path-bbox-change = 边界框：{ $before } → { $after }
+undo = 撤销
+redo = 重做
+history = 历史记录
+initial-state = 初始状态
+brand-changed = 品牌：{ $before } → { $after }
+color-changed = 颜色：#{ $before } → #{ $after }
+path-changed = 路径：{ $before } → { $after } 字节
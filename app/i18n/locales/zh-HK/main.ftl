@@ -60,7 +60,7 @@ license = 許可證
 custom-license = 定製許可證。
 subject-icon = { $icon } 圖標
 copy-icon-svg = 複製 { $icon } SVG
-error-generating-pdf = 使用PDFKit庫生成PDF時出錯：
+error-generating-pdf = 生成PDF時出錯：
 view-icon = 查看 { $icon }
 copy-hex-color = 複製十六進製顏色
 discord = Discord
@@ -93,3 +93,85 @@ libraries = 第三方庫
 copy-icon-modal-url = 复制图标詳情 URL
 copy-icon-url = 复制图标 URL
 copy-icon-svg-path = 复制图标 SVG 路径
+no-icons-found = 找不到「{ $search }」的圖示
+did-you-mean = 你是不是要找：
+submit-a-request-for = 提交 { $brand } 的請求
+select-icons = 選擇圖示
+n-selected-icons = 已選擇 { $n } 個
+select-all-results = 選擇所有結果
+clear-selection = 清除選擇
+download-zip = 下載 ZIP
+select-icon = 選擇 { $icon }
+favorites = 收藏
+add-to-favorites = 將 { $icon } 加入收藏
+remove-from-favorites = 將 { $icon } 從收藏中移除
+no-favorites = 尚未有收藏。使用圖示的星形按鈕將其加入此處。
+collection-of-n-icons = 包含 { $n } 個圖示的合集
+n-collection-icons-not-found = 合集中有 { $n } 個圖示未找到
+invalid-collection = 合集連結無效，正在顯示所有圖示。
+download-all = 全部下載
+show-all-icons = 顯示所有圖示
+copy-collection-link = 複製合集連結
+filters = 篩選
+has-brand-guidelines = 有品牌指引
+has-license = 有授權
+license-type = 授權：{ $type }
+has-localized-names = 有本地化名稱
+clear-filters = 清除篩選
+list = 列表
+preview = 預覽
+slug = 標識符
+monochrome = 單色
+brand-color-background = 品牌色背景
+brand-color-fill = 品牌色填充
+also-known-as = 又稱
+old-names = 舊名稱
+localized-names = 本地化名稱
+brands-sharing-the-icon = 共用此圖示的品牌
+details = 詳情
+snippets = 程式碼片段
+copy-snippet = 複製程式碼片段
+export = 匯出
+size = 尺寸
+fill = 填充
+background = 背景
+padding = 內邊距
+brand-color = 品牌顏色
+black = 黑色
+white = 白色
+custom-color = 自訂顏色
+transparent = 透明
+solid-color = 純色
+webp = WebP
+favicon-pack = 網站圖示包
+download-svg-sprite = 下載 SVG 雪碧圖
+vector-drawable = Android VectorDrawable
+xaml = XAML
+eps = EPS
+template = 範本
+pull-request-card = 拉取請求卡片
+open-graph-card = Open Graph 卡片
+square-card = 方形卡片
+dark-light-card = 深色/淺色對比卡片
+badge-label = 標籤
+badge-message = 訊息
+badge-style = 樣式
+copy-shields-url = 複製 shields.io 網址
+compare = 比較
+no-comparison = 無
+overlay-comparison = 疊加
+side-by-side-comparison = 並排
+current-path = 目前
+edited-path = 已編輯
+added-areas = 新增
+removed-areas = 移除
+current-path-not-found = 無法載入該品牌的目前圖示
+path-size-change = 大小：{ $before } → { $after } 位元組（{ $difference }）
+path-bbox-change = 邊界框：{ $before } → { $after }
+undo = 復原
+redo = 重做
+history = 歷史記錄
+initial-state = 初始狀態
+brand-changed = 品牌：{ $before } → { $after }
+color-changed = 顏色：#{ $before } → #{ $after }
+path-changed = 路徑：{ $before } → { $after } 位元組
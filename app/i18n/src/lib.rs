@@ -1,5 +1,5 @@
 use leptos::prelude::*;
-use leptos_fluent::leptos_fluent;
+use leptos_fluent::{Language, leptos_fluent};
 
 #[component]
 pub fn I18n(
@@ -25,3 +25,28 @@ pub fn I18n(
         customise: |bundle| bundle.set_use_isolating(false),
     }
 }
+
+/// Name of a language of the website by its identifier
+///
+/// Identifiers without region, like `es`, or with a region not available
+/// in the website, like `es-MX`, match the first language of the website
+/// with the same primary language.
+pub fn language_name(
+    languages: &[&'static Language],
+    id: &str,
+) -> Option<&'static str> {
+    fn primary_language(id: &str) -> &str {
+        id.split('-').next().unwrap_or(id)
+    }
+
+    languages
+        .iter()
+        .find(|language| language.id.eq_ignore_ascii_case(id))
+        .or_else(|| {
+            languages.iter().find(|language| {
+                primary_language(language.id)
+                    .eq_ignore_ascii_case(primary_language(id))
+            })
+        })
+        .map(|language| language.name)
+}
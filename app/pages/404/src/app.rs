@@ -1,4 +1,4 @@
-use icondata::{BsGrid3x2GapFill, IoWarningSharp, VsPreview};
+use icondata::{BsGrid3x2GapFill, BsStarFill, IoWarningSharp, VsPreview};
 use leptos::prelude::*;
 use leptos_fluent::move_tr;
 use leptos_icons::Icon;
@@ -40,6 +40,12 @@ pub fn Error404() -> impl IntoView {
                         {move_tr!("deprecations")}
                     </a>
                 </li>
+                <li class="flex p-1">
+                    <a class="button mx-auto" href="/favorites/">
+                        <Icon icon=BsStarFill width="24px" height="24px" />
+                        {move_tr!("favorites")}
+                    </a>
+                </li>
                 <li class="flex p-1">
                     <a class="button mx-auto" href="/preview/">
                         <Icon icon=VsPreview width="24px" height="24px" />
@@ -0,0 +1,33 @@
+use leptos::prelude::*;
+use leptos_fluent::move_tr;
+use simple_icons_website_grid::favorites::Favorites;
+use simple_icons_website_grid_constants::ICONS;
+use simple_icons_website_grid_types::IconsIndexSignal;
+use simple_icons_website_page_layout::{Index, SimpleIconsApp};
+
+pub static TITLE: &str = "Simple Icons | Favorites";
+
+#[component]
+pub fn App() -> impl IntoView {
+    view! {
+        <SimpleIconsApp title=TITLE>
+            <FavoritesIndex />
+        </SimpleIconsApp>
+    }
+}
+
+#[component]
+pub fn FavoritesIndex() -> impl IntoView {
+    let favorites = Favorites::from_localstorage();
+    let no_favorites = favorites.is_empty();
+    provide_context::<IconsIndexSignal>(IconsIndexSignal(
+        favorites.icons(ICONS.iter()),
+    ));
+    view! {
+        <Index />
+        {no_favorites
+            .then(|| {
+                view! { <p class="page-padding-x mt-5 text-center">{move_tr!("no-favorites")}</p> }
+            })}
+    }
+}
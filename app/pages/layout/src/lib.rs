@@ -7,8 +7,12 @@ use leptos_use::{
     use_color_mode_with_options,
 };
 use simple_icons_website_controls::{
-    Controls, download::provide_download_type_context,
-    layout::provide_layout_context, order::provide_order_mode_context,
+    Controls,
+    download::{provide_download_type_context, provide_export_options_context},
+    filters::{filter_icons, provide_filters_context},
+    layout::provide_layout_context,
+    order::provide_order_mode_context,
+    preview_mode::provide_preview_mode_context,
     search::provide_search_context,
 };
 use simple_icons_website_footer::Footer;
@@ -77,11 +81,15 @@ pub fn SimpleIconsApp(
 
 #[component]
 pub fn Index() -> AnyView {
-    let icons = expect_context::<IconsIndexSignal>().0;
+    let initial_filters = provide_filters_context();
+    let icons =
+        filter_icons(&expect_context::<IconsIndexSignal>().0, &initial_filters);
     let initial_search_value = provide_search_context(icons.clone());
     let initial_order_mode = provide_order_mode_context(&initial_search_value);
     provide_download_type_context();
+    provide_export_options_context();
     let initial_layout = provide_layout_context();
+    provide_preview_mode_context();
 
     provide_icons_grid_contexts(
         &initial_search_value,
@@ -1,4 +1,7 @@
 use leptos::prelude::*;
+use simple_icons_website_grid::collection::{
+    IconsCollection, IconsCollectionContext,
+};
 use simple_icons_website_grid_constants::ICONS;
 use simple_icons_website_grid_types::IconsIndexSignal;
 use simple_icons_website_page_layout::{Index, SimpleIconsApp};
@@ -14,8 +17,13 @@ pub fn App() -> impl IntoView {
 
 #[component]
 pub fn AllIconsIndex() -> impl IntoView {
-    provide_context::<IconsIndexSignal>(IconsIndexSignal(
-        ICONS.iter().collect(),
-    ));
+    // When a collection is shared in the URL, only its icons are displayed
+    let collection = IconsCollection::from_url(&ICONS);
+    let icons = match &collection {
+        Some(collection) => collection.icons.clone(),
+        None => ICONS.iter().collect(),
+    };
+    provide_context::<IconsIndexSignal>(IconsIndexSignal(icons));
+    provide_context(IconsCollectionContext(collection));
     view! { <Index /> }
 }
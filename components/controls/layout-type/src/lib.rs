@@ -1,19 +1,94 @@
 use core::str::FromStr;
 
+/// Number of viewports filled by a page of icons
+///
+/// A page must be higher than the viewport, otherwise the footer is
+/// visible after loading a page and the next one is loaded immediately.
+const VIEWPORTS_PER_PAGE: f64 = 1.5;
+
 #[derive(Default, Copy, Clone, PartialEq)]
 pub enum Layout {
     #[default]
     Comfortable,
     Compact,
+    List,
+}
+
+/// Dimensions of the icons grid used to compute the size of the pages
+#[derive(Copy, Clone, PartialEq)]
+pub struct GridDimensions {
+    /// Number of columns of the grid
+    pub columns: usize,
+    /// Number of cells of the grid occupied by the ad
+    pub ad_cells: usize,
+    /// Height of the viewport in pixels
+    pub viewport_height: f64,
+}
+
+impl GridDimensions {
+    /// Estimate the dimensions of the grid before it is rendered
+    pub fn estimate(
+        layout: &Layout,
+        viewport_width: f64,
+        viewport_height: f64,
+    ) -> Self {
+        let columns = match layout {
+            Layout::List => 1,
+            _ => (viewport_width / layout.column_width()).floor() as usize,
+        };
+        Self {
+            columns: columns.max(1),
+            ad_cells: 0,
+            viewport_height,
+        }
+    }
 }
 
 impl Layout {
-    pub fn icons_per_page(&self) -> u8 {
+    /// Approximate width of a column of the grid in pixels, including the gap
+    fn column_width(&self) -> f64 {
         match self {
-            Self::Comfortable => 30,
-            Self::Compact => 60,
+            Self::Comfortable => 220.0,
+            Self::Compact => 136.0,
+            Self::List => f64::INFINITY,
         }
     }
+
+    /// Approximate height of a row of the grid in pixels, including the gap
+    fn row_height(&self) -> f64 {
+        match self {
+            Self::Comfortable => 244.0,
+            Self::Compact => 164.0,
+            Self::List => 41.0,
+        }
+    }
+
+    /// Number of icons loaded per page
+    ///
+    /// Pages are made of whole rows of the grid and fill the viewport.
+    pub fn icons_per_page(&self, dimensions: &GridDimensions) -> usize {
+        let rows = (dimensions.viewport_height * VIEWPORTS_PER_PAGE
+            / self.row_height())
+        .ceil()
+        .max(1.0) as usize;
+        rows * dimensions.columns.max(1)
+    }
+
+    /// Number of icons loaded after loading a page of icons when
+    /// `loaded_icons_length` icons are loaded
+    ///
+    /// The last row of the page is completed, taking into account the cells
+    /// of the grid occupied by the ad.
+    pub fn page_end(
+        &self,
+        loaded_icons_length: usize,
+        dimensions: &GridDimensions,
+    ) -> usize {
+        let cells = dimensions.ad_cells
+            + loaded_icons_length
+            + self.icons_per_page(dimensions);
+        cells.next_multiple_of(dimensions.columns.max(1)) - dimensions.ad_cells
+    }
 }
 
 impl FromStr for Layout {
@@ -23,6 +98,7 @@ impl FromStr for Layout {
         match s {
             "comfortable" => Ok(Self::Comfortable),
             "compact" => Ok(Self::Compact),
+            "list" => Ok(Self::List),
             _ => Err(()),
         }
     }
@@ -33,6 +109,7 @@ impl core::fmt::Display for Layout {
         match self {
             Self::Comfortable => write!(f, "comfortable"),
             Self::Compact => write!(f, "compact"),
+            Self::List => write!(f, "list"),
         }
     }
 }
@@ -0,0 +1,92 @@
+//! Tests of the size of the pages of icons loaded in the grid
+
+use simple_icons_website_controls_layout_type::{GridDimensions, Layout};
+
+fn dimensions(
+    columns: usize,
+    ad_cells: usize,
+    viewport_height: f64,
+) -> GridDimensions {
+    GridDimensions {
+        columns,
+        ad_cells,
+        viewport_height,
+    }
+}
+
+#[test]
+fn pages_are_whole_rows_filling_the_viewport() {
+    // 1.5 viewports of 488 pixels are 3 comfortable rows of 244 pixels
+    assert_eq!(
+        Layout::Comfortable.icons_per_page(&dimensions(5, 0, 488.0)),
+        15
+    );
+    // and 4.46 compact rows of 164 pixels, rounded up to 5 rows
+    assert_eq!(Layout::Compact.icons_per_page(&dimensions(7, 0, 488.0)), 35);
+}
+
+#[test]
+fn pages_have_at_least_a_row() {
+    assert_eq!(
+        Layout::Comfortable.icons_per_page(&dimensions(4, 0, 0.0)),
+        4
+    );
+    assert_eq!(Layout::Compact.icons_per_page(&dimensions(0, 0, 0.0)), 1);
+}
+
+#[test]
+fn list_pages_have_a_row_per_icon() {
+    // 1.5 viewports of 410 pixels are 15 rows of 41 pixels
+    let dimensions = dimensions(1, 0, 410.0);
+    assert_eq!(Layout::List.icons_per_page(&dimensions), 15);
+    assert_eq!(Layout::List.page_end(0, &dimensions), 15);
+    assert_eq!(Layout::List.page_end(15, &dimensions), 30);
+}
+
+#[test]
+fn pages_end_in_whole_rows() {
+    let dimensions = dimensions(5, 0, 488.0);
+    assert_eq!(Layout::Comfortable.page_end(0, &dimensions), 15);
+    assert_eq!(Layout::Comfortable.page_end(15, &dimensions), 30);
+    // A partial row loaded before, like the results of a search, is
+    // completed by the next page
+    assert_eq!(Layout::Comfortable.page_end(12, &dimensions), 30);
+}
+
+#[test]
+fn pages_end_in_whole_rows_with_the_cells_of_the_ad() {
+    // The ad occupies 2 cells of the first row, so the first page ends
+    // at the end of its fourth row: 4 * 5 - 2 = 18 icons
+    let dimensions = dimensions(5, 2, 488.0);
+    assert_eq!(Layout::Comfortable.page_end(0, &dimensions), 18);
+    assert_eq!(Layout::Comfortable.page_end(18, &dimensions), 33);
+
+    // An ad that fills whole rows doesn't add a row
+    let dimensions = GridDimensions {
+        ad_cells: 10,
+        ..dimensions
+    };
+    assert_eq!(Layout::Comfortable.page_end(0, &dimensions), 15);
+}
+
+#[test]
+fn estimated_dimensions() {
+    let comfortable =
+        GridDimensions::estimate(&Layout::Comfortable, 1100.0, 800.0);
+    assert_eq!(comfortable.columns, 5);
+    assert_eq!(comfortable.ad_cells, 0);
+    assert_eq!(comfortable.viewport_height, 800.0);
+
+    assert_eq!(
+        GridDimensions::estimate(&Layout::Compact, 1100.0, 800.0).columns,
+        8
+    );
+    assert_eq!(
+        GridDimensions::estimate(&Layout::List, 1100.0, 800.0).columns,
+        1
+    );
+    assert_eq!(
+        GridDimensions::estimate(&Layout::Comfortable, 100.0, 800.0).columns,
+        1
+    );
+}
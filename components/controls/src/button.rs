@@ -79,20 +79,3 @@ pub fn ControlButtonIcon(
         </ControlButton>
     }
 }
-
-/// Control button made from text
-#[component]
-pub fn ControlButtonText(
-    /// Button title
-    title: Signal<String>,
-    /// Button text
-    text: Signal<String>,
-    /// The control is active
-    active: Signal<bool>,
-) -> impl IntoView {
-    view! {
-        <ControlButton active attr:title=title>
-            <span>{text}</span>
-        </ControlButton>
-    }
-}
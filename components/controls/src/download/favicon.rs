@@ -0,0 +1,175 @@
+use super::{download_bytes, svg::fetch_icon_path};
+use icon_format::{Color, ColorError};
+use icon_rasterizer::{
+    ImageFormat, RasterOptions, RasterizeError, rasterize, rasterize_ico,
+};
+use leptos::task::spawn_local;
+use simple_icons_website_types::SimpleIcon;
+use snafu::prelude::*;
+use zip_writer::{ZipError, ZipWriter};
+
+/// Sizes of the images of the `favicon.ico` file
+static ICO_SIZES: [u32; 3] = [16, 32, 48];
+
+/// Size of the Apple touch icon
+static APPLE_TOUCH_ICON_SIZE: u32 = 180;
+
+/// Sizes of the icons of the web app manifest
+static MANIFEST_ICON_SIZES: [u32; 2] = [192, 512];
+
+/// Padding of the Apple touch icon, which is displayed over its background
+static APPLE_TOUCH_ICON_PADDING: u32 = 15;
+
+/// Padding of the maskable icons
+///
+/// Maskable icons are cropped by a circle of 80% of the size of the image
+/// at most, so the icon must fit inside that safe zone. With a padding of
+/// 40% the icon covers 24 / 43.2 ≈ 55.6% of the image, which is less than
+/// the side of the square inscribed in the circle (80% / √2 ≈ 56.6%).
+static MASKABLE_ICON_PADDING: u32 = 40;
+
+/// Errors that can occur building a favicon pack
+#[derive(Debug, Snafu)]
+pub enum FaviconPackError {
+    /// The brand color of the icon is not valid
+    #[snafu(display("{source}"), context(false))]
+    Color { source: ColorError },
+
+    /// An image of the icon can't be rendered
+    #[snafu(display("{source}"), context(false))]
+    Rasterize { source: RasterizeError },
+
+    /// The images can't be bundled in the archive
+    #[snafu(display("{source}"), context(false))]
+    Zip { source: ZipError },
+}
+
+/// Color of the icon over its brand color, so it stays legible
+fn contrast_color(icon: &SimpleIcon) -> Color {
+    match icon.hex_is_relatively_light {
+        true => Color::new(0, 0, 0),
+        false => Color::new(255, 255, 255),
+    }
+}
+
+/// Content of the `manifest.webmanifest` file of the favicon pack
+fn web_app_manifest(icon: &SimpleIcon) -> String {
+    let mut icons = MANIFEST_ICON_SIZES
+        .iter()
+        .map(|size| {
+            serde_json::json!({
+                "src": format!("icon-{size}.png"),
+                "sizes": format!("{size}x{size}"),
+                "type": "image/png",
+                "purpose": "any",
+            })
+        })
+        .collect::<Vec<_>>();
+    icons.extend(MANIFEST_ICON_SIZES.iter().map(|size| {
+        serde_json::json!({
+            "src": format!("maskable-icon-{size}.png"),
+            "sizes": format!("{size}x{size}"),
+            "type": "image/png",
+            "purpose": "maskable",
+        })
+    }));
+    serde_json::to_string_pretty(&serde_json::json!({
+        "name": icon.title,
+        "short_name": icon.title,
+        "icons": icons,
+        "theme_color": format!("#{}", icon.hex),
+        "background_color": format!("#{}", icon.hex),
+        "display": "standalone",
+    }))
+    .unwrap()
+}
+
+/// Build a ZIP archive with favicons and app icons of an icon given its
+/// SVG path data
+///
+/// The archive contains:
+///
+/// - `favicon.ico` with images of 16, 32 and 48 pixels in the brand color.
+/// - `apple-touch-icon.png` over the brand color.
+/// - `icon-192.png` and `icon-512.png` in the brand color.
+/// - `maskable-icon-192.png` and `maskable-icon-512.png` over the brand
+///   color, with the icon inside the safe zone of maskable icons.
+/// - `manifest.webmanifest` referencing the icons of the web app.
+pub fn favicon_pack(
+    icon: &SimpleIcon,
+    path: &str,
+) -> Result<Vec<u8>, FaviconPackError> {
+    let brand_color = Color::from_hex(icon.hex)?;
+    let mut zip = ZipWriter::new();
+
+    let ico = rasterize_ico(
+        path,
+        &RasterOptions {
+            fill: brand_color,
+            ..Default::default()
+        },
+        &ICO_SIZES,
+    )?;
+    zip.add_file("favicon.ico", &ico)?;
+
+    let apple_touch_icon = rasterize(
+        path,
+        &RasterOptions {
+            size: APPLE_TOUCH_ICON_SIZE,
+            fill: contrast_color(icon),
+            background: Some(brand_color),
+            padding: APPLE_TOUCH_ICON_PADDING,
+        },
+        ImageFormat::Png,
+    )?;
+    zip.add_file("apple-touch-icon.png", &apple_touch_icon)?;
+
+    for size in MANIFEST_ICON_SIZES {
+        let any_icon = rasterize(
+            path,
+            &RasterOptions {
+                size,
+                fill: brand_color,
+                background: None,
+                padding: 0,
+            },
+            ImageFormat::Png,
+        )?;
+        zip.add_file(&format!("icon-{size}.png"), &any_icon)?;
+
+        let maskable_icon = rasterize(
+            path,
+            &RasterOptions {
+                size,
+                fill: contrast_color(icon),
+                background: Some(brand_color),
+                padding: MASKABLE_ICON_PADDING,
+            },
+            ImageFormat::Png,
+        )?;
+        zip.add_file(&format!("maskable-icon-{size}.png"), &maskable_icon)?;
+    }
+
+    zip.add_file("manifest.webmanifest", web_app_manifest(icon).as_bytes())?;
+    Ok(zip.finish()?)
+}
+
+/// Get the ZIP archive with favicons and app icons of an icon
+pub async fn export_favicon_pack(icon: &SimpleIcon) -> Result<Vec<u8>, String> {
+    let path = fetch_icon_path(icon).await?;
+    favicon_pack(icon, &path).map_err(|error| error.to_string())
+}
+
+/// Download a ZIP archive with favicons and app icons of an icon
+pub fn download_favicon_pack(icon: &'static SimpleIcon) {
+    spawn_local(async move {
+        match export_favicon_pack(icon).await {
+            Ok(zip) => download_bytes(
+                &format!("{}-favicons.zip", icon.slug),
+                &zip,
+                "application/zip",
+            ),
+            Err(error) => web_sys::console::error_1(&error.into()),
+        }
+    });
+}
@@ -1,35 +1,102 @@
-use wasm_bindgen::prelude::*;
+use super::{download_bytes, options::ExportOptions, svg::fetch_icon_path};
+use icon_rasterizer::{ImageFormat, rasterize};
+use leptos::{prelude::window, task::spawn_local};
+use simple_icons_website_types::SimpleIcon;
+use web_sys_simple_copy::copy_image_bytes;
 
-#[wasm_bindgen(module = "/src/download/image.js")]
-extern "C" {
-    pub fn download_png_(slug: &str);
-    pub fn download_jpg_(slug: &str);
-    pub fn copy_as_base64_jpg_(slug: &str);
-    pub fn copy_as_base64_png_(slug: &str);
-    pub fn copy_as_image_png_(slug: &str);
-    pub fn copy_as_image_jpg_(slug: &str);
+/// Rasterize an icon in the given format with the export options
+pub async fn rasterize_icon(
+    icon: &SimpleIcon,
+    options: &ExportOptions,
+    format: ImageFormat,
+) -> Result<Vec<u8>, String> {
+    let path = fetch_icon_path(icon).await?;
+    let raster_options = options
+        .raster_options(icon.hex)
+        .map_err(|error| error.to_string())?;
+    rasterize(&path, &raster_options, format).map_err(|error| error.to_string())
 }
 
-pub fn download_png(slug: &str) {
-    download_png_(slug);
+/// Rasterize an icon and pass the bytes of the image to a callback
+fn with_rasterized_icon(
+    icon: &'static SimpleIcon,
+    options: &ExportOptions,
+    format: ImageFormat,
+    callback: impl FnOnce(Vec<u8>) + 'static,
+) {
+    let options = options.clone();
+    spawn_local(async move {
+        match rasterize_icon(icon, &options, format).await {
+            Ok(bytes) => callback(bytes),
+            Err(error) => web_sys::console::error_1(&error.into()),
+        }
+    });
 }
 
-pub fn download_jpg(slug: &str) {
-    download_jpg_(slug);
+fn download_image(
+    icon: &'static SimpleIcon,
+    options: &ExportOptions,
+    format: ImageFormat,
+) {
+    with_rasterized_icon(icon, options, format, move |bytes| {
+        download_bytes(
+            &format!("{}.{}", icon.slug, format.extension()),
+            &bytes,
+            format.mime_type(),
+        );
+    });
 }
 
-pub fn copy_as_image_png(slug: &str) {
-    copy_as_image_png_(slug);
+/// Copy an icon to the clipboard as an image
+///
+/// The clipboard of the browsers only accepts PNG images, so the icon is
+/// always copied as PNG whatever the format chosen.
+fn copy_as_image(icon: &'static SimpleIcon, options: &ExportOptions) {
+    let format = ImageFormat::Png;
+    with_rasterized_icon(icon, options, format, move |bytes| {
+        copy_image_bytes(&bytes, format.mime_type());
+    });
 }
 
-pub fn copy_as_image_jpg(slug: &str) {
-    copy_as_image_jpg_(slug);
+fn copy_as_base64(
+    icon: &'static SimpleIcon,
+    options: &ExportOptions,
+    format: ImageFormat,
+) {
+    with_rasterized_icon(icon, options, format, move |bytes| {
+        let binary = bytes.iter().map(|byte| *byte as char).collect::<String>();
+        let base64 = window().btoa(&binary).unwrap();
+        _ = window().navigator().clipboard().write_text(&format!(
+            "data:{};base64,{base64}",
+            format.mime_type()
+        ));
+    });
 }
 
-pub fn copy_as_base64_jpg(slug: &str) {
-    copy_as_base64_jpg_(slug);
+pub fn download_png(icon: &'static SimpleIcon, options: &ExportOptions) {
+    download_image(icon, options, ImageFormat::Png);
 }
 
-pub fn copy_as_base64_png(slug: &str) {
-    copy_as_base64_png_(slug);
+pub fn download_jpg(icon: &'static SimpleIcon, options: &ExportOptions) {
+    download_image(icon, options, ImageFormat::Jpeg);
+}
+
+pub fn download_webp(icon: &'static SimpleIcon, options: &ExportOptions) {
+    download_image(icon, options, ImageFormat::Webp);
+}
+
+pub fn copy_as_image_png(icon: &'static SimpleIcon, options: &ExportOptions) {
+    copy_as_image(icon, options);
+}
+
+pub fn copy_as_image_jpg(icon: &'static SimpleIcon, options: &ExportOptions) {
+    copy_as_image(icon, options);
+}
+
+pub fn copy_as_base64_jpg(icon: &'static SimpleIcon, options: &ExportOptions) {
+    copy_as_base64(icon, options, ImageFormat::Jpeg);
+}
+
+pub fn copy_as_base64_png(icon: &'static SimpleIcon, options: &ExportOptions) {
+    copy_as_base64(icon, options, ImageFormat::Png);
 }
@@ -1,26 +1,99 @@
+pub mod favicon;
 pub mod image;
+pub mod options;
 pub mod pdf;
+pub mod sprite;
 pub mod svg;
+pub mod vector;
+pub mod zip;
 
-use crate::button::ControlButtonText;
+use crate::button::ControlButtonIcon;
+pub use favicon::download_favicon_pack;
 pub use image::{
     copy_as_base64_jpg, copy_as_base64_png, copy_as_image_jpg,
-    copy_as_image_png, download_jpg, download_png,
+    copy_as_image_png, download_jpg, download_png, download_webp,
 };
 use leptos::prelude::{document, *};
 use leptos_fluent::{move_tr, tr};
-pub use pdf::{add_pdfkit_scripts, download_pdf};
+pub use options::{
+    ExportOptions, ExportOptionsPanel, ExportOptionsSignal,
+    provide_export_options_context,
+};
+pub use pdf::{ExportPdfError, alert_pdf_error, download_pdf, export_pdf};
 use simple_icons_website_storage::LocalStorage;
+use simple_icons_website_types::SimpleIcon;
 use simple_icons_website_url as Url;
+pub use sprite::download_icons_sprite;
 use std::str::FromStr;
-pub use svg::download_svg;
+pub use svg::{download_colored_svg, download_svg, export_svg, svg_path};
+pub use vector::{download_eps, download_vector_drawable, download_xaml};
 use wasm_bindgen::JsCast;
+pub use zip::download_icons_zip;
 
+/// Format in which the icons are downloaded from the grid
 #[derive(Default, Copy, Clone, PartialEq)]
 pub enum DownloadType {
     #[default]
     SVG,
+    /// SVG filled with the brand color
+    ColoredSVG,
+    PDF,
     PNG,
+    JPG,
+    WebP,
+    /// Android VectorDrawable
+    VectorDrawable,
+    XAML,
+    EPS,
+    /// ZIP archive with favicons and app icons
+    FaviconPack,
+}
+
+impl DownloadType {
+    /// All the download types, in the order they are displayed
+    pub const ALL: [Self; 10] = [
+        Self::SVG,
+        Self::ColoredSVG,
+        Self::PDF,
+        Self::PNG,
+        Self::JPG,
+        Self::WebP,
+        Self::VectorDrawable,
+        Self::XAML,
+        Self::EPS,
+        Self::FaviconPack,
+    ];
+
+    /// Translated name of the file type
+    fn filetype(&self) -> String {
+        match self {
+            Self::SVG => tr!("svg"),
+            Self::ColoredSVG => tr!("colored-svg"),
+            Self::PDF => tr!("pdf"),
+            Self::PNG => tr!("png"),
+            Self::JPG => tr!("jpg"),
+            Self::WebP => tr!("webp"),
+            Self::VectorDrawable => tr!("vector-drawable"),
+            Self::XAML => tr!("xaml"),
+            Self::EPS => tr!("eps"),
+            Self::FaviconPack => tr!("favicon-pack"),
+        }
+    }
+
+    fn icon(&self) -> icondata::Icon {
+        match self {
+            Self::SVG => icondata::TbSvgOutline,
+            Self::ColoredSVG => icondata::IoColorWand,
+            Self::PDF => icondata::TbPdfOutline,
+            Self::PNG => icondata::TbPngOutline,
+            Self::JPG => icondata::TbJpgOutline,
+            Self::WebP => icondata::TbPhotoOutline,
+            Self::VectorDrawable => icondata::TbBrandAndroidOutline,
+            Self::XAML => icondata::TbBrandWindowsOutline,
+            Self::EPS => icondata::TbFileVectorOutline,
+            Self::FaviconPack => icondata::TbAppWindowOutline,
+        }
+    }
 }
 
 impl FromStr for DownloadType {
@@ -28,8 +101,17 @@ impl FromStr for DownloadType {
 
     fn from_str(value: &str) -> Result<Self, Self::Err> {
         match value {
+            "svg" => Ok(Self::SVG),
+            "colored-svg" => Ok(Self::ColoredSVG),
+            "pdf" => Ok(Self::PDF),
             "png" => Ok(Self::PNG),
-            _ => Ok(Self::SVG),
+            "jpg" => Ok(Self::JPG),
+            "webp" => Ok(Self::WebP),
+            "vector-drawable" => Ok(Self::VectorDrawable),
+            "xaml" => Ok(Self::XAML),
+            "eps" => Ok(Self::EPS),
+            "favicon-pack" => Ok(Self::FaviconPack),
+            _ => Err(()),
         }
     }
 }
@@ -38,7 +120,15 @@ impl core::fmt::Display for DownloadType {
     fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
         match self {
             Self::SVG => write!(f, "svg"),
+            Self::ColoredSVG => write!(f, "colored-svg"),
+            Self::PDF => write!(f, "pdf"),
             Self::PNG => write!(f, "png"),
+            Self::JPG => write!(f, "jpg"),
+            Self::WebP => write!(f, "webp"),
+            Self::VectorDrawable => write!(f, "vector-drawable"),
+            Self::XAML => write!(f, "xaml"),
+            Self::EPS => write!(f, "eps"),
+            Self::FaviconPack => write!(f, "favicon-pack"),
         }
     }
 }
@@ -50,10 +140,19 @@ pub fn provide_download_type_context() {
 #[derive(Copy, Clone)]
 pub struct DownloadTypeSignal(pub RwSignal<DownloadType>);
 
+/// Download type from the URL, or from the local storage if the URL
+/// doesn't define a valid one
 fn initial_download_type() -> DownloadType {
-    match Url::params::get(&Url::params::Names::DownloadType)
-        .and_then(|value| value.parse().ok())
-    {
+    let from_url = Url::params::get(&Url::params::Names::DownloadType)
+        .and_then(|value| match value.parse() {
+            Ok(download_type) => Some(download_type),
+            Err(()) => {
+                let msg = format!("Invalid download type in the URL: {value}");
+                web_sys::console::warn_1(&msg.into());
+                None
+            }
+        });
+    match from_url {
         Some(download_type) => {
             set_download_type_on_localstorage(&download_type);
             download_type
@@ -62,10 +161,19 @@ fn initial_download_type() -> DownloadType {
     }
 }
 
+/// Download type from the local storage, removing it if it's not valid
 fn get_download_type_from_localstorage() -> Option<DownloadType> {
-    LocalStorage::get(LocalStorage::Keys::DownloadType)
-        .as_ref()
-        .and_then(|value| DownloadType::from_str(value).ok())
+    let value = LocalStorage::get(LocalStorage::Keys::DownloadType)?;
+    match DownloadType::from_str(&value) {
+        Ok(download_type) => Some(download_type),
+        Err(()) => {
+            let msg =
+                format!("Invalid download type in the local storage: {value}");
+            web_sys::console::warn_1(&msg.into());
+            LocalStorage::remove(LocalStorage::Keys::DownloadType);
+            None
+        }
+    }
 }
 
 fn set_download_type_on_localstorage(download_type: &DownloadType) {
@@ -83,29 +191,53 @@ pub fn DownloadFileTypeControl() -> impl IntoView {
         <div class="control">
             <label>{move || tr!("download")}</label>
             <div class="flex flex-row">
-                <ControlButtonText
-                    text=move_tr!("svg")
-                    title=move_tr!("download-filetype", {"filetype" => tr!("svg") })
-                    active=Signal::derive(move || { download_type() == DownloadType::SVG })
-                    on:click=move |_| {
-                        download_type.set(DownloadType::SVG);
-                        set_download_type_on_localstorage(&DownloadType::SVG);
-                    }
-                />
-                <ControlButtonText
-                    text=move_tr!("png")
-                    title=move_tr!("download-filetype", { "filetype" => tr!("png") })
-                    active=Signal::derive(move || { download_type() == DownloadType::PNG })
-                    on:click=move |_| {
-                        download_type.set(DownloadType::PNG);
-                        set_download_type_on_localstorage(&DownloadType::PNG);
-                    }
-                />
+                {DownloadType::ALL
+                    .into_iter()
+                    .map(|type_| {
+                        view! {
+                            <ControlButtonIcon
+                                title=move_tr!(
+                                    "download-filetype", { "filetype" => type_.filetype() }
+                                )
+                                icon=type_.icon()
+                                active=Signal::derive(move || download_type() == type_)
+                                on:click=move |_| {
+                                    download_type.set(type_);
+                                    set_download_type_on_localstorage(&type_);
+                                }
+                            />
+                        }
+                    })
+                    .collect_view()}
             </div>
         </div>
     }
 }
 
+/// Download an icon in the given download type with the export options
+///
+/// The PDFs are generated before returning, so their errors can be shown
+/// to the user. The other types are downloaded in the background.
+pub async fn download_icon(
+    icon: &'static SimpleIcon,
+    download_type: &DownloadType,
+    options: &ExportOptions,
+) -> Result<(), ExportPdfError> {
+    match download_type {
+        DownloadType::SVG => download_svg(icon, options),
+        DownloadType::ColoredSVG => download_colored_svg(icon, options),
+        DownloadType::PDF => download_pdf(icon, options).await?,
+        DownloadType::PNG => download_png(icon, options),
+        DownloadType::JPG => download_jpg(icon, options),
+        DownloadType::WebP => download_webp(icon, options),
+        DownloadType::VectorDrawable => download_vector_drawable(icon, options),
+        DownloadType::XAML => download_xaml(icon, options),
+        DownloadType::EPS => download_eps(icon, options),
+        DownloadType::FaviconPack => download_favicon_pack(icon),
+    }
+    Ok(())
+}
+
 /// Download a SVG icon by its slug
 pub fn download(filename: &str, href: &str) {
     let link = document()
@@ -117,3 +249,16 @@ pub fn download(filename: &str, href: &str) {
     _ = link.set_attribute("href", href);
     link.click();
 }
+
+/// Download a file with the given content
+pub fn download_bytes(filename: &str, bytes: &[u8], mime_type: &str) {
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob =
+        web_sys::Blob::new_with_u8_array_sequence_and_options(&parts, &options)
+            .unwrap();
+    let url = web_sys::Url::create_object_url_with_blob(&blob).unwrap();
+    download(filename, &url);
+    _ = web_sys::Url::revoke_object_url(&url);
+}
@@ -0,0 +1,364 @@
+use icon_format::{Color, ColorError, VIEW_BOX_SIZE};
+use icon_pdf::PdfOptions;
+use icon_rasterizer::RasterOptions;
+use leptos::prelude::*;
+use leptos_fluent::move_tr;
+use simple_icons_website_storage::LocalStorage;
+use std::str::FromStr;
+
+/// Sizes in pixels offered to export the icons
+pub static EXPORT_SIZE_PRESETS: [u32; 10] =
+    [16, 24, 32, 48, 64, 128, 256, 512, 640, 1024];
+
+/// Maximum padding around the exported icons, as a percentage of the size
+static MAX_PADDING: u32 = 40;
+
+/// Color used to fill the exported icons
+#[derive(Default, Clone, PartialEq)]
+pub enum ExportFill {
+    /// Brand color of the icon
+    Brand,
+    /// Color of the icons without fill
+    #[default]
+    Black,
+    White,
+    /// Hex color chosen by the user, without `#`
+    Custom(String),
+}
+
+impl ExportFill {
+    /// Hex color of the fill, without `#`, given the hex of the icon
+    pub fn hex<'a>(&'a self, icon_hex: &'a str) -> &'a str {
+        match self {
+            Self::Brand => icon_hex,
+            Self::Black => "000000",
+            Self::White => "FFFFFF",
+            Self::Custom(hex) => hex,
+        }
+    }
+}
+
+/// Options applied to the icons when exporting them
+#[derive(Clone, PartialEq)]
+pub struct ExportOptions {
+    /// Width and height of the exported images in pixels
+    pub size: u32,
+    pub fill: ExportFill,
+    /// Hex color of the background, without `#`, transparent if `None`
+    pub background: Option<String>,
+    /// Padding around the icon as a percentage of the size
+    pub padding: u32,
+}
+
+impl Default for ExportOptions {
+    fn default() -> Self {
+        Self {
+            size: 640,
+            fill: ExportFill::default(),
+            background: None,
+            padding: 0,
+        }
+    }
+}
+
+/// Parse a hex color with or without `#`, returning it without `#`
+fn parse_hex(value: &str) -> Option<String> {
+    let hex = value.strip_prefix('#').unwrap_or(value);
+    (hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()))
+        .then(|| hex.to_ascii_uppercase())
+}
+
+/// Text between two delimiters
+fn between<'a>(text: &'a str, start: &str, end: &str) -> Option<&'a str> {
+    text.split_once(start)?
+        .1
+        .split_once(end)
+        .map(|(value, _)| value)
+}
+
+impl FromStr for ExportOptions {
+    type Err = ();
+
+    /// Parse the options from the format `size,fill,background,padding`
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let [size, fill, background, padding] = value
+            .split(',')
+            .collect::<Vec<_>>()
+            .try_into()
+            .map_err(|_| ())?;
+        Ok(Self {
+            size: size.parse().map_err(|_| ())?,
+            fill: match fill {
+                "brand" => ExportFill::Brand,
+                "black" => ExportFill::Black,
+                "white" => ExportFill::White,
+                hex => ExportFill::Custom(parse_hex(hex).ok_or(())?),
+            },
+            background: match background {
+                "transparent" => None,
+                hex => Some(parse_hex(hex).ok_or(())?),
+            },
+            padding: padding.parse::<u32>().map_err(|_| ())?.min(MAX_PADDING),
+        })
+    }
+}
+
+impl core::fmt::Display for ExportOptions {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(
+            f,
+            "{},{},{},{}",
+            self.size,
+            match &self.fill {
+                ExportFill::Brand => "brand",
+                ExportFill::Black => "black",
+                ExportFill::White => "white",
+                ExportFill::Custom(hex) => hex,
+            },
+            self.background.as_deref().unwrap_or("transparent"),
+            self.padding,
+        )
+    }
+}
+
+impl ExportOptions {
+    /// Options to rasterize an icon given its hex color
+    pub fn raster_options(
+        &self,
+        icon_hex: &str,
+    ) -> Result<RasterOptions, ColorError> {
+        Ok(RasterOptions {
+            size: self.size,
+            fill: Color::from_hex(self.fill.hex(icon_hex))?,
+            background: self
+                .background
+                .as_deref()
+                .map(Color::from_hex)
+                .transpose()?,
+            padding: self.padding,
+        })
+    }
+
+    /// Options to generate the PDF of an icon given its hex color
+    ///
+    /// The size of the images in pixels is used as the size of the
+    /// page in points.
+    pub fn pdf_options(
+        &self,
+        icon_hex: &str,
+    ) -> Result<PdfOptions, ColorError> {
+        Ok(PdfOptions {
+            page_size: self.size,
+            fill: Color::from_hex(self.fill.hex(icon_hex))?,
+            background: self
+                .background
+                .as_deref()
+                .map(Color::from_hex)
+                .transpose()?,
+            padding: self.padding,
+        })
+    }
+
+    /// Apply the options to the SVG of an icon given its hex color
+    ///
+    /// The SVG is returned untouched with the default options, so the
+    /// icons are exported as they are distributed.
+    pub fn apply_to_svg(&self, svg: &str, icon_hex: &str) -> String {
+        if *self == Self::default() {
+            return svg.to_string();
+        }
+        let title = between(svg, "<title>", "</title>").unwrap_or_default();
+        let path = between(svg, "<path d=\"", "\"").unwrap_or_default();
+
+        let padding = VIEW_BOX_SIZE * self.padding as f64 / 100.0;
+        let origin = 0.0 - padding;
+        let side = VIEW_BOX_SIZE + 2.0 * padding;
+        let background = match &self.background {
+            Some(hex) => format!(
+                "<rect x=\"{origin}\" y=\"{origin}\" width=\"{side}\" height=\"{side}\" fill=\"#{hex}\"/>"
+            ),
+            None => String::new(),
+        };
+        format!(
+            concat!(
+                "<svg role=\"img\" viewBox=\"{origin} {origin} {side} {side}\"",
+                " width=\"{size}\" height=\"{size}\"",
+                " xmlns=\"http://www.w3.org/2000/svg\">",
+                "<title>{title}</title>{background}",
+                "<path fill=\"#{fill}\" d=\"{path}\"/></svg>",
+            ),
+            origin = origin,
+            side = side,
+            size = self.size,
+            title = title,
+            background = background,
+            fill = self.fill.hex(icon_hex),
+            path = path,
+        )
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct ExportOptionsSignal(pub RwSignal<ExportOptions>);
+
+pub fn provide_export_options_context() {
+    provide_context(ExportOptionsSignal(RwSignal::new(
+        get_export_options_from_localstorage().unwrap_or_default(),
+    )));
+}
+
+fn get_export_options_from_localstorage() -> Option<ExportOptions> {
+    LocalStorage::get(LocalStorage::Keys::ExportOptions)
+        .as_ref()
+        .and_then(|value| value.parse().ok())
+}
+
+fn set_export_options_on_localstorage(options: &ExportOptions) {
+    LocalStorage::set(LocalStorage::Keys::ExportOptions, &options.to_string());
+}
+
+/// Panel to configure the options applied when exporting the icons
+#[component]
+pub fn ExportOptionsPanel() -> impl IntoView {
+    let options = expect_context::<ExportOptionsSignal>().0;
+
+    let update_options = move |update: &dyn Fn(&mut ExportOptions)| {
+        options.update(|options| update(options));
+        set_export_options_on_localstorage(&options.get_untracked());
+    };
+
+    let fill_mode = move || match options.read().fill {
+        ExportFill::Brand => "brand",
+        ExportFill::Black => "black",
+        ExportFill::White => "white",
+        ExportFill::Custom(_) => "custom",
+    };
+
+    view! {
+        <div class="export-options">
+            <label>
+                <span>{move_tr!("size")}</span>
+                <select on:change=move |ev| {
+                    if let Ok(size) = event_target_value(&ev).parse() {
+                        update_options(&|options| options.size = size);
+                    }
+                }>
+                    {EXPORT_SIZE_PRESETS
+                        .iter()
+                        .map(|size| {
+                            view! {
+                                <option value=*size selected=move || options.read().size == *size>
+                                    {format!("{size}×{size}")}
+                                </option>
+                            }
+                        })
+                        .collect_view()}
+                </select>
+            </label>
+            <label>
+                <span>{move_tr!("fill")}</span>
+                <select on:change=move |ev| {
+                    let fill = match event_target_value(&ev).as_str() {
+                        "brand" => ExportFill::Brand,
+                        "white" => ExportFill::White,
+                        "custom" => ExportFill::Custom("000000".to_string()),
+                        _ => ExportFill::Black,
+                    };
+                    update_options(&|options| options.fill = fill.clone());
+                }>
+                    <option value="brand" selected=move || fill_mode() == "brand">
+                        {move_tr!("brand-color")}
+                    </option>
+                    <option value="black" selected=move || fill_mode() == "black">
+                        {move_tr!("black")}
+                    </option>
+                    <option value="white" selected=move || fill_mode() == "white">
+                        {move_tr!("white")}
+                    </option>
+                    <option value="custom" selected=move || fill_mode() == "custom">
+                        {move_tr!("custom-color")}
+                    </option>
+                </select>
+                {move || match &options.read().fill {
+                    ExportFill::Custom(hex) => {
+                        Some(
+                            view! {
+                                <input
+                                    type="color"
+                                    prop:value=format!("#{}", hex.to_ascii_lowercase())
+                                    on:change=move |ev| {
+                                        if let Some(hex) = parse_hex(&event_target_value(&ev)) {
+                                            update_options(
+                                                &|options| {
+                                                    options.fill = ExportFill::Custom(hex.clone())
+                                                },
+                                            );
+                                        }
+                                    }
+                                />
+                            },
+                        )
+                    }
+                    _ => None,
+                }}
+            </label>
+            <label>
+                <span>{move_tr!("background")}</span>
+                <select on:change=move |ev| {
+                    let background = match event_target_value(&ev).as_str() {
+                        "solid" => Some("FFFFFF".to_string()),
+                        _ => None,
+                    };
+                    update_options(&|options| options.background = background.clone());
+                }>
+                    <option
+                        value="transparent"
+                        selected=move || options.read().background.is_none()
+                    >
+                        {move_tr!("transparent")}
+                    </option>
+                    <option value="solid" selected=move || options.read().background.is_some()>
+                        {move_tr!("solid-color")}
+                    </option>
+                </select>
+                {move || {
+                    options
+                        .read()
+                        .background
+                        .as_ref()
+                        .map(|hex| {
+                            view! {
+                                <input
+                                    type="color"
+                                    prop:value=format!("#{}", hex.to_ascii_lowercase())
+                                    on:change=move |ev| {
+                                        if let Some(hex) = parse_hex(&event_target_value(&ev)) {
+                                            update_options(
+                                                &|options| { options.background = Some(hex.clone()) },
+                                            );
+                                        }
+                                    }
+                                />
+                            }
+                        })
+                }}
+            </label>
+            <label>
+                <span>{move_tr!("padding")}</span>
+                <input
+                    type="range"
+                    min="0"
+                    max=MAX_PADDING
+                    step="5"
+                    prop:value=move || options.read().padding
+                    on:input=move |ev| {
+                        if let Ok(padding) = event_target_value(&ev).parse::<u32>() {
+                            update_options(&|options| options.padding = padding.min(MAX_PADDING));
+                        }
+                    }
+                />
+                <output>{move || format!("{}%", options.read().padding)}</output>
+            </label>
+        </div>
+    }
+}
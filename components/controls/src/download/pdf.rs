@@ -1,33 +1,52 @@
-use leptos::prelude::document;
-use simple_icons_macros::js_library_version;
-use wasm_bindgen::prelude::*;
+use super::{download_bytes, options::ExportOptions, svg::fetch_icon_path};
+use icon_format::ColorError;
+use icon_pdf::{PdfError, icon_pdf};
+use leptos::prelude::window;
+use leptos_fluent::{I18n, tr};
+use simple_icons_website_types::SimpleIcon;
+use snafu::prelude::*;
 
-static PDFKIT_VERSION: &str = js_library_version!("pdfkit");
-static BLOB_STREAM_VERSION: &str = js_library_version!("blob-stream");
+/// Errors that can occur exporting the PDF of an icon
+#[derive(Debug, Snafu)]
+pub enum ExportPdfError {
+    /// The SVG of the icon can't be fetched
+    #[snafu(display("{message}"))]
+    Fetch { message: String },
 
-#[wasm_bindgen(module = "/src/download/pdf.js")]
-extern "C" {
-    pub fn download_pdf_(
-        slug: &str,
-        errorMessageSchema: &str,
-        pdfkitVersion: &str,
-        blobStreamVersion: &str,
-    );
-    pub fn add_scripts_(pdfkitVersion: &str, blobStreamVersion: &str);
+    /// The color of the icon or of the background is not valid
+    #[snafu(display("{source}"), context(false))]
+    Color { source: ColorError },
+
+    /// The path of the icon can't be written as a PDF
+    #[snafu(display("{source}"), context(false))]
+    Pdf { source: PdfError },
 }
 
-pub fn download_pdf(slug: &str) {
-    let msg = document()
-        .query_selector("[data-error-generating-pdf-msg]")
-        .unwrap()
-        .unwrap()
-        .unchecked_into::<web_sys::HtmlButtonElement>()
-        .get_attribute("data-error-generating-pdf-msg")
-        .unwrap();
+/// Generate the PDF of an icon with the export options
+pub async fn export_pdf(
+    icon: &SimpleIcon,
+    options: &ExportOptions,
+) -> Result<Vec<u8>, ExportPdfError> {
+    let path = fetch_icon_path(icon)
+        .await
+        .map_err(|message| ExportPdfError::Fetch { message })?;
+    let pdf_options = options.pdf_options(icon.hex)?;
+    Ok(icon_pdf(&path, &pdf_options)?)
+}
 
-    download_pdf_(slug, &msg, PDFKIT_VERSION, BLOB_STREAM_VERSION);
+/// Download the PDF of an icon with the export options
+pub async fn download_pdf(
+    icon: &SimpleIcon,
+    options: &ExportOptions,
+) -> Result<(), ExportPdfError> {
+    let pdf = export_pdf(icon, options).await?;
+    download_bytes(&format!("{}.pdf", icon.slug), &pdf, "application/pdf");
+    Ok(())
 }
 
-pub fn add_pdfkit_scripts() {
-    add_scripts_(PDFKIT_VERSION, BLOB_STREAM_VERSION);
+/// Tell the user that the PDF of an icon can't be generated
+pub fn alert_pdf_error(i18n: I18n, error: &ExportPdfError) {
+    let message = format!("{} {error}", tr!(i18n, "error-generating-pdf"));
+    web_sys::console::error_1(&message.as_str().into());
+    _ = window().alert_with_message(&message);
 }
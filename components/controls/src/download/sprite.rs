@@ -0,0 +1,122 @@
+use super::{download_bytes, svg::fetch_icon_path};
+use futures::{StreamExt, TryStreamExt, stream};
+use icon_format::escape_xml;
+use simple_icons_website_types::SimpleIcon;
+use zip_writer::ZipWriter;
+
+/// Name of the files of the sprite archives, without extension
+static SPRITE_FILENAME: &str = "simple-icons-sprite";
+
+/// Maximum number of icons fetched at the same time
+const CONCURRENT_FETCHES: usize = 16;
+
+/// Build a SVG sprite with a `<symbol>` for each icon given their paths
+///
+/// The symbols are identified by the slugs of the icons. If `brand_colors`
+/// is `true` the paths are filled with the brand colors of the icons,
+/// otherwise they inherit the fill of the `<svg>` that uses them.
+pub fn svg_sprite(
+    icons: &[(&'static SimpleIcon, String)],
+    brand_colors: bool,
+) -> String {
+    let mut sprite =
+        "<svg xmlns=\"http://www.w3.org/2000/svg\" style=\"display: none\">\n"
+            .to_string();
+    for (icon, path) in icons {
+        let fill = match brand_colors {
+            true => format!(" fill=\"#{}\"", icon.hex),
+            false => String::new(),
+        };
+        sprite.push_str(&format!(
+            concat!(
+                "  <symbol id=\"{slug}\" viewBox=\"0 0 24 24\">",
+                "<title>{title}</title><path{fill} d=\"{path}\"/></symbol>\n",
+            ),
+            slug = icon.slug,
+            title = escape_xml(icon.title),
+            fill = fill,
+            path = path,
+        ));
+    }
+    sprite.push_str("</svg>\n");
+    sprite
+}
+
+/// HTML page that explains how to use a SVG sprite, showing its icons
+fn sprite_usage_html(icons: &[(&'static SimpleIcon, String)]) -> String {
+    let uses = icons
+        .iter()
+        .map(|(icon, _)| {
+            format!(
+                concat!(
+                    "    <svg role=\"img\" width=\"24\" height=\"24\"",
+                    " aria-label=\"{title}\">",
+                    "<use href=\"{filename}.svg#{slug}\"/></svg>\n",
+                ),
+                title = escape_xml(icon.title),
+                filename = SPRITE_FILENAME,
+                slug = icon.slug,
+            )
+        })
+        .collect::<String>();
+    format!(
+        concat!(
+            "<!DOCTYPE html>\n",
+            "<html lang=\"en\">\n",
+            "  <head>\n",
+            "    <meta charset=\"utf-8\">\n",
+            "    <title>Simple Icons sprite</title>\n",
+            "  </head>\n",
+            "  <body>\n",
+            "    <!--\n",
+            "      Each icon of {filename}.svg is a <symbol> identified by\n",
+            "      the slug of the icon. Reference it with <use> from a <svg>\n",
+            "      served from the same origin as the sprite:\n",
+            "\n",
+            "      <svg role=\"img\" width=\"24\" height=\"24\">\n",
+            "        <use href=\"{filename}.svg#slug\"/>\n",
+            "      </svg>\n",
+            "\n",
+            "      Icons without brand colors inherit the `fill` of the <svg>.\n",
+            "    -->\n",
+            "{uses}",
+            "  </body>\n",
+            "</html>\n",
+        ),
+        filename = SPRITE_FILENAME,
+        uses = uses,
+    )
+}
+
+/// Download a ZIP archive with a SVG sprite of the icons
+///
+/// The archive includes an HTML file with examples of usage of the
+/// sprite for each icon.
+pub async fn download_icons_sprite(
+    icons: Vec<&'static SimpleIcon>,
+    brand_colors: bool,
+) -> Result<(), String> {
+    let icons_paths = stream::iter(icons)
+        .map(|icon| async move {
+            fetch_icon_path(icon).await.map(|path| (icon, path))
+        })
+        .buffered(CONCURRENT_FETCHES)
+        .try_collect::<Vec<_>>()
+        .await?;
+
+    let mut zip = ZipWriter::new();
+    zip.add_file(
+        &format!("{SPRITE_FILENAME}.svg"),
+        svg_sprite(&icons_paths, brand_colors).as_bytes(),
+    )
+    .map_err(|error| error.to_string())?;
+    zip.add_file(
+        &format!("{SPRITE_FILENAME}.html"),
+        sprite_usage_html(&icons_paths).as_bytes(),
+    )
+    .map_err(|error| error.to_string())?;
+    let zip = zip.finish().map_err(|error| error.to_string())?;
+
+    download_bytes(&format!("{SPRITE_FILENAME}.zip"), &zip, "application/zip");
+    Ok(())
+}
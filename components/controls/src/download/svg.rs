@@ -1,6 +1,78 @@
-use crate::download::download;
+use super::{
+    download_bytes,
+    options::{ExportFill, ExportOptions},
+};
+use leptos::task::spawn_local;
+use simple_icons_website_types::SimpleIcon;
+use web_sys_simple_fetch::fetch_text;
 
-/// Download a SVG icon by its slug
-pub fn download_svg(slug: &str) {
-    download(&format!("{slug}.svg"), &format!("/icons/{slug}.svg"));
+/// Path of the SVG of an icon
+pub fn svg_path(svg: &str) -> Option<&str> {
+    svg.split_once("<path d=\"")?
+        .1
+        .split_once('"')
+        .map(|(path, _)| path)
+}
+
+/// Fetch the SVG of an icon
+async fn fetch_icon_svg(icon: &SimpleIcon) -> Result<String, String> {
+    fetch_text(&format!("/icons/{}.svg", icon.slug)).await
+}
+
+/// Fetch the path of the SVG of an icon
+pub async fn fetch_icon_path(icon: &SimpleIcon) -> Result<String, String> {
+    let svg = fetch_icon_svg(icon).await?;
+    svg_path(&svg)
+        .map(str::to_string)
+        .ok_or_else(|| format!("Path not found in the icon {}", icon.slug))
+}
+
+/// Get the SVG of an icon with the export options applied
+pub async fn export_svg(
+    icon: &SimpleIcon,
+    options: &ExportOptions,
+) -> Result<String, String> {
+    let svg = fetch_icon_svg(icon).await?;
+    Ok(options.apply_to_svg(&svg, icon.hex))
+}
+
+/// Download a SVG icon with the export options applied
+pub fn download_svg(icon: &'static SimpleIcon, options: &ExportOptions) {
+    let options = options.clone();
+    spawn_local(async move {
+        match export_svg(icon, &options).await {
+            Ok(svg) => download_bytes(
+                &format!("{}.svg", icon.slug),
+                svg.as_bytes(),
+                "image/svg+xml",
+            ),
+            Err(error) => web_sys::console::error_1(&error.into()),
+        }
+    });
+}
+
+/// Export options of the colored SVGs, filled with the brand color
+pub fn colored_svg_options(options: &ExportOptions) -> ExportOptions {
+    ExportOptions {
+        fill: ExportFill::Brand,
+        ..options.clone()
+    }
+}
+
+/// Download a SVG icon filled with its brand color
+pub fn download_colored_svg(
+    icon: &'static SimpleIcon,
+    options: &ExportOptions,
+) {
+    let options = colored_svg_options(options);
+    spawn_local(async move {
+        match export_svg(icon, &options).await {
+            Ok(svg) => download_bytes(
+                &format!("{}-color.svg", icon.slug),
+                svg.as_bytes(),
+                "image/svg+xml",
+            ),
+            Err(error) => web_sys::console::error_1(&error.into()),
+        }
+    });
 }
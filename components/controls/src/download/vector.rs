@@ -0,0 +1,87 @@
+use super::{download_bytes, options::ExportOptions, svg::fetch_icon_path};
+use leptos::task::spawn_local;
+use simple_icons::export::{to_eps, to_vector_drawable, to_xaml};
+use simple_icons_website_types::SimpleIcon;
+use svg_path_cst::svg_path_cst;
+
+/// Vector formats of other platforms in which the icons can be exported
+#[derive(Copy, Clone, PartialEq)]
+pub enum VectorFormat {
+    /// Android VectorDrawable
+    VectorDrawable,
+    /// XAML `PathGeometry`
+    Xaml,
+    /// Encapsulated PostScript
+    Eps,
+}
+
+impl VectorFormat {
+    /// Name of the file of an icon in the format
+    ///
+    /// The names of Android resources must start with a letter, so
+    /// VectorDrawables are prefixed with `ic_` as Android icons.
+    pub fn filename(&self, slug: &str) -> String {
+        match self {
+            Self::VectorDrawable => format!("ic_{slug}.xml"),
+            Self::Xaml => format!("{slug}.xaml"),
+            Self::Eps => format!("{slug}.eps"),
+        }
+    }
+
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::VectorDrawable | Self::Xaml => "application/xml",
+            Self::Eps => "application/postscript",
+        }
+    }
+}
+
+/// Convert an icon to a vector format filled with the export options
+pub async fn export_vector(
+    icon: &SimpleIcon,
+    options: &ExportOptions,
+    format: VectorFormat,
+) -> Result<String, String> {
+    let path = fetch_icon_path(icon).await?;
+    let cst =
+        svg_path_cst(path.as_bytes()).map_err(|error| error.to_string())?;
+    let hex = options.fill.hex(icon.hex);
+    Ok(match format {
+        VectorFormat::VectorDrawable => to_vector_drawable(&cst, hex),
+        VectorFormat::Xaml => to_xaml(&cst, icon.title, hex),
+        VectorFormat::Eps => to_eps(&cst, icon.title, hex),
+    })
+}
+
+fn download_vector(
+    icon: &'static SimpleIcon,
+    options: &ExportOptions,
+    format: VectorFormat,
+) {
+    let options = options.clone();
+    spawn_local(async move {
+        match export_vector(icon, &options, format).await {
+            Ok(document) => download_bytes(
+                &format.filename(icon.slug),
+                document.as_bytes(),
+                format.mime_type(),
+            ),
+            Err(error) => web_sys::console::error_1(&error.into()),
+        }
+    });
+}
+
+pub fn download_vector_drawable(
+    icon: &'static SimpleIcon,
+    options: &ExportOptions,
+) {
+    download_vector(icon, options, VectorFormat::VectorDrawable);
+}
+
+pub fn download_xaml(icon: &'static SimpleIcon, options: &ExportOptions) {
+    download_vector(icon, options, VectorFormat::Xaml);
+}
+
+pub fn download_eps(icon: &'static SimpleIcon, options: &ExportOptions) {
+    download_vector(icon, options, VectorFormat::Eps);
+}
@@ -0,0 +1,99 @@
+use super::{
+    DownloadType, download_bytes,
+    favicon::export_favicon_pack,
+    image::rasterize_icon,
+    options::ExportOptions,
+    pdf::export_pdf,
+    svg::{colored_svg_options, export_svg},
+    vector::{VectorFormat, export_vector},
+};
+use icon_rasterizer::ImageFormat;
+use simple_icons_website_types::SimpleIcon;
+use zip_writer::ZipWriter;
+
+/// Metadata of the icons included in ZIP archives
+fn icons_metadata_json(icons: &[&'static SimpleIcon]) -> String {
+    let metadata = icons
+        .iter()
+        .map(|icon| {
+            serde_json::json!({
+                "title": icon.title,
+                "slug": icon.slug,
+                "hex": icon.hex,
+                "source": icon.source,
+                "license": (icon.license_type.is_some()
+                    || icon.license_url.is_some())
+                .then(|| {
+                    serde_json::json!({
+                        "type": icon.license_type,
+                        "url": icon.license_url,
+                    })
+                }),
+                "guidelines": icon.guidelines,
+            })
+        })
+        .collect::<Vec<_>>();
+    serde_json::to_string_pretty(&metadata).unwrap()
+}
+
+/// Download a ZIP archive with the icons in the given download type
+///
+/// The export options are applied to all the icons. The archive includes a
+/// `metadata.json` file with the title, hex color, source, license and
+/// guidelines of each icon.
+pub async fn download_icons_zip(
+    icons: Vec<&'static SimpleIcon>,
+    download_type: DownloadType,
+    options: ExportOptions,
+) -> Result<(), String> {
+    let options = &options;
+    let mut zip = ZipWriter::new();
+    for icon in &icons {
+        let slug = icon.slug;
+        let raster = |format: ImageFormat| async move {
+            let image = rasterize_icon(icon, options, format).await?;
+            Ok::<_, String>((format!("{slug}.{}", format.extension()), image))
+        };
+        let vector = |format: VectorFormat| async move {
+            let document = export_vector(icon, options, format).await?;
+            Ok::<_, String>((format.filename(slug), document.into_bytes()))
+        };
+        let (filename, content) = match download_type {
+            DownloadType::SVG => {
+                let svg = export_svg(icon, options).await?;
+                (format!("{slug}.svg"), svg.into_bytes())
+            }
+            DownloadType::ColoredSVG => {
+                let svg =
+                    export_svg(icon, &colored_svg_options(options)).await?;
+                (format!("{slug}-color.svg"), svg.into_bytes())
+            }
+            DownloadType::PDF => {
+                let pdf = export_pdf(icon, options)
+                    .await
+                    .map_err(|error| error.to_string())?;
+                (format!("{slug}.pdf"), pdf)
+            }
+            DownloadType::PNG => raster(ImageFormat::Png).await?,
+            DownloadType::JPG => raster(ImageFormat::Jpeg).await?,
+            DownloadType::WebP => raster(ImageFormat::Webp).await?,
+            DownloadType::VectorDrawable => {
+                vector(VectorFormat::VectorDrawable).await?
+            }
+            DownloadType::XAML => vector(VectorFormat::Xaml).await?,
+            DownloadType::EPS => vector(VectorFormat::Eps).await?,
+            DownloadType::FaviconPack => (
+                format!("{slug}-favicons.zip"),
+                export_favicon_pack(icon).await?,
+            ),
+        };
+        zip.add_file(&filename, &content)
+            .map_err(|error| error.to_string())?;
+    }
+    zip.add_file("metadata.json", icons_metadata_json(&icons).as_bytes())
+        .map_err(|error| error.to_string())?;
+    let zip = zip.finish().map_err(|error| error.to_string())?;
+
+    download_bytes("simple-icons.zip", &zip, "application/zip");
+    Ok(())
+}
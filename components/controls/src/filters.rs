@@ -0,0 +1,294 @@
+use crate::{
+    order::{OrderModeSignal, set_order_mode},
+    search::{SearchValueSignal, fire_on_search_event, init_searcher},
+};
+use leptos::prelude::*;
+use leptos_fluent::move_tr;
+use simple_icons_website_controls_layout_signal::LayoutSignal;
+use simple_icons_website_grid_types::{IconsGridSignal, IconsIndexSignal};
+use simple_icons_website_storage::LocalStorage;
+use simple_icons_website_types::SimpleIcon;
+use simple_icons_website_url as Url;
+use std::{collections::BTreeSet, str::FromStr};
+
+/// Prefix of the filters by license type
+static LICENSE_TYPE_PREFIX: &str = "license:";
+
+/// Filters by metadata of the icons
+///
+/// Icons must match all the filters enabled. If several license types are
+/// selected, icons must have one of them.
+#[derive(Clone, Default, PartialEq)]
+pub struct IconsFilters {
+    /// Icons with brand guidelines
+    pub guidelines: bool,
+    /// Icons with license
+    pub license: bool,
+    /// Icons with one of these license types
+    pub license_types: BTreeSet<String>,
+    /// Deprecated icons
+    pub deprecated: bool,
+    /// Icons with localized names
+    pub localized_names: bool,
+}
+
+impl FromStr for IconsFilters {
+    type Err = ();
+
+    /// Parse a comma separated list of filters, ignoring the unknown ones
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        let mut filters = Self::default();
+        for filter in value.split(',') {
+            match filter {
+                "guidelines" => filters.guidelines = true,
+                "license" => filters.license = true,
+                "deprecated" => filters.deprecated = true,
+                "localized" => filters.localized_names = true,
+                _ => {
+                    if let Some(license_type) =
+                        filter.strip_prefix(LICENSE_TYPE_PREFIX)
+                        && !license_type.is_empty()
+                    {
+                        filters.license_types.insert(license_type.to_string());
+                    }
+                }
+            }
+        }
+        Ok(filters)
+    }
+}
+
+impl core::fmt::Display for IconsFilters {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let mut filters = vec![];
+        if self.guidelines {
+            filters.push("guidelines".to_string());
+        }
+        if self.license {
+            filters.push("license".to_string());
+        }
+        filters.extend(self.license_types.iter().map(|license_type| {
+            format!("{LICENSE_TYPE_PREFIX}{license_type}")
+        }));
+        if self.deprecated {
+            filters.push("deprecated".to_string());
+        }
+        if self.localized_names {
+            filters.push("localized".to_string());
+        }
+        write!(f, "{}", filters.join(","))
+    }
+}
+
+impl IconsFilters {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+
+    /// Whether an icon matches all the filters enabled
+    pub fn matches(&self, icon: &SimpleIcon) -> bool {
+        (!self.guidelines || icon.guidelines.is_some())
+            && (!self.license || icon.license_type.is_some())
+            && (self.license_types.is_empty()
+                || icon
+                    .license_type
+                    .is_some_and(|t| self.license_types.contains(t)))
+            && (!self.deprecated || icon.deprecation.is_some())
+            && (!self.localized_names
+                || icon
+                    .aliases
+                    .and_then(|aliases| aliases.loc)
+                    .is_some_and(|loc| !loc.is_empty()))
+    }
+}
+
+/// Icons of the index matching the filters
+pub fn filter_icons(
+    icons: &[&'static SimpleIcon],
+    filters: &IconsFilters,
+) -> Vec<&'static SimpleIcon> {
+    icons
+        .iter()
+        .filter(|icon| filters.matches(icon))
+        .copied()
+        .collect()
+}
+
+#[derive(Copy, Clone)]
+pub struct FiltersSignal(pub RwSignal<IconsFilters>);
+
+pub fn provide_filters_context() -> IconsFilters {
+    let filters = initial_filters();
+    provide_context(FiltersSignal(RwSignal::new(filters.clone())));
+    filters
+}
+
+fn initial_filters() -> IconsFilters {
+    match Url::params::get(&Url::params::Names::Filters)
+        .and_then(|value| value.parse().ok())
+    {
+        Some(filters) => {
+            set_filters_on_localstorage(&filters);
+            filters
+        }
+        None => {
+            let filters = get_filters_from_localstorage().unwrap_or_default();
+            Url::params::update(
+                &Url::params::Names::Filters,
+                &filters.to_string(),
+            );
+            filters
+        }
+    }
+}
+
+fn get_filters_from_localstorage() -> Option<IconsFilters> {
+    LocalStorage::get(LocalStorage::Keys::Filters)
+        .as_ref()
+        .and_then(|value| value.parse().ok())
+}
+
+fn set_filters_on_localstorage(filters: &IconsFilters) {
+    LocalStorage::set(LocalStorage::Keys::Filters, &filters.to_string());
+}
+
+/// License types of the icons, the most used first
+fn license_types(icons: &[&'static SimpleIcon]) -> Vec<&'static str> {
+    let mut counts: Vec<(&'static str, usize)> = vec![];
+    for license_type in icons.iter().filter_map(|icon| icon.license_type) {
+        match counts.iter_mut().find(|(t, _)| *t == license_type) {
+            Some((_, count)) => *count += 1,
+            None => counts.push((license_type, 1)),
+        }
+    }
+    counts.sort_by(|(type_a, count_a), (type_b, count_b)| {
+        count_b.cmp(count_a).then(type_a.cmp(type_b))
+    });
+    counts
+        .into_iter()
+        .map(|(license_type, _)| license_type)
+        .collect()
+}
+
+#[component]
+fn FilterChip(
+    /// Chip text
+    #[prop(into)]
+    text: Signal<String>,
+    /// The filter is enabled
+    #[prop(into)]
+    active: Signal<bool>,
+    /// Toggle the filter
+    on_toggle: impl Fn() + 'static,
+) -> impl IntoView {
+    view! {
+        <button
+            type="button"
+            class:selected=active
+            aria-pressed=move || active().to_string()
+            on:click=move |_| on_toggle()
+        >
+            {text}
+        </button>
+    }
+}
+
+/// Chips to filter the icons by their metadata
+#[component]
+pub fn FiltersControl() -> impl IntoView {
+    let filters = expect_context::<FiltersSignal>().0;
+    let icons_grid = expect_context::<IconsGridSignal>().0;
+    let order_mode = expect_context::<OrderModeSignal>().0;
+    let search = expect_context::<SearchValueSignal>().0;
+    let layout = expect_context::<LayoutSignal>().0;
+    let icons = StoredValue::new(expect_context::<IconsIndexSignal>().0);
+    let license_types = license_types(&icons.read_value());
+
+    // Update the filters and the icons of the grid
+    let update_filters = move |update: &dyn Fn(&mut IconsFilters)| {
+        filters.update(|filters| update(filters));
+        let filters = filters.get_untracked();
+        set_filters_on_localstorage(&filters);
+        Url::params::update(&Url::params::Names::Filters, &filters.to_string());
+
+        let filtered_icons = filter_icons(&icons.read_value(), &filters);
+        init_searcher(filtered_icons.clone());
+        match search.read_untracked().is_empty() {
+            true => set_order_mode(
+                &order_mode.get_untracked().favorite,
+                &order_mode,
+                &icons_grid,
+                Some(&layout.get_untracked()),
+                true,
+                filtered_icons,
+            ),
+            // Search again with the new icons
+            false => fire_on_search_event(),
+        }
+    };
+
+    view! {
+        <div class="filters">
+            <label>{move_tr!("filters")}</label>
+            <FilterChip
+                text=move_tr!("has-brand-guidelines")
+                active=Signal::derive(move || filters.read().guidelines)
+                on_toggle=move || update_filters(
+                    &|filters| filters.guidelines = !filters.guidelines,
+                )
+            />
+            <FilterChip
+                text=move_tr!("has-license")
+                active=Signal::derive(move || filters.read().license)
+                on_toggle=move || update_filters(&|filters| filters.license = !filters.license)
+            />
+            {license_types
+                .into_iter()
+                .map(|license_type| {
+                    view! {
+                        <FilterChip
+                            text=move_tr!("license-type", { "type" => license_type })
+                            active=Signal::derive(move || {
+                                filters.read().license_types.contains(license_type)
+                            })
+                            on_toggle=move || {
+                                update_filters(
+                                    &|filters| {
+                                        if !filters.license_types.remove(license_type) {
+                                            filters.license_types.insert(license_type.to_string());
+                                        }
+                                    },
+                                )
+                            }
+                        />
+                    }
+                })
+                .collect_view()}
+            <FilterChip
+                text=move_tr!("deprecated")
+                active=Signal::derive(move || filters.read().deprecated)
+                on_toggle=move || update_filters(
+                    &|filters| filters.deprecated = !filters.deprecated,
+                )
+            />
+            <FilterChip
+                text=move_tr!("has-localized-names")
+                active=Signal::derive(move || filters.read().localized_names)
+                on_toggle=move || update_filters(
+                    &|filters| filters.localized_names = !filters.localized_names,
+                )
+            />
+            <Show when=move || !filters.read().is_empty()>
+                <button
+                    type="button"
+                    class="clear-filters"
+                    on:click=move |_| {
+                        update_filters(&|filters| *filters = IconsFilters::default())
+                    }
+                >
+                    {move_tr!("clear-filters")}
+                </button>
+            </Show>
+        </div>
+    }
+}
@@ -1,5 +1,5 @@
 use super::button::ControlButtonIcon;
-use icondata::{LuGrid2x2, LuGrid3x3};
+use icondata::{LuGrid2x2, LuGrid3x3, LuList};
 use leptos::prelude::*;
 use leptos_fluent::move_tr;
 use simple_icons_website_controls_layout_signal::LayoutSignal;
@@ -49,8 +49,9 @@ pub fn LayoutControl() -> impl IntoView {
     let icons_loader: RwSignal<IconsLoader> =
         expect_context::<IconsLoaderSignal>().0;
 
-    let activate_compact_layout = move || {
-        set_layout(Layout::Compact, &layout);
+    // Layouts that display more icons per page than the comfortable one
+    let activate_dense_layout = move |new_layout: Layout| {
+        set_layout(new_layout, &layout);
 
         // load more icons if needed, see:
         // https://github.com/simple-icons/simple-icons-website-rs/issues/290
@@ -105,7 +106,17 @@ pub fn LayoutControl() -> impl IntoView {
                     active=Signal::derive(move || layout() == Layout::Compact)
                     on:click=move |_| {
                         if layout.get_untracked() != Layout::Compact {
-                            activate_compact_layout();
+                            activate_dense_layout(Layout::Compact);
+                        }
+                    }
+                />
+                <ControlButtonIcon
+                    title=move_tr!("list")
+                    icon=LuList
+                    active=Signal::derive(move || layout() == Layout::List)
+                    on:click=move |_| {
+                        if layout.get_untracked() != Layout::List {
+                            activate_dense_layout(Layout::List);
                         }
                     }
                 />
@@ -3,18 +3,22 @@
 mod button;
 pub mod color_scheme;
 pub mod download;
+pub mod filters;
 pub mod layout;
 pub mod order;
+pub mod preview_mode;
 pub mod search;
 
 use button::XS_ICON_SIZE;
 use color_scheme::ColorSchemeControl;
 use download::DownloadFileTypeControl;
+use filters::FiltersControl;
 use layout::LayoutControl;
 use leptos::prelude::*;
 use leptos_fluent::tr;
 use leptos_use::use_media_query;
 use order::OrderControl;
+use preview_mode::PreviewModeControl;
 use search::SearchControl;
 use simple_icons_website_svg_defs::SVGDef;
 use simple_icons_website_svg_icon::SVGIcon;
@@ -59,6 +63,7 @@ pub fn Controls() -> impl IntoView {
                 <ColorSchemeControl />
                 <DownloadFileTypeControl />
                 <LayoutControl />
+                <PreviewModeControl />
             </div>
             <div class=move || {
                 let mut class = "relative lg:hidden".to_string();
@@ -70,6 +75,7 @@ pub fn Controls() -> impl IntoView {
                 <ControlsToggler />
             </div>
         </menu>
+        <FiltersControl />
     }
 }
 
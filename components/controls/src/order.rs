@@ -1,4 +1,5 @@
 use super::button::ControlButtonIcon;
+use super::filters::{FiltersSignal, filter_icons};
 use super::search::{
     SearchValueSignal, fire_on_search_event, get_search_value_from_localstorage,
 };
@@ -87,12 +88,17 @@ pub fn set_order_mode(
                         sort_icons(order_mode, &mut grid.icons);
                         sort_icons(order_mode, &mut grid.loaded_icons);
                     } else {
-                        // If not searching, load the new icons in order
+                        // If not searching, load the new icons in order,
+                        // as many as before to keep the scroll position
+                        let loaded_icons_length = grid.loaded_icons.len();
                         grid.loaded_icons = Vec::with_capacity(icons.len());
                         grid.icons = icons;
                         sort_icons(order_mode, &mut grid.icons);
                         if let Some(layout_signal) = layout_signal {
-                            grid.load_next_icons(layout_signal);
+                            grid.load_icons_until(
+                                loaded_icons_length,
+                                layout_signal,
+                            );
                         }
                     }
                 });
@@ -108,6 +114,9 @@ pub fn OrderControl() -> impl IntoView {
     let search_signal = expect_context::<SearchValueSignal>().0;
     let layout_signal = expect_context::<LayoutSignal>().0;
     let icons = StoredValue::new(expect_context::<IconsIndexSignal>().0);
+    let filters = expect_context::<FiltersSignal>().0;
+    let filtered_icons =
+        move || filter_icons(&icons.read_value(), &filters.read_untracked());
 
     Effect::new(move |_| match order_mode.get_untracked().current {
         OrderModeVariant::SearchMatch | OrderModeVariant::Alphabetic => {}
@@ -117,7 +126,7 @@ pub fn OrderControl() -> impl IntoView {
             &icons_grid,
             Some(&layout_signal()),
             true,
-            icons.read_value().to_vec(),
+            filtered_icons(),
         ),
     });
 
@@ -145,7 +154,7 @@ pub fn OrderControl() -> impl IntoView {
                             &icons_grid,
                             Some(&layout_signal()),
                             true,
-                            icons.read_value().to_vec(),
+                            filtered_icons(),
                         )
                     }
                 />
@@ -169,7 +178,7 @@ pub fn OrderControl() -> impl IntoView {
                             &icons_grid,
                             Some(&layout_signal()),
                             true,
-                            icons.read_value().to_vec(),
+                            filtered_icons(),
                         )
                     }
                 />
@@ -191,7 +200,7 @@ pub fn OrderControl() -> impl IntoView {
                             &icons_grid,
                             Some(&layout_signal()),
                             true,
-                            icons.read_value().to_vec(),
+                            filtered_icons(),
                         )
                     }
                 />
@@ -215,7 +224,7 @@ pub fn OrderControl() -> impl IntoView {
                             &icons_grid,
                             Some(&layout_signal()),
                             true,
-                            icons.read_value().to_vec(),
+                            filtered_icons(),
                         )
                     }
                 />
@@ -229,7 +238,7 @@ pub fn OrderControl() -> impl IntoView {
                         &icons_grid,
                         Some(&layout_signal()),
                         true,
-                        icons.read_value().to_vec(),
+                        filtered_icons(),
                     )
                 />
                 {move || match search_signal().is_empty() {
@@ -250,7 +259,7 @@ pub fn OrderControl() -> impl IntoView {
                                     &icons_grid,
                                     Some(&layout_signal()),
                                     true,
-                                    icons.read_value().to_vec(),
+                                    filtered_icons(),
                                 )
                             />
                         }
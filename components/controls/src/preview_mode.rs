@@ -0,0 +1,126 @@
+use super::button::ControlButtonIcon;
+use icondata::{LuContrast, LuPaintBucket, LuPalette};
+use leptos::prelude::*;
+use leptos_fluent::move_tr;
+use simple_icons_website_storage::LocalStorage;
+use simple_icons_website_url as Url;
+use std::str::FromStr;
+
+/// How the icons are displayed in the previews of the grid
+#[derive(Default, Copy, Clone, PartialEq)]
+pub enum PreviewMode {
+    /// Icon in the color of the text over the page background
+    #[default]
+    Monochrome,
+    /// Icon over the brand color, in black or white depending on the
+    /// contrast with the brand color
+    BrandBackground,
+    /// Icon filled with the brand color
+    BrandColor,
+}
+
+impl FromStr for PreviewMode {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "monochrome" => Ok(Self::Monochrome),
+            "brand-background" => Ok(Self::BrandBackground),
+            "brand-color" => Ok(Self::BrandColor),
+            _ => Err(()),
+        }
+    }
+}
+
+impl core::fmt::Display for PreviewMode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Monochrome => write!(f, "monochrome"),
+            Self::BrandBackground => write!(f, "brand-background"),
+            Self::BrandColor => write!(f, "brand-color"),
+        }
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct PreviewModeSignal(pub RwSignal<PreviewMode>);
+
+pub fn provide_preview_mode_context() {
+    provide_context(PreviewModeSignal(RwSignal::new(initial_preview_mode())));
+}
+
+fn initial_preview_mode() -> PreviewMode {
+    match Url::params::get(&Url::params::Names::PreviewMode)
+        .and_then(|value| value.parse().ok())
+    {
+        Some(preview_mode) => {
+            set_preview_mode_on_localstorage(&preview_mode);
+            preview_mode
+        }
+        None => {
+            let preview_mode =
+                get_preview_mode_from_localstorage().unwrap_or_default();
+            Url::params::update(
+                &Url::params::Names::PreviewMode,
+                &preview_mode.to_string(),
+            );
+            preview_mode
+        }
+    }
+}
+
+fn get_preview_mode_from_localstorage() -> Option<PreviewMode> {
+    LocalStorage::get(LocalStorage::Keys::PreviewMode)
+        .as_ref()
+        .and_then(|value| value.parse().ok())
+}
+
+fn set_preview_mode_on_localstorage(preview_mode: &PreviewMode) {
+    LocalStorage::set(
+        LocalStorage::Keys::PreviewMode,
+        &preview_mode.to_string(),
+    );
+}
+
+fn set_preview_mode(
+    preview_mode: PreviewMode,
+    preview_mode_signal: &RwSignal<PreviewMode>,
+) {
+    preview_mode_signal.set(preview_mode);
+    set_preview_mode_on_localstorage(&preview_mode);
+    Url::params::update(
+        &Url::params::Names::PreviewMode,
+        &preview_mode.to_string(),
+    );
+}
+
+#[component]
+pub fn PreviewModeControl() -> impl IntoView {
+    let preview_mode = expect_context::<PreviewModeSignal>().0;
+
+    view! {
+        <div class="control">
+            <label>{move_tr!("preview")}</label>
+            <div>
+                <ControlButtonIcon
+                    title=move_tr!("monochrome")
+                    icon=LuContrast
+                    active=Signal::derive(move || preview_mode() == PreviewMode::Monochrome)
+                    on:click=move |_| set_preview_mode(PreviewMode::Monochrome, &preview_mode)
+                />
+                <ControlButtonIcon
+                    title=move_tr!("brand-color-background")
+                    icon=LuPaintBucket
+                    active=Signal::derive(move || preview_mode() == PreviewMode::BrandBackground)
+                    on:click=move |_| set_preview_mode(PreviewMode::BrandBackground, &preview_mode)
+                />
+                <ControlButtonIcon
+                    title=move_tr!("brand-color-fill")
+                    icon=LuPalette
+                    active=Signal::derive(move || preview_mode() == PreviewMode::BrandColor)
+                    on:click=move |_| set_preview_mode(PreviewMode::BrandColor, &preview_mode)
+                />
+            </div>
+        </div>
+    }
+}
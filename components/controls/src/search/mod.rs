@@ -1,4 +1,7 @@
-use crate::order::{OrderModeSignal, set_order_mode};
+use crate::{
+    filters::{FiltersSignal, filter_icons},
+    order::{OrderModeSignal, set_order_mode},
+};
 use fast_fuzzy::{build_searcher, search};
 use leptos::{prelude::*, task::spawn_local};
 use leptos_fluent::tr;
@@ -180,7 +183,8 @@ pub fn SearchControl() -> impl IntoView {
     let search = expect_context::<SearchValueSignal>().0;
     let order_mode = expect_context::<OrderModeSignal>().0;
     let layout = expect_context::<LayoutSignal>().0;
-    let icons = expect_context::<IconsIndexSignal>().0;
+    let icons = StoredValue::new(expect_context::<IconsIndexSignal>().0);
+    let filters = expect_context::<FiltersSignal>().0;
 
     let search_input_ref = NodeRef::new();
     // Focus on load. Fallback for Safari, see:
@@ -211,8 +215,8 @@ pub fn SearchControl() -> impl IntoView {
                                 search,
                                 icons_grid,
                                 order_mode,
-                                layout().icons_per_page() as usize,
-                                icons.clone(),
+                                icons_grid.read_untracked().page_end(0, &layout.get_untracked()),
+                                filter_icons(&icons.read_value(), &filters.read_untracked()),
                             ),
                         )
                     }
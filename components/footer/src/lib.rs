@@ -6,6 +6,17 @@ use leptos_fluent::{move_tr, tr};
 use simple_icons_macros::get_simple_icon_svg_path;
 use simple_icons_website_svg_icon::SVGIcon;
 
+/// URL of the issue template used to request new icons
+pub const ICON_REQUEST_ISSUE_URL: &str = "https://github.com/simple-icons/simple-icons/issues/new?assignees=&labels=new+icon&template=icon_request.yml";
+
+/// URL of the new icon request issue template with the title pre-filled
+pub fn icon_request_issue_url(brand_name: &str) -> String {
+    format!(
+        "{ICON_REQUEST_ISSUE_URL}&title={}",
+        js_sys::encode_uri_component(brand_name)
+    )
+}
+
 /// Footer of the website
 #[component]
 pub fn Footer(
@@ -70,9 +81,7 @@ fn ReportProblems() -> impl IntoView {
         <div class="flex flex-col py-8">
             <p>
                 {move_tr!("icon-missing")} {" "}
-                <ReportLink href="https://github.com/simple-icons/simple-icons/issues/new?assignees=&labels=new+icon&template=icon_request.yml">
-                    {move_tr!("submit-a-request")}
-                </ReportLink>
+                <ReportLink href=ICON_REQUEST_ISSUE_URL>{move_tr!("submit-a-request")}</ReportLink>
             </p>
             <p>
                 {move_tr!("icon-outdated")} {" "}
@@ -0,0 +1,142 @@
+//! Collections of icons shared by URL
+//!
+//! The icons of a collection are encoded in the `collection` URL param, see
+//! the `icons-collection` crate for the details of the encoding.
+
+use leptos::{prelude::*, task::spawn_local};
+use leptos_fluent::move_tr;
+use simple_icons_website_controls::download::{
+    DownloadTypeSignal, ExportOptionsSignal, download_icons_zip,
+};
+use simple_icons_website_types::SimpleIcon;
+use simple_icons_website_url as Url;
+
+/// Collection of icons read from the URL
+#[derive(Clone)]
+pub struct IconsCollection {
+    /// Icons of the collection found
+    pub icons: Vec<&'static SimpleIcon>,
+    /// Number of icons of the collection not found
+    ///
+    /// Collections reference the icons by their alphabetical index, so a
+    /// collection shared before some icons were removed can reference
+    /// indexes that no longer exist.
+    pub missing_icons: usize,
+    /// Whether the collection param could not be decoded
+    pub malformed: bool,
+}
+
+impl IconsCollection {
+    /// Read the collection from the URL, if any
+    ///
+    /// `icons` must be all the icons in alphabetical order.
+    pub fn from_url(icons: &'static [SimpleIcon]) -> Option<Self> {
+        let param = Url::params::get(&Url::params::Names::Collection)?;
+        let collection = match icons_collection::decode(&param) {
+            Ok(indexes) => {
+                let found_icons = indexes
+                    .iter()
+                    .filter_map(|index| icons.get(*index))
+                    .collect::<Vec<_>>();
+                Self {
+                    missing_icons: indexes.len() - found_icons.len(),
+                    icons: found_icons,
+                    malformed: false,
+                }
+            }
+            Err(error) => {
+                let msg = format!("Invalid collection in the URL: {error}");
+                web_sys::console::error_1(&msg.into());
+                Self {
+                    icons: icons.iter().collect(),
+                    missing_icons: 0,
+                    malformed: true,
+                }
+            }
+        };
+        Some(collection)
+    }
+
+    /// Build the URL of the index page showing a collection of icons
+    pub fn url(icons: &[&'static SimpleIcon]) -> String {
+        let location = location();
+        format!(
+            "{}/?{}={}",
+            location.origin().unwrap_or_default(),
+            Url::params::Names::Collection.as_str(),
+            icons_collection::encode(icons.iter().map(|icon| icon.order_alpha)),
+        )
+    }
+}
+
+/// Collection of icons displayed in the grid, if any
+#[derive(Clone)]
+pub struct IconsCollectionContext(pub Option<IconsCollection>);
+
+/// Bar displayed above the grid when showing a collection of icons
+///
+/// Allows to download all the icons of the collection and to go back to
+/// all the icons.
+#[component]
+pub fn IconsCollectionBar() -> impl IntoView {
+    let collection = use_context::<IconsCollectionContext>().and_then(|c| c.0);
+    let download_type = expect_context::<DownloadTypeSignal>().0;
+    let export_options = expect_context::<ExportOptionsSignal>().0;
+    let (downloading, set_downloading) = signal(false);
+
+    collection.map(|collection| {
+        let IconsCollection {
+            icons,
+            missing_icons,
+            malformed,
+        } = collection;
+        let icons = StoredValue::new(icons);
+        let n_icons = icons.read_value().len();
+
+        let download_all = move |_| {
+            set_downloading(true);
+            spawn_local(async move {
+                if let Err(error) = download_icons_zip(
+                    icons.get_value(),
+                    download_type.get_untracked(),
+                    export_options.get_untracked(),
+                )
+                .await
+                {
+                    web_sys::console::error_1(&error.into());
+                }
+                set_downloading(false);
+            });
+        };
+
+        view! {
+            <div class="collection-bar">
+                {match malformed {
+                    true => view! { <p>{move_tr!("invalid-collection")}</p> }.into_any(),
+                    false => {
+                        view! {
+                            <p>{move_tr!("collection-of-n-icons", { "n" => n_icons })}</p>
+                            {(missing_icons > 0)
+                                .then(|| {
+                                    view! {
+                                        <p>
+                                            {move_tr!(
+                                                "n-collection-icons-not-found", { "n" => missing_icons }
+                                            )}
+                                        </p>
+                                    }
+                                })}
+                            <button
+                                disabled=move || downloading() || n_icons == 0
+                                on:click=download_all
+                            >
+                                {move_tr!("download-all")}
+                            </button>
+                        }
+                            .into_any()
+                    }
+                }} <a href="/">{move_tr!("show-all-icons")}</a>
+            </div>
+        }
+    })
+}
@@ -0,0 +1,117 @@
+use icondata::{BsStar, BsStarFill};
+use leptos::prelude::*;
+use leptos_fluent::{move_tr, tr};
+use leptos_icons::Icon;
+use simple_icons_website_storage::LocalStorage;
+use simple_icons_website_types::SimpleIcon;
+use std::collections::BTreeSet;
+
+/// Path of the favorites page
+static FAVORITES_PAGE_PATH: &str = "/favorites/";
+
+/// Icons marked as favorites by the user
+///
+/// Persisted in the local storage as a comma separated list of slugs.
+#[derive(Clone, Default)]
+pub struct Favorites(BTreeSet<String>);
+
+impl Favorites {
+    pub fn from_localstorage() -> Self {
+        Self(
+            LocalStorage::get(LocalStorage::Keys::Favorites)
+                .unwrap_or_default()
+                .split(',')
+                .filter(|slug| !slug.is_empty())
+                .map(str::to_string)
+                .collect(),
+        )
+    }
+
+    fn set_on_localstorage(&self) {
+        LocalStorage::set(
+            LocalStorage::Keys::Favorites,
+            &self.0.iter().cloned().collect::<Vec<String>>().join(","),
+        )
+    }
+
+    pub fn contains(&self, slug: &str) -> bool {
+        self.0.contains(slug)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+
+    /// Add or remove an icon from the favorites
+    pub fn toggle(&mut self, slug: &str) {
+        if !self.0.remove(slug) {
+            self.0.insert(slug.to_string());
+        }
+        self.set_on_localstorage();
+    }
+
+    /// Filter the favorite icons, keeping the order of `icons`
+    ///
+    /// Slugs of icons that no longer exist are ignored.
+    pub fn icons(
+        &self,
+        icons: impl IntoIterator<Item = &'static SimpleIcon>,
+    ) -> Vec<&'static SimpleIcon> {
+        icons
+            .into_iter()
+            .filter(|icon| self.contains(icon.slug))
+            .collect()
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct FavoritesSignal(pub RwSignal<Favorites>);
+
+/// Button to add or remove an icon from the favorites
+#[component]
+pub fn IconFavoriteButton(
+    /// The icon
+    icon: &'static SimpleIcon,
+    /// Localized brand name
+    icon_localized_title: Memo<&'static str>,
+) -> impl IntoView {
+    let favorites = expect_context::<FavoritesSignal>().0;
+    let is_favorite = move || favorites.read().contains(icon.slug);
+
+    view! {
+        <button
+            class="favorite-button"
+            class:favorite=is_favorite
+            aria-pressed=move || is_favorite().to_string()
+            title=move || match is_favorite() {
+                true => tr!("remove-from-favorites", { "icon" => icon_localized_title() }),
+                false => tr!("add-to-favorites", { "icon" => icon_localized_title() }),
+            }
+            on:click=move |_| favorites.update(|favorites| favorites.toggle(icon.slug))
+        >
+            {move || match is_favorite() {
+                true => view! { <Icon icon=BsStarFill /> },
+                false => view! { <Icon icon=BsStar /> },
+            }}
+        </button>
+    }
+}
+
+/// Link to the favorites page
+///
+/// Not displayed in the favorites page itself.
+#[component]
+pub fn FavoritesLink() -> impl IntoView {
+    let in_favorites_page = location()
+        .pathname()
+        .is_ok_and(|pathname| pathname.starts_with(FAVORITES_PAGE_PATH));
+
+    (!in_favorites_page).then(|| {
+        view! {
+            <a class="favorites-link" href=FAVORITES_PAGE_PATH>
+                <Icon icon=BsStarFill />
+                {move_tr!("favorites")}
+            </a>
+        }
+    })
+}
@@ -1,21 +1,32 @@
-use crate::{CurrentIconViewSignal, item::title::get_icon_localized_title};
+use crate::{
+    CurrentIconViewSignal,
+    item::{
+        export::IconDetailsModalExport, snippets::IconDetailsModalSnippets,
+        title::get_icon_localized_title,
+    },
+};
 use icondata::{
     BiCheckRegular, BiLinkAltRegular, BiMenuAltRightRegular, BiMenuRegular,
-    BsCode, BsWindowFullscreen, IoColorWand, TbJpgOutline, TbPdfOutline,
-    TbPngOutline, TbSvgOutline, VsSymbolNamespace,
+    BsCode, BsWindowFullscreen, IoColorWand, TbAppWindowOutline,
+    TbBrandAndroidOutline, TbBrandWindowsOutline, TbFileVectorOutline,
+    TbJpgOutline, TbPdfOutline, TbPhotoOutline, TbPngOutline, TbSvgOutline,
+    VsSymbolNamespace,
 };
 use leptos::{ev::MouseEvent, prelude::*, task::spawn_local};
 use leptos_fluent::{I18n, move_tr, tr};
 use leptos_icons::Icon;
 use leptos_use::{UseClipboardReturn, on_click_outside, use_clipboard};
 use simple_icons_website_controls::download::{
-    add_pdfkit_scripts, copy_as_base64_jpg, copy_as_base64_png,
-    copy_as_image_jpg, copy_as_image_png, download, download_jpg, download_pdf,
-    download_png, download_svg,
+    ExportOptionsSignal, alert_pdf_error, copy_as_base64_jpg,
+    copy_as_base64_png, copy_as_image_jpg, copy_as_image_png,
+    download_colored_svg, download_eps, download_favicon_pack, download_jpg,
+    download_pdf, download_png, download_svg, download_vector_drawable,
+    download_webp, download_xaml, export_svg,
 };
 use simple_icons_website_copy::{
     copy_and_set_copied_transition, copy_child_img_src_content_from_mouse_event,
 };
+use simple_icons_website_i18n::language_name;
 use simple_icons_website_ids::Ids;
 use simple_icons_website_menu::{Menu, MenuItem};
 use simple_icons_website_modal::{Modal, ModalOpenSignal};
@@ -64,10 +75,6 @@ pub fn fill_icon_details_modal_with_icon(
     i18n: I18n,
     icon: &'static SimpleIcon,
 ) {
-    // Load pdfkit and blob-stream scripts to avoid loading when
-    // the user clicks on the download pdf button
-    add_pdfkit_scripts();
-
     let language = (i18n.language)();
     let icon_localized_title = get_icon_localized_title(icon, language);
 
@@ -244,6 +251,57 @@ pub fn fill_icon_details_modal_with_icon(
     }
 }
 
+/// Fill the details modal with an icon and open it
+pub fn open_icon_details_modal(
+    i18n: I18n,
+    icon: &'static SimpleIcon,
+    current_icon_view: RwSignal<Option<&'static SimpleIcon>>,
+    modal_open: ModalOpenSignal,
+) {
+    fill_icon_details_modal_with_icon(i18n, icon);
+    current_icon_view.update(|state| *state = Some(icon));
+    modal_open.set_icon(icon.slug);
+}
+
+/// Tabs of the details modal
+#[derive(Copy, Clone, PartialEq)]
+enum IconDetailsModalTab {
+    /// Preview and information of the icon
+    Details,
+    /// Code snippets to use the icon
+    Snippets,
+    /// Options applied when exporting the icon
+    Export,
+}
+
+/// Buttons to switch between the tabs of the details modal
+///
+/// Rendered after the content of the modal, so the elements of the details
+/// tab can be located by their position, but displayed before it.
+#[component]
+fn IconDetailsModalTabs(tab: RwSignal<IconDetailsModalTab>) -> impl IntoView {
+    let tab_button = move |value: IconDetailsModalTab, text: Signal<String>| {
+        view! {
+            <button
+                type="button"
+                role="tab"
+                aria-selected=move || (tab() == value).to_string()
+                on:click=move |_| tab.set(value)
+            >
+                {text}
+            </button>
+        }
+    };
+
+    view! {
+        <div class="tabs" role="tablist">
+            {tab_button(IconDetailsModalTab::Details, move_tr!("details"))}
+            {tab_button(IconDetailsModalTab::Snippets, move_tr!("snippets"))}
+            {tab_button(IconDetailsModalTab::Export, move_tr!("export"))}
+        </div>
+    }
+}
+
 /// Details modal icon preview
 #[component]
 fn IconDetailsModalPreview() -> impl IntoView {
@@ -269,15 +327,138 @@ fn IconDetailsModalInformation() -> impl IntoView {
             <a target="_blank">{move || tr!("brand-guidelines")}</a>
             <a target="_blank" title=move || tr!("license")></a>
             <p></p>
+            <IconDetailsModalAliases />
         </div>
     }
 }
 
+/// Details modal aliases of the icon
+///
+/// Other names of the brand and other brands that use the same icon.
+#[component]
+fn IconDetailsModalAliases() -> impl IntoView {
+    let current_icon_view = expect_context::<CurrentIconViewSignal>().0;
+    let languages = expect_context::<I18n>().languages;
+    let localized_name =
+        move |(language, title): &(&'static str, &'static str)| {
+            format!(
+                "{}: {title}",
+                language_name(languages, language).unwrap_or(language)
+            )
+        };
+
+    move || {
+        current_icon_view()
+            .and_then(|icon| icon.aliases)
+            .map(|aliases| {
+                view! {
+                    <div class="aliases">
+                        {aliases
+                            .aka
+                            .map(|aka| {
+                                view! {
+                                    <h4>{move_tr!("also-known-as")}</h4>
+                                    <ul>
+                                        {aka
+                                            .iter()
+                                            .map(|name| view! { <li>{*name}</li> })
+                                            .collect_view()}
+                                    </ul>
+                                }
+                            })}
+                        {aliases
+                            .old
+                            .map(|old| {
+                                view! {
+                                    <h4>{move_tr!("old-names")}</h4>
+                                    <ul>
+                                        {old
+                                            .iter()
+                                            .map(|name| view! { <li>{*name}</li> })
+                                            .collect_view()}
+                                    </ul>
+                                }
+                            })}
+                        {aliases
+                            .loc
+                            .map(|loc| {
+                                view! {
+                                    <h4>{move_tr!("localized-names")}</h4>
+                                    <ul>
+                                        {loc
+                                            .iter()
+                                            .map(|loc| view! { <li>{localized_name(loc)}</li> })
+                                            .collect_view()}
+                                    </ul>
+                                }
+                            })}
+                        {aliases
+                            .dup
+                            .map(|dup| {
+                                view! {
+                                    <h4>{move_tr!("brands-sharing-the-icon")}</h4>
+                                    <ul>
+                                        {dup
+                                            .iter()
+                                            .map(|duplicate| {
+                                                view! {
+                                                    <li>
+                                                        {duplicate.title}
+                                                        {duplicate
+                                                            .hex
+                                                            .map(|hex| {
+                                                                view! {
+                                                                    <span
+                                                                        class="hex"
+                                                                        class:relatively-light=duplicate.hex_is_relatively_light
+                                                                        style:background-color=format!("#{hex}")
+                                                                    >
+                                                                        {format!("#{hex}")}
+                                                                    </span>
+                                                                }
+                                                            })}
+                                                        {duplicate
+                                                            .guidelines
+                                                            .map(|guidelines| {
+                                                                view! {
+                                                                    <a href=guidelines target="_blank">
+                                                                        {move_tr!("brand-guidelines")}
+                                                                    </a>
+                                                                }
+                                                            })}
+                                                        {duplicate
+                                                            .loc
+                                                            .map(|loc| {
+                                                                view! {
+                                                                    <small>
+                                                                        {loc
+                                                                            .iter()
+                                                                            .map(localized_name)
+                                                                            .collect::<Vec<_>>()
+                                                                            .join(", ")}
+                                                                    </small>
+                                                                }
+                                                            })}
+                                                    </li>
+                                                }
+                                            })
+                                            .collect_view()}
+                                    </ul>
+                                }
+                            })}
+                    </div>
+                }
+            })
+    }
+}
+
 /// Detail modal view for icons
 #[component]
 pub fn IconDetailsModal() -> impl IntoView {
     let current_icon_view = expect_context::<CurrentIconViewSignal>().0;
     let modal_open = expect_context::<ModalOpenSignal>();
+    let export_options = expect_context::<ExportOptionsSignal>().0;
+    let i18n = expect_context::<I18n>();
 
     let (controls_open, set_controls_open) = signal(false);
     let menu_ref = NodeRef::new();
@@ -288,6 +469,7 @@ pub fn IconDetailsModal() -> impl IntoView {
     });
 
     let modal_is_open = Signal::derive(move || current_icon_view().is_some());
+    let tab = RwSignal::new(IconDetailsModalTab::Details);
 
     let (copying_as_base64_svg, set_copying_as_base64_svg) = signal(false);
     let copy_as_base64_svg_icon = Signal::derive(move || {
@@ -366,6 +548,16 @@ pub fn IconDetailsModal() -> impl IntoView {
         move_tr!("download-filetype", {"filetype" => tr!("jpg")});
     let download_png_msg =
         move_tr!("download-filetype", {"filetype" => tr!("png")});
+    let download_webp_msg =
+        move_tr!("download-filetype", {"filetype" => tr!("webp")});
+    let download_vector_drawable_msg =
+        move_tr!("download-filetype", {"filetype" => tr!("vector-drawable")});
+    let download_xaml_msg =
+        move_tr!("download-filetype", {"filetype" => tr!("xaml")});
+    let download_eps_msg =
+        move_tr!("download-filetype", {"filetype" => tr!("eps")});
+    let download_favicon_pack_msg =
+        move_tr!("download-filetype", {"filetype" => tr!("favicon-pack")});
 
     let (copying_svg, set_copying_svg) = signal(false);
     let copy_svg_msg = Signal::derive(move || match copying_svg() {
@@ -470,10 +662,15 @@ pub fn IconDetailsModal() -> impl IntoView {
             on_close=Signal::derive(move || {
                 current_icon_view.update(|state| *state = None);
                 modal_open.set_none();
+                tab.set(IconDetailsModalTab::Details);
             })
         >
 
-            <div class="icon-details-modal" id=Ids::IconDetailsModal>
+            <div
+                class="icon-details-modal"
+                class:details-hidden=move || tab() != IconDetailsModalTab::Details
+                id=Ids::IconDetailsModal
+            >
                 <div>
                     <IconDetailsModalPreview />
                     <IconDetailsModalInformation />
@@ -507,16 +704,24 @@ pub fn IconDetailsModal() -> impl IntoView {
                                 text=download_svg_msg
                                 icon=TbSvgOutline
                                 on:click=move |_| {
-                                    let slug = get_slug_from_modal_container();
-                                    download_svg(&slug);
+                                    if let Some(icon) = current_icon_view.get_untracked() {
+                                        download_svg(icon, &export_options.read_untracked());
+                                    }
                                 }
                             />
                             <DetailsMenuItem
                                 text=download_pdf_msg
                                 icon=TbPdfOutline
                                 on:click=move |_| {
-                                    let slug = get_slug_from_modal_container();
-                                    download_pdf(&slug);
+                                    let Some(icon) = current_icon_view.get_untracked() else {
+                                        return;
+                                    };
+                                    let options = export_options.get_untracked();
+                                    spawn_local(async move {
+                                        if let Err(error) = download_pdf(icon, &options).await {
+                                            alert_pdf_error(i18n, &error);
+                                        }
+                                    });
                                 }
                             />
 
@@ -524,8 +729,9 @@ pub fn IconDetailsModal() -> impl IntoView {
                                 text=download_png_msg
                                 icon=TbPngOutline
                                 on:click=move |_| {
-                                    let slug = get_slug_from_modal_container();
-                                    download_png(&slug);
+                                    if let Some(icon) = current_icon_view.get_untracked() {
+                                        download_png(icon, &export_options.read_untracked());
+                                    }
                                 }
                             />
 
@@ -533,8 +739,62 @@ pub fn IconDetailsModal() -> impl IntoView {
                                 text=download_jpg_msg
                                 icon=TbJpgOutline
                                 on:click=move |_| {
-                                    let slug = get_slug_from_modal_container();
-                                    download_jpg(&slug);
+                                    if let Some(icon) = current_icon_view.get_untracked() {
+                                        download_jpg(icon, &export_options.read_untracked());
+                                    }
+                                }
+                            />
+
+                            <DetailsMenuItem
+                                text=download_webp_msg
+                                icon=TbPhotoOutline
+                                on:click=move |_| {
+                                    if let Some(icon) = current_icon_view.get_untracked() {
+                                        download_webp(icon, &export_options.read_untracked());
+                                    }
+                                }
+                            />
+
+                            <DetailsMenuItem
+                                text=download_vector_drawable_msg
+                                icon=TbBrandAndroidOutline
+                                on:click=move |_| {
+                                    if let Some(icon) = current_icon_view.get_untracked() {
+                                        download_vector_drawable(
+                                            icon,
+                                            &export_options.read_untracked(),
+                                        );
+                                    }
+                                }
+                            />
+
+                            <DetailsMenuItem
+                                text=download_xaml_msg
+                                icon=TbBrandWindowsOutline
+                                on:click=move |_| {
+                                    if let Some(icon) = current_icon_view.get_untracked() {
+                                        download_xaml(icon, &export_options.read_untracked());
+                                    }
+                                }
+                            />
+
+                            <DetailsMenuItem
+                                text=download_eps_msg
+                                icon=TbFileVectorOutline
+                                on:click=move |_| {
+                                    if let Some(icon) = current_icon_view.get_untracked() {
+                                        download_eps(icon, &export_options.read_untracked());
+                                    }
+                                }
+                            />
+
+                            <DetailsMenuItem
+                                text=download_favicon_pack_msg
+                                icon=TbAppWindowOutline
+                                on:click=move |_| {
+                                    if let Some(icon) = current_icon_view.get_untracked() {
+                                        download_favicon_pack(icon);
+                                    }
                                 }
                             />
 
@@ -542,24 +802,12 @@ pub fn IconDetailsModal() -> impl IntoView {
                                 text=download_colored_svg_msg
                                 icon=TbSvgOutline
                                 on:click=move |_| {
-                                    let slug = get_slug_from_modal_container();
-                                    let hex = get_hex_from_modal_container();
-                                    spawn_local(async move {
-                                        match fetch_text(&format!("/icons/{}.svg", &slug)).await {
-                                            Ok(svg) => {
-                                                let colored_icon_svg = svg
-                                                    .replacen("<svg", &format!("<svg fill=\"{hex}\""), 1);
-                                                download(
-                                                    &format!("{slug}-color.svg"),
-                                                    &format!(
-                                                        "data:image/svg+xml;utf8,{}",
-                                                        js_sys::encode_uri_component(&colored_icon_svg),
-                                                    ),
-                                                );
-                                            }
-                                            Err(err) => leptos::logging::error!("{}", err),
-                                        }
-                                    });
+                                    if let Some(icon) = current_icon_view.get_untracked() {
+                                        download_colored_svg(
+                                            icon,
+                                            &export_options.read_untracked(),
+                                        );
+                                    }
                                 }
                             />
 
@@ -567,10 +815,13 @@ pub fn IconDetailsModal() -> impl IntoView {
                                 text=copy_svg_msg
                                 icon=copy_svg_icon
                                 on:click=move |_| {
-                                    let slug = get_slug_from_modal_container();
+                                    let Some(icon) = current_icon_view.get_untracked() else {
+                                        return;
+                                    };
+                                    let options = export_options.get_untracked();
                                     set_copying_svg(true);
                                     spawn_local(async move {
-                                        match fetch_text(&format!("/icons/{slug}.svg")).await {
+                                        match export_svg(icon, &options).await {
                                             Ok(svg) => {
                                                 copy_and_set_copied_transition(
                                                     &svg,
@@ -597,9 +848,11 @@ pub fn IconDetailsModal() -> impl IntoView {
                                 text=copy_png_msg
                                 icon=copy_png_icon
                                 on:click=move |_| {
-                                    let slug = get_slug_from_modal_container();
+                                    let Some(icon) = current_icon_view.get_untracked() else {
+                                        return;
+                                    };
                                     set_copying_png(true);
-                                    copy_as_image_png(&slug);
+                                    copy_as_image_png(icon, &export_options.read_untracked());
                                     set_timeout(
                                         move || set_copying_png(false),
                                         std::time::Duration::from_secs(1),
@@ -611,9 +864,11 @@ pub fn IconDetailsModal() -> impl IntoView {
                                 text=copy_jpg_msg
                                 icon=copy_jpg_icon
                                 on:click=move |_| {
-                                    let slug = get_slug_from_modal_container();
+                                    let Some(icon) = current_icon_view.get_untracked() else {
+                                        return;
+                                    };
                                     set_copying_jpg(true);
-                                    copy_as_image_jpg(&slug);
+                                    copy_as_image_jpg(icon, &export_options.read_untracked());
                                     set_timeout(
                                         move || set_copying_jpg(false),
                                         std::time::Duration::from_secs(1),
@@ -691,9 +946,12 @@ pub fn IconDetailsModal() -> impl IntoView {
                                         move || set_copying_as_base64_svg(false),
                                         std::time::Duration::from_secs(1),
                                     );
-                                    let slug = get_slug_from_modal_container();
+                                    let Some(icon) = current_icon_view.get_untracked() else {
+                                        return;
+                                    };
+                                    let options = export_options.get_untracked();
                                     spawn_local(async move {
-                                        match fetch_text(&format!("/icons/{slug}.svg")).await {
+                                        match export_svg(icon, &options).await {
                                             Ok(svg) => {
                                                 let base64 = window().btoa(&svg).unwrap();
                                                 let base64_svg = format!(
@@ -725,8 +983,9 @@ pub fn IconDetailsModal() -> impl IntoView {
                                         move || set_copying_as_base64_jpg(false),
                                         std::time::Duration::from_secs(1),
                                     );
-                                    let slug = get_slug_from_modal_container();
-                                    copy_as_base64_jpg(&slug);
+                                    if let Some(icon) = current_icon_view.get_untracked() {
+                                        copy_as_base64_jpg(icon, &export_options.read_untracked());
+                                    }
                                 }
                             />
 
@@ -742,8 +1001,9 @@ pub fn IconDetailsModal() -> impl IntoView {
                                         move || set_copying_as_base64_png(false),
                                         std::time::Duration::from_secs(1),
                                     );
-                                    let slug = get_slug_from_modal_container();
-                                    copy_as_base64_png(&slug);
+                                    if let Some(icon) = current_icon_view.get_untracked() {
+                                        copy_as_base64_png(icon, &export_options.read_untracked());
+                                    }
                                 }
                             />
 
@@ -780,7 +1040,7 @@ pub fn IconDetailsModal() -> impl IntoView {
                                         .split("/")
                                         .collect::<Vec<&str>>();
                                     let url = format!(
-                                        "{}//{}/?modal=icon&q={}",
+                                        "{}//{}/?icon={}",
                                         current_url_split[0],
                                         current_url_split[2],
                                         get_slug_from_modal_container(),
@@ -833,6 +1093,13 @@ pub fn IconDetailsModal() -> impl IntoView {
                         </Menu>
                     </Show>
                 </div>
+                <Show when=move || tab() == IconDetailsModalTab::Snippets>
+                    <IconDetailsModalSnippets />
+                </Show>
+                <Show when=move || tab() == IconDetailsModalTab::Export>
+                    <IconDetailsModalExport />
+                </Show>
+                <IconDetailsModalTabs tab />
             </div>
         </Modal>
     }
@@ -0,0 +1,36 @@
+//! Export options of the icons
+
+use crate::CurrentIconViewSignal;
+use leptos::prelude::*;
+use leptos_fluent::move_tr;
+use simple_icons_website_controls::download::{
+    ExportOptionsPanel, ExportOptionsSignal, export_svg,
+};
+
+/// Details modal export tab
+///
+/// Options applied when exporting the icons, with a preview of the icon
+/// displayed in the modal exported with them.
+#[component]
+pub fn IconDetailsModalExport() -> impl IntoView {
+    let current_icon_view = expect_context::<CurrentIconViewSignal>().0;
+    let export_options = expect_context::<ExportOptionsSignal>().0;
+    let preview = LocalResource::new(move || {
+        let icon = current_icon_view();
+        let options = export_options();
+        async move {
+            let svg = export_svg(icon?, &options).await.ok()?;
+            Some(format!(
+                "data:image/svg+xml;utf8,{}",
+                js_sys::encode_uri_component(&svg),
+            ))
+        }
+    });
+
+    view! {
+        <div class="export">
+            <ExportOptionsPanel />
+            <img alt=move_tr!("preview") src=move || preview.get().flatten() />
+        </div>
+    }
+}
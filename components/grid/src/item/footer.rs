@@ -1,11 +1,9 @@
-use crate::{
-    CurrentIconViewSignal, item::details::fill_icon_details_modal_with_icon,
-};
+use crate::{CurrentIconViewSignal, item::details::open_icon_details_modal};
 use leptos::ev::MouseEvent;
-use leptos::prelude::*;
+use leptos::{prelude::*, task::spawn_local};
 use leptos_fluent::{I18n, move_tr, tr};
 use simple_icons_website_controls::download::{
-    DownloadType, DownloadTypeSignal, download_png, download_svg,
+    DownloadTypeSignal, ExportOptionsSignal, alert_pdf_error, download_icon,
 };
 use simple_icons_website_controls_search::focus_search_bar;
 use simple_icons_website_copy::copy_and_set_copied_transition;
@@ -29,6 +27,7 @@ pub fn IconGridItemFooter(
 
     // Controls context
     let download_type = expect_context::<DownloadTypeSignal>().0;
+    let export_options = expect_context::<ExportOptionsSignal>().0;
 
     // Context to handle the opening state of detail modals
     let current_icon_view = expect_context::<CurrentIconViewSignal>().0;
@@ -59,9 +58,7 @@ pub fn IconGridItemFooter(
             <button
                 title=view_icon_button_title
                 on:click=move |_| {
-                    fill_icon_details_modal_with_icon(i18n, icon);
-                    current_icon_view.update(|state| *state = Some(icon));
-                    modal_open.set_icon();
+                    open_icon_details_modal(i18n, icon, current_icon_view, modal_open)
                 }
             >
 
@@ -69,13 +66,14 @@ pub fn IconGridItemFooter(
             </button>
             <button
                 title=move_tr!("download")
-                data-error-generating-pdf-msg=move_tr!("error-generating-pdf")
                 on:click=move |_| {
-                    if download_type() == DownloadType::SVG {
-                        download_svg(icon.slug);
-                    } else {
-                        download_png(icon.slug);
-                    }
+                    let download_type = download_type.get_untracked();
+                    let options = export_options.get_untracked();
+                    spawn_local(async move {
+                        if let Err(error) = download_icon(icon, &download_type, &options).await {
+                            alert_pdf_error(i18n, &error);
+                        }
+                    });
                     focus_search_bar();
                 }
             >
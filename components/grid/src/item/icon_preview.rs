@@ -1,23 +1,48 @@
 use leptos::prelude::*;
 use leptos_fluent::tr;
+use simple_icons_website_controls::preview_mode::{
+    PreviewMode, PreviewModeSignal,
+};
 use simple_icons_website_copy::copy_child_img_src_content_from_mouse_event;
+use simple_icons_website_types::SimpleIcon;
 
 /// Icon grid item preview
 ///
 /// The icon preview in the grid.
-/// Contains the lazy loaded logo of the brand.
+/// Contains the lazy loaded logo of the brand, displayed depending on the
+/// preview mode selected in the controls.
 #[component]
 pub fn IconGridItemPreview(
-    /// Icon slug
-    slug: &'static str,
+    /// The icon
+    icon: &'static SimpleIcon,
     /// Brand title
     title: Memo<&'static str>,
 ) -> impl IntoView {
+    let preview_mode = expect_context::<PreviewModeSignal>().0;
+    let src = format!("/icons/{}.svg", icon.slug);
+
     let title = move || tr!("copy-icon-svg", {"icon" => title()});
     let alt = move || tr!("subject-icon", {"icon" => title()});
     view! {
-        <button title=title on:click=copy_child_img_src_content_from_mouse_event>
-            <img src=format!("/icons/{}.svg", slug) alt=alt width=56 height=56 />
+        <button
+            title=title
+            class:preview-brand-background=move || {
+                preview_mode() == PreviewMode::BrandBackground
+            }
+            class:preview-brand-color=move || preview_mode() == PreviewMode::BrandColor
+            class:relatively-light=icon.hex_is_relatively_light
+            style:--brand-color=format!("#{}", icon.hex)
+            on:click=copy_child_img_src_content_from_mouse_event
+        >
+            <img src=src.clone() alt=alt width=56 height=56 />
+            // Images can't be colored, so the icon is used as a mask of
+            // the brand color
+            <Show when=move || preview_mode() == PreviewMode::BrandColor>
+                <span
+                    aria-hidden="true"
+                    style:mask-image=format!("url({src})")
+                ></span>
+            </Show>
         </button>
     }
 }
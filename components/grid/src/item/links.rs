@@ -1,6 +1,30 @@
 use leptos::prelude::*;
 use leptos_fluent::tr;
 
+/// Localized title of the license of an icon
+pub fn license_title(license_type: Option<&'static str>) -> String {
+    match license_type {
+        Some("custom") => tr!("custom-license"),
+        Some(license_type) => license_type.to_string(),
+        None => tr!("license"),
+    }
+}
+
+/// URL of the license of an icon
+///
+/// Licenses without URL are linked to their SPDX page.
+pub fn license_href(
+    license_url: Option<&'static str>,
+    license_type: Option<&'static str>,
+) -> Option<String> {
+    match license_url {
+        Some(license_url) => Some(license_url.to_string()),
+        None => license_type.map(|license_type| {
+            format!("https://spdx.org/licenses/{license_type}")
+        }),
+    }
+}
+
 #[component]
 pub fn IconGridItemLinks(
     /// Brand guidelines URL
@@ -33,30 +57,12 @@ pub fn IconGridItemLinks(
                         );
                 }
                 if license_type.is_some() || license_url.is_some() {
-                    let title = move || {
-                        match license_type {
-                            Some(license_type) => {
-                                match license_type {
-                                    "custom" => tr!("custom-license"),
-                                    _ => license_type.to_string(),
-                                }
-                            }
-                            None => tr!("license"),
-                        }
-                    };
+                    let title = move || license_title(license_type);
                     links
                         .push(
                             view! {
                                 <a
-                                    href=match license_url {
-                                        Some(license_url) => license_url.to_string(),
-                                        None => {
-                                            format!(
-                                                "https://spdx.org/licenses/{}",
-                                                license_type.unwrap(),
-                                            )
-                                        }
-                                    }
+                                    href=license_href(license_url, license_type)
 
                                     title=title
                                     class="license"
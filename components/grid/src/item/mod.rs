@@ -1,15 +1,20 @@
 mod deprecated;
 pub mod details;
+mod export;
 mod footer;
 pub(crate) mod icon_preview;
-mod links;
-mod title;
+pub(crate) mod links;
+mod snippets;
+pub(crate) mod title;
 
-use crate::item::title::get_icon_localized_title;
+use crate::{
+    favorites::IconFavoriteButton, item::title::get_icon_localized_title,
+    keyboard::GridKeyboardNavigation, selection::IconSelectionCheckbox,
+};
 use deprecated::IconIsDeprecatedNotice;
 use footer::IconGridItemFooter;
 use icon_preview::IconGridItemPreview;
-use leptos::prelude::*;
+use leptos::{html::Li, prelude::*};
 use leptos_fluent::I18n;
 use links::IconGridItemLinks;
 use simple_icons_website_types::SimpleIcon;
@@ -24,9 +29,29 @@ pub fn IconGridItem(icon: &'static SimpleIcon) -> impl IntoView {
         get_icon_localized_title(icon, (expect_context::<I18n>().language)())
     });
 
+    let keyboard_navigation = expect_context::<GridKeyboardNavigation>();
+    let item_ref = NodeRef::<Li>::new();
+    Effect::new(move |_| {
+        if keyboard_navigation.must_focus(icon.slug)
+            && let Some(item) = item_ref.get()
+        {
+            _ = item.focus();
+        }
+    });
+
     view! {
-        <li>
-            <IconGridItemPreview slug=icon.slug title=icon_localized_title />
+        <li
+            node_ref=item_ref
+            tabindex=move || match keyboard_navigation.tabbable_slug.get() == Some(icon.slug) {
+                true => "0",
+                false => "-1",
+            }
+            on:focus=move |_| keyboard_navigation.on_focus(icon.slug)
+            on:keydown=move |ev| keyboard_navigation.on_keydown(ev, icon)
+        >
+            <IconGridItemPreview icon title=icon_localized_title />
+            <IconSelectionCheckbox icon icon_localized_title />
+            <IconFavoriteButton icon icon_localized_title />
             <IconGridItemLinks
                 guidelines_url=icon.guidelines
                 license_url=icon.license_url
@@ -0,0 +1,197 @@
+//! Code snippets to integrate the icons in other projects
+
+use crate::CurrentIconViewSignal;
+use icon_format::escape_xml;
+use leptos::{ev::MouseEvent, prelude::*};
+use leptos_fluent::move_tr;
+use simple_icons_macros::js_library_version;
+use simple_icons_website_controls::download::svg_path;
+use simple_icons_website_copy::copy_and_set_copied_transition;
+use simple_icons_website_types::SimpleIcon;
+use web_sys_simple_fetch::fetch_text;
+
+static SIMPLE_ICONS_VERSION: &str = js_library_version!("simple-icons");
+
+/// Snippets generated for an icon
+#[derive(Copy, Clone)]
+enum Snippet {
+    /// `<img>` tag loading the icon from a CDN
+    CdnImage,
+    /// `<svg>` element with accessibility attributes
+    InlineSvg,
+    /// CSS rule displaying the icon in the brand color as a mask
+    CssMask,
+    /// Markdown image loading the icon from a CDN
+    Markdown,
+    /// React component rendering the icon
+    Jsx,
+    /// Command of the `simpleicons` LaTeX package
+    Latex,
+}
+
+impl Snippet {
+    const ALL: [Self; 6] = [
+        Self::CdnImage,
+        Self::InlineSvg,
+        Self::CssMask,
+        Self::Markdown,
+        Self::Jsx,
+        Self::Latex,
+    ];
+
+    fn label(&self) -> &'static str {
+        match self {
+            Self::CdnImage => "HTML <img>",
+            Self::InlineSvg => "Inline SVG",
+            Self::CssMask => "CSS mask-image",
+            Self::Markdown => "Markdown",
+            Self::Jsx => "JSX",
+            Self::Latex => "LaTeX",
+        }
+    }
+
+    /// Code of the snippet for an icon given the path of its SVG
+    fn code(&self, icon: &SimpleIcon, path: &str) -> String {
+        let title = escape_xml(icon.title);
+        let slug = icon.slug;
+        let cdn_url = cdn_url(slug);
+        match self {
+            Self::CdnImage => format!(
+                "<img height=\"32\" width=\"32\" src=\"{cdn_url}\" alt=\"{title}\" />"
+            ),
+            Self::InlineSvg => format!(
+                concat!(
+                    "<svg role=\"img\" aria-labelledby=\"si-{slug}-title\"",
+                    " viewBox=\"0 0 24 24\" xmlns=\"http://www.w3.org/2000/svg\">\n",
+                    "  <title id=\"si-{slug}-title\">{title}</title>\n",
+                    "  <path d=\"{path}\" />\n",
+                    "</svg>",
+                ),
+                slug = slug,
+                title = title,
+                path = path,
+            ),
+            Self::CssMask => format!(
+                concat!(
+                    ".si-{slug} {{\n",
+                    "  display: inline-block;\n",
+                    "  width: 24px;\n",
+                    "  height: 24px;\n",
+                    "  background-color: #{hex};\n",
+                    "  mask-image: url(\"{cdn_url}\");\n",
+                    "  mask-repeat: no-repeat;\n",
+                    "  mask-size: contain;\n",
+                    "}}",
+                ),
+                slug = slug,
+                hex = icon.hex,
+                cdn_url = cdn_url,
+            ),
+            Self::Markdown => format!(
+                "![{}]({cdn_url})",
+                icon.title.replace('[', "\\[").replace(']', "\\]")
+            ),
+            Self::Jsx => format!(
+                concat!(
+                    "const {component} = (props) => (\n",
+                    "  <svg role=\"img\" viewBox=\"0 0 24 24\"",
+                    " xmlns=\"http://www.w3.org/2000/svg\" fill=\"#{hex}\" {{...props}}>\n",
+                    "    <title>{title}</title>\n",
+                    "    <path d=\"{path}\" />\n",
+                    "  </svg>\n",
+                    ");",
+                ),
+                component = jsx_component_name(slug),
+                hex = icon.hex,
+                title = title.replace('{', "&#123;").replace('}', "&#125;"),
+                path = path,
+            ),
+            Self::Latex => format!("\\simpleicon{{{slug}}}"),
+        }
+    }
+}
+
+/// URL of an icon in the jsDelivr CDN for the version of the website
+fn cdn_url(slug: &str) -> String {
+    format!(
+        "https://cdn.jsdelivr.net/npm/simple-icons@{SIMPLE_ICONS_VERSION}/icons/{slug}.svg"
+    )
+}
+
+/// Name of the React component of an icon, like `SiGithub` for `github`
+fn jsx_component_name(slug: &str) -> String {
+    let mut chars = slug.chars();
+    match chars.next() {
+        Some(first) => {
+            format!("Si{}{}", first.to_ascii_uppercase(), chars.as_str())
+        }
+        None => "Si".to_string(),
+    }
+}
+
+/// Snippet with a button to copy it
+#[component]
+fn IconDetailsModalSnippet(snippet: Snippet, code: String) -> impl IntoView {
+    let code = StoredValue::new(code);
+
+    view! {
+        <li>
+            <h4>{snippet.label()}</h4>
+            <div>
+                <pre>
+                    <code>{code.get_value()}</code>
+                </pre>
+                <button
+                    type="button"
+                    title=move_tr!("copy-snippet")
+                    on:click=move |ev: MouseEvent| {
+                        let target = event_target::<web_sys::HtmlElement>(&ev);
+                        copy_and_set_copied_transition(&code.read_value(), target);
+                    }
+                ></button>
+            </div>
+        </li>
+    }
+}
+
+/// Details modal snippets tab
+///
+/// Code to integrate the icon displayed in the modal in other projects.
+#[component]
+pub fn IconDetailsModalSnippets() -> impl IntoView {
+    let current_icon_view = expect_context::<CurrentIconViewSignal>().0;
+    let svg = LocalResource::new(move || async move {
+        match current_icon_view() {
+            Some(icon) => fetch_text(&format!("/icons/{}.svg", icon.slug))
+                .await
+                .map(|svg| (icon, svg))
+                .ok(),
+            None => None,
+        }
+    });
+
+    view! {
+        <ul class="snippets">
+            {move || {
+                svg.get()
+                    .flatten()
+                    .and_then(|(icon, svg)| {
+                        let path = svg_path(&svg)?.to_string();
+                        Some(
+                            Snippet::ALL
+                                .iter()
+                                .map(|snippet| {
+                                    view! {
+                                        <IconDetailsModalSnippet
+                                            snippet=*snippet
+                                            code=snippet.code(icon, &path)
+                                        />
+                                    }
+                                })
+                                .collect_view(),
+                        )
+                    })
+            }}
+        </ul>
+    }
+}
@@ -0,0 +1,185 @@
+//! Keyboard navigation across the icons grid
+//!
+//! The grid items implement a roving tabindex: only one item is reachable
+//! with the Tab key and the arrow keys move the focus between the items.
+
+use crate::{
+    CurrentIconViewSignal,
+    item::details::open_icon_details_modal,
+    virtual_grid::{VirtualGrid, VirtualGridSignal},
+};
+use leptos::{ev::KeyboardEvent, prelude::*, task::spawn_local};
+use leptos_fluent::I18n;
+use simple_icons_website_controls::download::{
+    DownloadType, DownloadTypeSignal, ExportOptions, ExportOptionsSignal,
+    alert_pdf_error, download_icon,
+};
+use simple_icons_website_grid_types::{IconsGrid, IconsGridSignal};
+use simple_icons_website_modal::ModalOpenSignal;
+use simple_icons_website_types::SimpleIcon;
+
+/// Grid item that has been focused the last time
+#[derive(Copy, Clone, Default)]
+struct ActiveGridItem {
+    /// Slug of the icon of the item
+    slug: Option<&'static str>,
+    /// Whether the item must take the focus
+    focus: bool,
+}
+
+/// Keyboard navigation state of the icons grid
+#[derive(Copy, Clone)]
+pub struct GridKeyboardNavigation {
+    active_grid_item: RwSignal<ActiveGridItem>,
+    /// Slug of the icon of the unique item reachable with the Tab key
+    ///
+    /// It's the active item if it is mounted, otherwise the first item
+    /// mounted.
+    pub tabbable_slug: Memo<Option<&'static str>>,
+    icons_grid: RwSignal<IconsGrid>,
+    virtual_grid: RwSignal<VirtualGrid>,
+    i18n: I18n,
+    current_icon_view: RwSignal<Option<&'static SimpleIcon>>,
+    modal_open: ModalOpenSignal,
+    download_type: RwSignal<DownloadType>,
+    export_options: RwSignal<ExportOptions>,
+}
+
+impl GridKeyboardNavigation {
+    /// Whether the item of an icon must take the focus
+    pub fn must_focus(&self, slug: &str) -> bool {
+        let active_grid_item = self.active_grid_item.read();
+        active_grid_item.focus && active_grid_item.slug == Some(slug)
+    }
+
+    /// Set the item of an icon as the active one when it receives the focus
+    pub fn on_focus(&self, slug: &'static str) {
+        let active_grid_item = self.active_grid_item.get_untracked();
+        if active_grid_item.slug != Some(slug) || active_grid_item.focus {
+            self.active_grid_item.set(ActiveGridItem {
+                slug: Some(slug),
+                focus: false,
+            });
+        }
+    }
+
+    /// Handle the key pressed on a grid item
+    ///
+    /// - Arrow keys move the focus to the adjacent items.
+    /// - Enter opens the details modal of the icon.
+    /// - Shift+D downloads the icon in the current download type.
+    pub fn on_keydown(&self, ev: KeyboardEvent, icon: &'static SimpleIcon) {
+        // Only handle the keys pressed when the item itself is focused,
+        // not its buttons and links
+        if ev.target() != ev.current_target()
+            || ev.ctrl_key()
+            || ev.alt_key()
+            || ev.meta_key()
+        {
+            return;
+        }
+
+        match ev.key().as_str() {
+            key @ ("ArrowLeft" | "ArrowRight" | "ArrowUp" | "ArrowDown") => {
+                ev.prevent_default();
+                self.move_focus(key, icon);
+            }
+            "Enter" => {
+                ev.prevent_default();
+                open_icon_details_modal(
+                    self.i18n,
+                    icon,
+                    self.current_icon_view,
+                    self.modal_open,
+                );
+            }
+            "D" if ev.shift_key() => {
+                ev.prevent_default();
+                let i18n = self.i18n;
+                let download_type = self.download_type.get_untracked();
+                let options = self.export_options.get_untracked();
+                spawn_local(async move {
+                    if let Err(error) =
+                        download_icon(icon, &download_type, &options).await
+                    {
+                        alert_pdf_error(i18n, &error);
+                    }
+                });
+            }
+            _ => {}
+        }
+    }
+
+    fn move_focus(&self, key: &str, icon: &'static SimpleIcon) {
+        let loaded_icons = &self.icons_grid.read_untracked().loaded_icons;
+        let Some(index) = loaded_icons
+            .iter()
+            .position(|loaded_icon| loaded_icon.slug == icon.slug)
+        else {
+            return;
+        };
+
+        let virtual_grid = self.virtual_grid.read_untracked();
+        let (row, column) = virtual_grid.position_of(index);
+        let new_index = match key {
+            "ArrowLeft" => index.checked_sub(1),
+            "ArrowRight" => Some(index + 1),
+            "ArrowUp" => row.checked_sub(1).and_then(|row| {
+                virtual_grid.index_at(row, column, loaded_icons.len())
+            }),
+            _ => virtual_grid.index_at(row + 1, column, loaded_icons.len()),
+        };
+
+        if let Some(new_icon) = new_index.and_then(|i| loaded_icons.get(i)) {
+            self.active_grid_item.set(ActiveGridItem {
+                slug: Some(new_icon.slug),
+                focus: true,
+            });
+        }
+    }
+}
+
+pub fn provide_keyboard_navigation_context() {
+    let icons_grid = expect_context::<IconsGridSignal>().0;
+    let virtual_grid = expect_context::<VirtualGridSignal>().0;
+    let active_grid_item = RwSignal::new(ActiveGridItem::default());
+
+    let tabbable_slug = Memo::new(move |_| {
+        let loaded_icons = &icons_grid.read().loaded_icons;
+        let virtual_grid = virtual_grid.read();
+        let active_index = active_grid_item.read().slug.and_then(|slug| {
+            loaded_icons.iter().position(|icon| icon.slug == slug)
+        });
+
+        match active_index {
+            Some(index)
+                if virtual_grid.is_mounted(index, loaded_icons.len()) =>
+            {
+                Some(loaded_icons[index].slug)
+            }
+            _ => {
+                let first_mounted_index = match virtual_grid.pinned_icons {
+                    0 => {
+                        virtual_grid
+                            .mounted_icons_range(loaded_icons.len())
+                            .start
+                    }
+                    _ => 0,
+                };
+                loaded_icons.get(first_mounted_index).map(|icon| icon.slug)
+            }
+        }
+    });
+
+    provide_context(GridKeyboardNavigation {
+        active_grid_item,
+        tabbable_slug,
+        icons_grid,
+        virtual_grid,
+        i18n: expect_context::<I18n>(),
+        current_icon_view: expect_context::<CurrentIconViewSignal>().0,
+        modal_open: expect_context::<ModalOpenSignal>(),
+        download_type: expect_context::<DownloadTypeSignal>().0,
+        export_options: expect_context::<ExportOptionsSignal>().0,
+    });
+}
@@ -1,26 +1,44 @@
 #![feature(stmt_expr_attributes)]
 
 mod ad;
+pub mod collection;
+pub mod favorites;
 pub(crate) mod item;
+mod keyboard;
+mod list;
+mod no_results;
 mod scroll;
+pub(crate) mod selection;
+pub(crate) mod virtual_grid;
 
 use ad::CarbonAdsAdGridItem;
-use item::{IconGridItem, details::IconDetailsModal};
+use collection::IconsCollectionBar;
+use favorites::{Favorites, FavoritesSignal};
+use item::{
+    IconGridItem,
+    details::{IconDetailsModal, open_icon_details_modal},
+};
+use keyboard::provide_keyboard_navigation_context;
 use leptos::{
     html::Footer,
     prelude::{NodeRef, *},
 };
+use leptos_fluent::I18n;
 use leptos_use::use_intersection_observer;
+use list::IconsTable;
+use no_results::NoResults;
 use scroll::ScrollButtons;
+use selection::{IconsSelection, IconsSelectionBar, IconsSelectionSignal};
 use simple_icons_website_controls_layout_signal::LayoutSignal;
-use simple_icons_website_controls_layout_type::Layout;
+use simple_icons_website_controls_layout_type::{GridDimensions, Layout};
 use simple_icons_website_controls_order_types::OrderMode;
+use simple_icons_website_grid_constants::ICONS;
 use simple_icons_website_grid_icons_loader::{IconsLoader, IconsLoaderSignal};
 use simple_icons_website_grid_types::{IconsGrid, IconsGridSignal};
-use simple_icons_website_modal::ModalOpen;
+use simple_icons_website_modal::{ModalOpen, ModalOpenSignal};
 use simple_icons_website_types::SimpleIcon;
 use simple_icons_website_url as Url;
-use wasm_bindgen::JsCast;
+use virtual_grid::{VirtualGridSignal, provide_virtual_grid_context};
 
 /// Signal to control the current detail view modal of icons
 #[derive(Copy, Clone)]
@@ -32,29 +50,51 @@ pub fn provide_icons_grid_contexts(
     initial_layout: &Layout,
     icons: Vec<&'static SimpleIcon>,
 ) {
+    let window = window();
+    let dimensions = GridDimensions::estimate(
+        initial_layout,
+        window
+            .inner_width()
+            .ok()
+            .and_then(|width| width.as_f64())
+            .unwrap_or_default(),
+        window
+            .inner_height()
+            .ok()
+            .and_then(|height| height.as_f64())
+            .unwrap_or_default(),
+    );
     provide_context(IconsGridSignal(RwSignal::new(IconsGrid::new(
         initial_search_value,
         &initial_order_mode.current,
         initial_layout,
         icons,
+        dimensions,
     ))));
     provide_context(IconsLoaderSignal(RwSignal::new(IconsLoader::default())));
 }
 
-fn wait_for_first_grid_item_and_open_details(attempt: u32) {
-    if let Some(el) = document()
-        .query_selector(
-            "main > ul > :nth-child(2) > :last-child > :nth-child(2)",
-        )
-        .unwrap_or(None)
-    {
-        el.unchecked_into::<web_sys::HtmlElement>().click();
-    } else if attempt < 2000 {
-        set_timeout(
-            move || wait_for_first_grid_item_and_open_details(attempt + 1),
-            std::time::Duration::from_millis(5),
-        );
+/// Icon whose details modal must be opened when the grid is loaded
+///
+/// Icons are referenced by their slug in the `icon` URL param. Links
+/// created before this param existed open the icon whose slug is the
+/// search value, like `?modal=icon&q=<slug>`.
+fn icon_to_open_from_url() -> Option<&'static SimpleIcon> {
+    let slug = Url::params::get(&Url::params::Names::Icon).or_else(|| {
+        match Url::params::get(&Url::params::Names::Modal)
+            == Some(ModalOpen::Icon.to_string())
+        {
+            true => Url::params::get(&Url::params::Names::Query),
+            false => None,
+        }
+    })?;
+    let icon = ICONS.iter().find(|icon| icon.slug == slug);
+    if icon.is_none() {
+        let msg = format!("Icon \"{slug}\" of the URL not found");
+        web_sys::console::error_1(&msg.into());
+        Url::params::update(&Url::params::Names::Icon, "");
     }
+    icon
 }
 
 /// Icons grid
@@ -62,24 +102,67 @@ fn wait_for_first_grid_item_and_open_details(attempt: u32) {
 /// The icons grid items are lazy loaded with pagination. The first page is
 /// loaded on the first render. The next pages are loaded when the user
 /// scrolls to the footer.
+///
+/// Only the loaded icons placed in rows near the viewport are mounted, see
+/// [`virtual_grid`].
 #[component]
 pub fn Icons() -> impl IntoView {
     let icons_grid = expect_context::<IconsGridSignal>().0;
+    let virtual_grid = expect_context::<VirtualGridSignal>().0;
+
+    let pinned_icons = move || {
+        let pinned_icons = virtual_grid.read().pinned_icons;
+        icons_grid
+            .read()
+            .loaded_icons
+            .iter()
+            .take(pinned_icons)
+            .copied()
+            .collect::<Vec<_>>()
+    };
+    let mounted_icons = move || {
+        let loaded_icons = &icons_grid.read().loaded_icons;
+        loaded_icons
+            [virtual_grid.read().mounted_icons_range(loaded_icons.len())]
+        .to_vec()
+    };
 
     view! {
         <For
-            each=move || icons_grid().loaded_icons
+            each=pinned_icons
+            key=move |icon| icon.slug
+            children=move |icon: &'static SimpleIcon| {
+                view! { <IconGridItem icon /> }
+            }
+        />
+        <Show when=move || { virtual_grid.read().top_spacer_height > 0.0 }>
+            <li
+                class="grid-spacer grid-spacer-top"
+                aria-hidden="true"
+                style:height=move || format!("{}px", virtual_grid.read().top_spacer_height)
+            ></li>
+        </Show>
+        <For
+            each=mounted_icons
             key=move |icon| icon.slug
             children=move |icon: &'static SimpleIcon| {
                 view! { <IconGridItem icon /> }
             }
         />
+        <Show when=move || { virtual_grid.read().bottom_spacer_height > 0.0 }>
+            <li
+                class="grid-spacer"
+                aria-hidden="true"
+                style:height=move || format!("{}px", virtual_grid.read().bottom_spacer_height)
+            ></li>
+        </Show>
     }
 }
 
 /// Main grid
 ///
-/// Includes the Carbon Ads ad and the icons
+/// Includes the Carbon Ads ad and the icons. In the list layout the icons
+/// are displayed in a table below the ad, see [`list`].
 ///
 /// When the user scrolls nearly to the footer, the next page of icons
 /// are loaded. This is accomplished by using an `IntersectionObserver`,
@@ -92,6 +175,16 @@ pub fn Grid() -> impl IntoView {
     // Provide the context for the current icon details view
     provide_context(CurrentIconViewSignal(RwSignal::new(None)));
 
+    // Provide the context for the icons marked as favorites
+    provide_context(FavoritesSignal(RwSignal::new(
+        Favorites::from_localstorage(),
+    )));
+
+    // Provide the context for the selection of icons
+    provide_context(IconsSelectionSignal(RwSignal::new(
+        IconsSelection::default(),
+    )));
+
     let icons_grid = expect_context::<IconsGridSignal>().0;
     let icons_loader: RwSignal<IconsLoader> =
         expect_context::<IconsLoaderSignal>().0;
@@ -112,19 +205,41 @@ pub fn Grid() -> impl IntoView {
     });
 
     let icons_list_ref = NodeRef::new();
+    let icons_table_body_ref = NodeRef::new();
+    provide_virtual_grid_context(icons_list_ref, icons_table_body_ref);
+    provide_keyboard_navigation_context();
+    let i18n = expect_context::<I18n>();
+    let current_icon_view = expect_context::<CurrentIconViewSignal>().0;
+    let modal_open = expect_context::<ModalOpenSignal>();
+    // The details modal is mounted before the grid
     icons_list_ref.on_load(move |_| {
-        let modal_param = Url::params::get(&Url::params::Names::Modal);
-        if modal_param == Some(ModalOpen::Icon.to_string()) {
-            wait_for_first_grid_item_and_open_details(1);
+        if let Some(icon) = icon_to_open_from_url() {
+            open_icon_details_modal(i18n, icon, current_icon_view, modal_open);
         }
     });
 
     view! {
         <IconDetailsModal />
-        <ul node_ref=icons_list_ref class:layout-compact=move || layout() == Layout::Compact>
+        <IconsCollectionBar />
+        <IconsSelectionBar />
+        <ul
+            node_ref=icons_list_ref
+            class:layout-compact=move || layout() == Layout::Compact
+            class:layout-list=move || layout() == Layout::List
+            data-loaded-icons=move || icons_grid.read().loaded_icons.len()
+            data-columns=move || icons_grid.read().dimensions.columns
+            data-ad-cells=move || icons_grid.read().dimensions.ad_cells
+            data-viewport-height=move || icons_grid.read().dimensions.viewport_height
+        >
             <CarbonAdsAdGridItem />
-            <Icons />
+            <Show when=move || layout() != Layout::List>
+                <Icons />
+            </Show>
         </ul>
+        <Show when=move || layout() == Layout::List>
+            <IconsTable body_ref=icons_table_body_ref />
+        </Show>
+        <NoResults />
         <IconsLoader />
         <ScrollButtons />
     }
@@ -0,0 +1,326 @@
+//! List layout of the icons grid
+//!
+//! The loaded icons are displayed in a table, a row per icon, which is
+//! convenient to compare the colors and the licenses of the brands. The
+//! rows are loaded with the same pagination as the items of the grid and
+//! only the ones near the viewport are mounted, see [`crate::virtual_grid`].
+//!
+//! The rows can be selected, marked as favorites and navigated with the
+//! keyboard like the items of the grid.
+
+use crate::{
+    CurrentIconViewSignal,
+    favorites::IconFavoriteButton,
+    item::{
+        details::open_icon_details_modal,
+        icon_preview::IconGridItemPreview,
+        links::{license_href, license_title},
+        title::get_icon_localized_title,
+    },
+    keyboard::GridKeyboardNavigation,
+    selection::IconSelectionCheckbox,
+    virtual_grid::VirtualGridSignal,
+};
+use core::cmp::Ordering;
+use leptos::{
+    ev::MouseEvent,
+    html::{Tbody, Tr},
+    prelude::*,
+};
+use leptos_fluent::{I18n, move_tr, tr};
+use simple_icons_website_controls::{
+    order::OrderModeSignal, search::SearchValueSignal,
+};
+use simple_icons_website_copy::copy_and_set_copied_transition;
+use simple_icons_website_grid_types::IconsGridSignal;
+use simple_icons_website_modal::ModalOpenSignal;
+use simple_icons_website_types::SimpleIcon;
+
+/// Number of columns of the table
+const ICONS_TABLE_COLUMNS: u8 = 7;
+
+/// Columns of the table by which the icons can be sorted
+#[derive(Copy, Clone, PartialEq)]
+enum IconsTableColumn {
+    Title,
+    Slug,
+    Hex,
+    License,
+    Guidelines,
+}
+
+impl IconsTableColumn {
+    fn compare(&self, a: &SimpleIcon, b: &SimpleIcon) -> Ordering {
+        match self {
+            Self::Title => a.order_alpha.cmp(&b.order_alpha),
+            Self::Slug => a.slug.cmp(b.slug),
+            Self::Hex => a.hex.cmp(b.hex),
+            // Icons without license at the end
+            Self::License => a
+                .license_type
+                .is_none()
+                .cmp(&b.license_type.is_none())
+                .then(a.license_type.cmp(&b.license_type)),
+            // Icons with brand guidelines first
+            Self::Guidelines => {
+                b.guidelines.is_some().cmp(&a.guidelines.is_some())
+            }
+        }
+    }
+}
+
+/// Column by which the icons of the table are sorted
+#[derive(Copy, Clone, PartialEq)]
+struct IconsTableSort {
+    column: IconsTableColumn,
+    descending: bool,
+}
+
+impl IconsTableSort {
+    fn sort(&self, icons: &mut [&'static SimpleIcon]) {
+        icons.sort_by(|a, b| match self.descending {
+            true => self.column.compare(b, a),
+            false => self.column.compare(a, b),
+        });
+    }
+}
+
+/// Header of a column of the table that sorts the icons when clicked
+#[component]
+fn IconsTableSortableHeader(
+    /// Column text
+    #[prop(into)]
+    text: Signal<String>,
+    /// Column of the header
+    column: IconsTableColumn,
+    /// Current sort of the table
+    sort: RwSignal<Option<IconsTableSort>>,
+) -> impl IntoView {
+    let icons_grid = expect_context::<IconsGridSignal>().0;
+
+    let sort_by_column = move |_| {
+        let new_sort = match sort.get_untracked() {
+            Some(current) if current.column == column => IconsTableSort {
+                column,
+                descending: !current.descending,
+            },
+            _ => IconsTableSort {
+                column,
+                descending: false,
+            },
+        };
+        sort.set(Some(new_sort));
+
+        // Sort all the icons of the grid, not only the loaded ones,
+        // keeping the number of icons loaded
+        icons_grid.update(|grid| {
+            new_sort.sort(&mut grid.icons);
+            let loaded_icons_length = grid.loaded_icons.len();
+            grid.loaded_icons = grid.icons[..loaded_icons_length].to_vec();
+        });
+    };
+
+    view! {
+        <th aria-sort=move || match sort() {
+            Some(IconsTableSort { column: c, descending }) if c == column => {
+                match descending {
+                    true => "descending",
+                    false => "ascending",
+                }
+            }
+            _ => "none",
+        }>
+            <button type="button" on:click=sort_by_column>
+                {text}
+            </button>
+        </th>
+    }
+}
+
+/// Row of the table of icons
+#[component]
+fn IconsTableRow(icon: &'static SimpleIcon) -> impl IntoView {
+    let i18n = expect_context::<I18n>();
+    let icon_localized_title =
+        Memo::new(move |_| get_icon_localized_title(icon, (i18n.language)()));
+    let current_icon_view = expect_context::<CurrentIconViewSignal>().0;
+    let modal_open = expect_context::<ModalOpenSignal>();
+    let css_hex = format!("#{}", icon.hex);
+
+    let keyboard_navigation = expect_context::<GridKeyboardNavigation>();
+    let row_ref = NodeRef::<Tr>::new();
+    Effect::new(move |_| {
+        if keyboard_navigation.must_focus(icon.slug)
+            && let Some(row) = row_ref.get()
+        {
+            _ = row.focus();
+        }
+    });
+
+    view! {
+        <tr
+            node_ref=row_ref
+            tabindex=move || match keyboard_navigation.tabbable_slug.get() == Some(icon.slug) {
+                true => "0",
+                false => "-1",
+            }
+            on:focus=move |_| keyboard_navigation.on_focus(icon.slug)
+            on:keydown=move |ev| keyboard_navigation.on_keydown(ev, icon)
+        >
+            <td>
+                <IconGridItemPreview icon title=icon_localized_title />
+            </td>
+            <td>
+                <button
+                    type="button"
+                    title=move || tr!("view-icon", { "icon" => icon_localized_title() })
+                    on:click=move |_| {
+                        open_icon_details_modal(i18n, icon, current_icon_view, modal_open)
+                    }
+                >
+                    {icon_localized_title}
+                </button>
+            </td>
+            <td>
+                <code>{icon.slug}</code>
+            </td>
+            <td>
+                <button
+                    type="button"
+                    title=move_tr!("copy-hex-color")
+                    class:dark=icon.hex_is_relatively_light
+                    style=format!("background: {css_hex}")
+                    on:click=move |ev: MouseEvent| {
+                        let target = event_target::<web_sys::HtmlElement>(&ev);
+                        let value = target.text_content().unwrap();
+                        copy_and_set_copied_transition(&value, target);
+                    }
+                >
+                    {css_hex.clone()}
+                </button>
+            </td>
+            <td>
+                {license_href(icon.license_url, icon.license_type)
+                    .map(|href| {
+                        view! {
+                            <a href=href target="_blank">
+                                {move || license_title(icon.license_type)}
+                            </a>
+                        }
+                    })}
+            </td>
+            <td>
+                {icon
+                    .guidelines
+                    .map(|guidelines_url| {
+                        view! {
+                            <a href=guidelines_url target="_blank">
+                                {move_tr!("brand-guidelines")}
+                            </a>
+                        }
+                    })}
+            </td>
+            <td>
+                <IconSelectionCheckbox icon icon_localized_title />
+                <IconFavoriteButton icon icon_localized_title />
+            </td>
+        </tr>
+    }
+}
+
+/// Table of icons displayed by the list layout
+///
+/// Sorting by a column sorts all the icons of the grid. The sort is
+/// discarded when the order mode or the search change.
+#[component]
+pub fn IconsTable(
+    /// Body of the table, where the rows are windowed
+    body_ref: NodeRef<Tbody>,
+) -> impl IntoView {
+    let icons_grid = expect_context::<IconsGridSignal>().0;
+    let virtual_grid = expect_context::<VirtualGridSignal>().0;
+    let order_mode = expect_context::<OrderModeSignal>().0;
+    let search = expect_context::<SearchValueSignal>().0;
+    let sort = RwSignal::new(None::<IconsTableSort>);
+
+    Effect::watch(
+        move || {
+            order_mode.track();
+            search.track();
+        },
+        move |_, _, _| sort.set(None),
+        false,
+    );
+
+    let mounted_icons = move || {
+        let loaded_icons = &icons_grid.read().loaded_icons;
+        loaded_icons
+            [virtual_grid.read().mounted_icons_range(loaded_icons.len())]
+        .to_vec()
+    };
+
+    view! {
+        <table class="icons-table" data-loaded-icons=move || icons_grid.read().loaded_icons.len()>
+            <thead>
+                <tr>
+                    <th>{move_tr!("preview")}</th>
+                    <IconsTableSortableHeader
+                        text=move_tr!("brand")
+                        column=IconsTableColumn::Title
+                        sort
+                    />
+                    <IconsTableSortableHeader
+                        text=move_tr!("slug")
+                        column=IconsTableColumn::Slug
+                        sort
+                    />
+                    <IconsTableSortableHeader
+                        text=move_tr!("color")
+                        column=IconsTableColumn::Hex
+                        sort
+                    />
+                    <IconsTableSortableHeader
+                        text=move_tr!("license")
+                        column=IconsTableColumn::License
+                        sort
+                    />
+                    <IconsTableSortableHeader
+                        text=move_tr!("brand-guidelines")
+                        column=IconsTableColumn::Guidelines
+                        sort
+                    />
+                    <th></th>
+                </tr>
+            </thead>
+            <tbody node_ref=body_ref>
+                <Show when=move || { virtual_grid.read().top_spacer_height > 0.0 }>
+                    <tr class="grid-spacer grid-spacer-top" aria-hidden="true">
+                        <td
+                            colspan=ICONS_TABLE_COLUMNS
+                            style:height=move || {
+                                format!("{}px", virtual_grid.read().top_spacer_height)
+                            }
+                        ></td>
+                    </tr>
+                </Show>
+                <For
+                    each=mounted_icons
+                    key=move |icon| icon.slug
+                    children=move |icon: &'static SimpleIcon| {
+                        view! { <IconsTableRow icon /> }
+                    }
+                />
+                <Show when=move || { virtual_grid.read().bottom_spacer_height > 0.0 }>
+                    <tr class="grid-spacer" aria-hidden="true">
+                        <td
+                            colspan=ICONS_TABLE_COLUMNS
+                            style:height=move || {
+                                format!("{}px", virtual_grid.read().bottom_spacer_height)
+                            }
+                        ></td>
+                    </tr>
+                </Show>
+            </tbody>
+        </table>
+    }
+}
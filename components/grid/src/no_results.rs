@@ -0,0 +1,145 @@
+use leptos::prelude::*;
+use leptos_fluent::move_tr;
+use simple_icons_website_controls::{
+    filters::FiltersSignal,
+    search::{SearchValueSignal, fire_on_search_event},
+};
+use simple_icons_website_controls_search::get_search_input;
+use simple_icons_website_footer::icon_request_issue_url;
+use simple_icons_website_grid_types::{IconsGridSignal, IconsIndexSignal};
+use simple_icons_website_types::SimpleIcon;
+
+/// Maximum number of suggestions displayed
+const MAX_SUGGESTIONS: usize = 5;
+
+/// Levenshtein edit distance between two strings
+fn edit_distance(a: &[char], b: &[char]) -> usize {
+    let mut previous_row: Vec<usize> = (0..=b.len()).collect();
+    let mut current_row = vec![0; b.len() + 1];
+
+    for (i, a_char) in a.iter().enumerate() {
+        current_row[0] = i + 1;
+        for (j, b_char) in b.iter().enumerate() {
+            let substitution_cost = usize::from(a_char != b_char);
+            current_row[j + 1] = (previous_row[j] + substitution_cost)
+                .min(previous_row[j + 1] + 1)
+                .min(current_row[j] + 1);
+        }
+        std::mem::swap(&mut previous_row, &mut current_row);
+    }
+
+    previous_row[b.len()]
+}
+
+/// Get the icons whose title, slug or aliases are the closest to the
+/// search value by edit distance
+fn did_you_mean<'a>(
+    search_value: &str,
+    icons: impl Iterator<Item = &'a &'static SimpleIcon>,
+) -> Vec<&'static SimpleIcon> {
+    let search_value = search_value.trim().to_lowercase();
+    let search_chars = search_value.chars().collect::<Vec<char>>();
+    // Allow one edit for every three characters, at least one
+    let max_distance = (search_chars.len() / 3).max(1);
+
+    let mut suggestions = icons
+        .filter_map(|icon| {
+            let mut candidates = vec![icon.title, icon.slug];
+            candidates.extend(icon.plain_aliases());
+            candidates
+                .iter()
+                .map(|candidate| {
+                    let candidate = candidate.to_lowercase();
+                    edit_distance(
+                        &search_chars,
+                        &candidate.chars().collect::<Vec<char>>(),
+                    )
+                })
+                .min()
+                .filter(|distance| *distance <= max_distance)
+                .map(|distance| (distance, *icon))
+        })
+        .collect::<Vec<(usize, &'static SimpleIcon)>>();
+    suggestions.sort_by(|(distance_a, icon_a), (distance_b, icon_b)| {
+        distance_a
+            .cmp(distance_b)
+            .then(icon_a.order_alpha.cmp(&icon_b.order_alpha))
+    });
+
+    suggestions
+        .into_iter()
+        .take(MAX_SUGGESTIONS)
+        .map(|(_, icon)| icon)
+        .collect()
+}
+
+fn suggestions_changed(
+    previous: Option<&Vec<&'static SimpleIcon>>,
+    current: Option<&Vec<&'static SimpleIcon>>,
+) -> bool {
+    let slugs = |icons: Option<&Vec<&'static SimpleIcon>>| {
+        icons.map(|icons| {
+            icons.iter().map(|icon| icon.slug).collect::<Vec<&str>>()
+        })
+    };
+    slugs(previous) != slugs(current)
+}
+
+/// Empty state of the icons grid
+///
+/// Displayed when a search does not match any icon. Suggests the closest
+/// icons to the search value that pass the filters enabled and links to
+/// the new icon request form.
+#[component]
+pub fn NoResults() -> impl IntoView {
+    let icons_grid = expect_context::<IconsGridSignal>().0;
+    let search = expect_context::<SearchValueSignal>().0;
+    let icons = expect_context::<IconsIndexSignal>().0;
+    let filters = expect_context::<FiltersSignal>().0;
+
+    let show_no_results =
+        move || icons_grid().icons.is_empty() && !search().is_empty();
+    let suggestions = Memo::new_with_compare(
+        move |_| match show_no_results() {
+            true => filters.with(|filters| {
+                did_you_mean(
+                    &search(),
+                    icons.iter().filter(|icon| filters.matches(icon)),
+                )
+            }),
+            false => vec![],
+        },
+        suggestions_changed,
+    );
+    let icon_request_url = move || icon_request_issue_url(&search());
+
+    view! {
+        <Show when=show_no_results>
+            <div class="no-results">
+                <p>{move_tr!("no-icons-found", { "search" => search() })}</p>
+                <Show when=move || !suggestions().is_empty()>
+                    <p>{move_tr!("did-you-mean")}</p>
+                    <ul>
+                        <For
+                            each=suggestions
+                            key=move |icon| icon.slug
+                            children=move |icon: &'static SimpleIcon| {
+                                view! {
+                                    <li>
+                                        <button on:click=move |_| {
+                                            get_search_input().set_value(icon.title);
+                                            fire_on_search_event();
+                                        }>{icon.title}</button>
+                                    </li>
+                                }
+                            }
+                        />
+                    </ul>
+                </Show>
+                <a href=icon_request_url>
+                    {move_tr!("submit-a-request-for", { "brand" => search() })}
+                </a>
+            </div>
+        </Show>
+    }
+}
@@ -0,0 +1,243 @@
+use crate::{collection::IconsCollection, favorites::FavoritesLink};
+use leptos::{ev::MouseEvent, prelude::*, task::spawn_local};
+use leptos_fluent::move_tr;
+use simple_icons_website_controls::download::{
+    DownloadTypeSignal, ExportOptionsSignal, download_icons_sprite,
+    download_icons_zip,
+};
+use simple_icons_website_copy::copy_and_set_copied_transition;
+use simple_icons_website_grid_constants::ICONS;
+use simple_icons_website_grid_types::{IconsGridSignal, IconsIndexSignal};
+use simple_icons_website_types::SimpleIcon;
+use std::collections::HashSet;
+
+/// Icons selected in the grid to be downloaded at once
+#[derive(Clone, Default)]
+pub struct IconsSelection {
+    /// Whether the selection mode is enabled
+    pub enabled: bool,
+    /// Slugs of the selected icons
+    pub slugs: HashSet<&'static str>,
+    /// Slug of the last icon toggled, used to select ranges of icons
+    anchor: Option<&'static str>,
+}
+
+impl IconsSelection {
+    pub fn is_selected(&self, slug: &str) -> bool {
+        self.slugs.contains(slug)
+    }
+
+    /// Toggle the selection of an icon
+    ///
+    /// If `range` is `true`, all the icons between the last icon toggled
+    /// and this one get the same selection state.
+    pub fn toggle(
+        &mut self,
+        icon: &'static SimpleIcon,
+        range: bool,
+        loaded_icons: &[&'static SimpleIcon],
+    ) {
+        let select = !self.is_selected(icon.slug);
+        let position =
+            |slug: &str| loaded_icons.iter().position(|i| i.slug == slug);
+
+        let icons_to_toggle = match (range, self.anchor.and_then(position)) {
+            (true, Some(anchor_index)) => match position(icon.slug) {
+                Some(index) => {
+                    let (start, end) = match anchor_index < index {
+                        true => (anchor_index, index),
+                        false => (index, anchor_index),
+                    };
+                    loaded_icons[start..=end].to_vec()
+                }
+                None => vec![icon],
+            },
+            _ => vec![icon],
+        };
+        for icon_to_toggle in icons_to_toggle {
+            match select {
+                true => self.slugs.insert(icon_to_toggle.slug),
+                false => self.slugs.remove(icon_to_toggle.slug),
+            };
+        }
+        self.anchor = Some(icon.slug);
+    }
+
+    pub fn select_all(&mut self, icons: &[&'static SimpleIcon]) {
+        self.slugs.extend(icons.iter().map(|icon| icon.slug));
+    }
+
+    pub fn clear(&mut self) {
+        self.slugs.clear();
+        self.anchor = None;
+    }
+}
+
+#[derive(Copy, Clone)]
+pub struct IconsSelectionSignal(pub RwSignal<IconsSelection>);
+
+/// Checkbox to select an icon in the grid
+///
+/// Only displayed when the selection mode is enabled.
+#[component]
+pub fn IconSelectionCheckbox(
+    /// The icon
+    icon: &'static SimpleIcon,
+    /// Localized brand name
+    icon_localized_title: Memo<&'static str>,
+) -> impl IntoView {
+    let selection = expect_context::<IconsSelectionSignal>().0;
+    let icons_grid = expect_context::<IconsGridSignal>().0;
+
+    view! {
+        <Show when=move || selection.read().enabled>
+            <input
+                type="checkbox"
+                class="selection-checkbox"
+                title=move_tr!("select-icon", { "icon" => icon_localized_title() })
+                prop:checked=move || selection.read().is_selected(icon.slug)
+                on:click=move |ev: MouseEvent| {
+                    let range = ev.shift_key();
+                    selection
+                        .update(|selection| {
+                            selection.toggle(icon, range, &icons_grid.read_untracked().loaded_icons)
+                        });
+                }
+            />
+        </Show>
+    }
+}
+
+/// Bar to manage the selection of icons
+///
+/// Allows to enable the selection mode, select all the icons of the
+/// current results and download the selected ones as a ZIP archive.
+/// The selected icons, or the results of the grid if there is no
+/// selection, can also be downloaded as a SVG sprite. The results are
+/// only downloaded when they don't include all the icons, like when a
+/// search or a filter narrows them, so every icon is not fetched by
+/// mistake.
+#[component]
+pub fn IconsSelectionBar() -> impl IntoView {
+    let selection = expect_context::<IconsSelectionSignal>().0;
+    let icons_grid = expect_context::<IconsGridSignal>().0;
+    let download_type = expect_context::<DownloadTypeSignal>().0;
+    let export_options = expect_context::<ExportOptionsSignal>().0;
+    let icons = StoredValue::new(expect_context::<IconsIndexSignal>().0);
+    let (downloading, set_downloading) = signal(false);
+    let (sprite_brand_colors, set_sprite_brand_colors) = signal(false);
+
+    let selected_icons_length = move || selection.read().slugs.len();
+    let results_narrowed = move || icons_grid.read().icons.len() < ICONS.len();
+    let can_download_sprite =
+        move || selected_icons_length() > 0 || results_narrowed();
+
+    let selected_icons = move || {
+        icons
+            .read_value()
+            .iter()
+            .filter(|icon| selection.read_untracked().is_selected(icon.slug))
+            .copied()
+            .collect::<Vec<&'static SimpleIcon>>()
+    };
+
+    let download_selection = move |_| {
+        let selected_icons = selected_icons();
+        set_downloading(true);
+        spawn_local(async move {
+            if let Err(error) = download_icons_zip(
+                selected_icons,
+                download_type.get_untracked(),
+                export_options.get_untracked(),
+            )
+            .await
+            {
+                web_sys::console::error_1(&error.into());
+            }
+            set_downloading(false);
+        });
+    };
+
+    let download_sprite = move |_| {
+        let sprite_icons = match selection.read_untracked().slugs.is_empty() {
+            true => icons_grid.read_untracked().icons.clone(),
+            false => selected_icons(),
+        };
+        set_downloading(true);
+        spawn_local(async move {
+            if let Err(error) = download_icons_sprite(
+                sprite_icons,
+                sprite_brand_colors.get_untracked(),
+            )
+            .await
+            {
+                web_sys::console::error_1(&error.into());
+            }
+            set_downloading(false);
+        });
+    };
+
+    view! {
+        <div class="selection-bar">
+            <Show
+                when=move || selection.read().enabled
+                fallback=move || {
+                    view! {
+                        <button on:click=move |_| {
+                            selection.update(|selection| selection.enabled = true)
+                        }>{move_tr!("select-icons")}</button>
+                    }
+                }
+            >
+                <span>{move_tr!("n-selected-icons", { "n" => selected_icons_length() })}</span>
+                <button on:click=move |_| {
+                    selection
+                        .update(|selection| {
+                            selection.select_all(&icons_grid.read_untracked().icons)
+                        })
+                }>{move_tr!("select-all-results")}</button>
+                <button on:click=move |_| {
+                    selection.update(|selection| selection.clear())
+                }>{move_tr!("clear-selection")}</button>
+                <button
+                    disabled=move || downloading() || selected_icons_length() == 0
+                    on:click=download_selection
+                >
+                    {move_tr!("download-zip")}
+                </button>
+                <button
+                    class="copy-collection-link"
+                    disabled=move || selected_icons_length() == 0
+                    on:click=move |ev: MouseEvent| {
+                        let url = IconsCollection::url(&selected_icons());
+                        copy_and_set_copied_transition(&url, event_target(&ev));
+                    }
+                >
+                    {move_tr!("copy-collection-link")}
+                </button>
+                <button on:click=move |_| {
+                    selection
+                        .update(|selection| {
+                            selection.clear();
+                            selection.enabled = false;
+                        })
+                }>{move_tr!("close")}</button>
+            </Show>
+            <label class="sprite-brand-colors">
+                <input
+                    type="checkbox"
+                    prop:checked=sprite_brand_colors
+                    on:change=move |ev| set_sprite_brand_colors(event_target_checked(&ev))
+                />
+                {move_tr!("brand-color-fill")}
+            </label>
+            <button
+                disabled=move || downloading() || !can_download_sprite()
+                on:click=download_sprite
+            >
+                {move_tr!("download-svg-sprite")}
+            </button>
+            <FavoritesLink />
+        </div>
+    }
+}
@@ -0,0 +1,476 @@
+//! Windowed rendering of the icons grid
+//!
+//! Only the rows of icons visible in the viewport, plus a buffer of rows
+//! above and below them, are mounted in the DOM. The space of the rows not
+//! mounted is replaced by spacers, so the height of the grid is the same as
+//! if all the loaded icons were rendered and the pagination triggered by
+//! the footer keeps working as before.
+//!
+//! In the list layout the rows of the table of icons are windowed the same
+//! way, as a grid of a single column.
+//!
+//! The measured columns are also used to compute the size of the pages of
+//! icons, see [`GridDimensions`].
+
+use core::ops::Range;
+use leptos::{
+    html::{Tbody, Ul},
+    prelude::*,
+};
+use leptos_use::{UseWindowSizeReturn, use_window_scroll, use_window_size};
+use simple_icons_website_controls_layout_signal::LayoutSignal;
+use simple_icons_website_controls_layout_type::{GridDimensions, Layout};
+use simple_icons_website_grid_types::{IconsGrid, IconsGridSignal};
+use simple_icons_website_types::SimpleIcon;
+use wasm_bindgen::JsCast;
+use web_sys::{Element, HtmlElement};
+
+/// Number of rows mounted above and below the ones visible in the viewport
+const BUFFER_ROWS: usize = 3;
+
+/// Maximum number of consecutive measures after a change in the grid
+///
+/// Mounting new rows can change the estimated heights of the spacers, so
+/// the grid is measured again until it is stable.
+const MAX_MEASURE_PASSES: u8 = 3;
+
+/// Window of the icons grid currently mounted
+///
+/// The icons placed in the rows shared with the Carbon Ads ad are pinned,
+/// so they're always mounted. The rest of the loaded icons are placed in
+/// virtual rows of `columns` icons and only the virtual rows in `rows`
+/// are mounted.
+#[derive(Clone, PartialEq)]
+pub struct VirtualGrid {
+    /// Number of columns of the grid
+    pub columns: usize,
+    /// Number of icons per row in the rows shared with the ad
+    pub pinned_columns: usize,
+    /// Number of icons always mounted at the start of the grid
+    pub pinned_icons: usize,
+    /// Virtual rows currently mounted
+    pub rows: Range<usize>,
+    /// Height of the spacer that replaces the rows above the mounted ones
+    pub top_spacer_height: f64,
+    /// Height of the spacer that replaces the rows below the mounted ones
+    pub bottom_spacer_height: f64,
+}
+
+impl Default for VirtualGrid {
+    /// Mount all the loaded icons until the grid is measured
+    fn default() -> Self {
+        Self {
+            columns: 1,
+            pinned_columns: 1,
+            pinned_icons: 0,
+            rows: 0..usize::MAX,
+            top_spacer_height: 0.0,
+            bottom_spacer_height: 0.0,
+        }
+    }
+}
+
+impl VirtualGrid {
+    /// Range of the loaded icons mounted in the virtual rows
+    pub fn mounted_icons_range(
+        &self,
+        loaded_icons_length: usize,
+    ) -> Range<usize> {
+        let pinned_icons = self.pinned_icons.min(loaded_icons_length);
+        let start = self
+            .rows
+            .start
+            .saturating_mul(self.columns)
+            .saturating_add(pinned_icons)
+            .min(loaded_icons_length);
+        let end = self
+            .rows
+            .end
+            .saturating_mul(self.columns)
+            .saturating_add(pinned_icons)
+            .min(loaded_icons_length);
+        start..end
+    }
+
+    /// Whether a loaded icon is currently mounted
+    pub fn is_mounted(&self, index: usize, loaded_icons_length: usize) -> bool {
+        index < self.pinned_icons.min(loaded_icons_length)
+            || self
+                .mounted_icons_range(loaded_icons_length)
+                .contains(&index)
+    }
+
+    /// Number of rows occupied by the pinned icons
+    fn pinned_rows(&self) -> usize {
+        self.pinned_icons.div_ceil(self.pinned_columns.max(1))
+    }
+
+    /// Row and column where a loaded icon is placed
+    pub fn position_of(&self, index: usize) -> (usize, usize) {
+        match index < self.pinned_icons {
+            true => (index / self.pinned_columns, index % self.pinned_columns),
+            false => {
+                let index = index - self.pinned_icons;
+                (
+                    self.pinned_rows() + index / self.columns,
+                    index % self.columns,
+                )
+            }
+        }
+    }
+
+    /// Index of the loaded icon placed at a row and column
+    ///
+    /// If the row has less icons than the column, the last icon of the row
+    /// is returned.
+    pub fn index_at(
+        &self,
+        row: usize,
+        column: usize,
+        loaded_icons_length: usize,
+    ) -> Option<usize> {
+        let pinned_rows = self.pinned_rows();
+        let (row_start, row_end) = match row < pinned_rows {
+            true => {
+                let row_start = row * self.pinned_columns;
+                (
+                    row_start,
+                    (row_start + self.pinned_columns).min(self.pinned_icons),
+                )
+            }
+            false => {
+                let row_start =
+                    self.pinned_icons + (row - pinned_rows) * self.columns;
+                (row_start, row_start + self.columns)
+            }
+        };
+        let row_end = row_end.min(loaded_icons_length);
+        (row_start < row_end).then(|| (row_start + column).min(row_end - 1))
+    }
+}
+
+/// Signal to control the window of the icons grid currently mounted
+#[derive(Copy, Clone)]
+pub struct VirtualGridSignal(pub RwSignal<VirtualGrid>);
+
+/// Measures of the grid kept between updates of the window
+#[derive(Default)]
+struct GridMeasures {
+    /// Heights of the virtual rows that have been mounted at some point
+    row_heights: Vec<Option<f64>>,
+    /// Loaded icons when the grid was measured the last time
+    loaded_icons: Vec<&'static SimpleIcon>,
+    /// Layout of the grid when it was measured the last time
+    layout: Layout,
+}
+
+fn px_to_f64(value: &str) -> f64 {
+    value.trim_end_matches("px").parse().unwrap_or(0.0)
+}
+
+fn element_top(element: &Element) -> f64 {
+    element.get_bounding_client_rect().top()
+}
+
+/// Compute the window of the grid that must be mounted
+///
+/// `grid` is the list of the grid items, or the body of the table of icons
+/// in the list layout.
+fn measure_virtual_grid(
+    grid: &HtmlElement,
+    layout: &Layout,
+    current: &VirtualGrid,
+    measures: &mut GridMeasures,
+    loaded_icons: &[&'static SimpleIcon],
+) -> Option<VirtualGrid> {
+    let loaded_icons_length = loaded_icons.len();
+    let (columns, gap, items_selector) = match layout {
+        // The rows of the table are not separated
+        Layout::List => (1, 0.0, ":scope > tr:not(.grid-spacer)"),
+        _ => {
+            let style = window().get_computed_style(grid).ok()??;
+            let columns = style
+                .get_property_value("grid-template-columns")
+                .ok()?
+                .split_whitespace()
+                .count()
+                .max(1);
+            let gap = px_to_f64(&style.get_property_value("row-gap").ok()?);
+            (columns, gap, ":scope > li:not(.grid-spacer)")
+        }
+    };
+
+    let items = grid.query_selector_all(items_selector).ok()?;
+    let first_item = items.get(0)?.unchecked_into::<Element>();
+    let first_item_rect = first_item.get_bounding_client_rect();
+
+    // The ad spans two rows, so the icons in these rows are pinned
+    let pinned_columns =
+        match grid.query_selector(":scope > #carbonads").ok().flatten() {
+            Some(ad) => {
+                let ad_columns = ((ad.get_bounding_client_rect().width() + gap)
+                    / (first_item_rect.width() + gap))
+                    .round() as usize;
+                columns.saturating_sub(ad_columns)
+            }
+            None => columns,
+        };
+    let pinned_icons = match pinned_columns == columns {
+        true => 0,
+        false => (pinned_columns * 2).min(loaded_icons_length),
+    };
+
+    let geometry_changed = columns != current.columns
+        || pinned_icons != current.pinned_icons
+        || *layout != measures.layout;
+    let loaded_icons_replaced = measures.loaded_icons.len()
+        > loaded_icons.len()
+        || measures
+            .loaded_icons
+            .iter()
+            .zip(loaded_icons)
+            .any(|(previous, current)| !core::ptr::eq(*previous, *current));
+    if geometry_changed || loaded_icons_replaced {
+        measures.row_heights.clear();
+    }
+
+    let total_rows = loaded_icons_length
+        .saturating_sub(pinned_icons)
+        .div_ceil(columns);
+    measures.row_heights.resize(total_rows, None);
+
+    // Measure the heights of the virtual rows currently mounted
+    let mounted_items_length = items.length() as usize;
+    if !geometry_changed {
+        for (i, index) in (current.pinned_icons..mounted_items_length)
+            .step_by(columns)
+            .enumerate()
+        {
+            let row = current.rows.start + i;
+            if row >= total_rows {
+                break;
+            }
+            if let Some(item) = items.get(index as u32) {
+                let height = item
+                    .unchecked_into::<Element>()
+                    .get_bounding_client_rect()
+                    .height();
+                measures.row_heights[row] = Some(height);
+            }
+        }
+    }
+
+    let measured_heights = measures
+        .row_heights
+        .iter()
+        .flatten()
+        .copied()
+        .collect::<Vec<f64>>();
+    let estimated_row_height = match measured_heights.is_empty() {
+        true => first_item_rect.height(),
+        false => {
+            measured_heights.iter().sum::<f64>() / measured_heights.len() as f64
+        }
+    };
+    let row_height =
+        |row: usize| measures.row_heights[row].unwrap_or(estimated_row_height);
+
+    // Top of the first virtual row in document coordinates
+    let scroll_y = window().scroll_y().unwrap_or(0.0);
+    let virtual_rows_top = match current.rows.start > 0 {
+        true => grid
+            .query_selector(":scope > .grid-spacer-top")
+            .ok()
+            .flatten()
+            .map(|spacer| element_top(&spacer)),
+        false => items
+            .get(current.pinned_icons.min(mounted_items_length) as u32)
+            .map(|item| element_top(&item.unchecked_into::<Element>())),
+    }
+    .unwrap_or(first_item_rect.top())
+        + scroll_y;
+
+    let viewport_top = scroll_y;
+    let viewport_bottom =
+        scroll_y + window().inner_height().ok()?.as_f64().unwrap_or(0.0);
+    let mut first_visible_row = None;
+    let mut last_visible_row = 0;
+    let mut row_top = virtual_rows_top;
+    for row in 0..total_rows {
+        let row_bottom = row_top + row_height(row);
+        if first_visible_row.is_none() && row_bottom >= viewport_top {
+            first_visible_row = Some(row);
+        }
+        if row_top <= viewport_bottom {
+            last_visible_row = row;
+        }
+        row_top = row_bottom + gap;
+    }
+    let first_visible_row =
+        first_visible_row.unwrap_or(total_rows.saturating_sub(1));
+    let last_visible_row = last_visible_row.max(first_visible_row);
+
+    let start = first_visible_row.saturating_sub(BUFFER_ROWS);
+    let end = (last_visible_row + 1 + BUFFER_ROWS)
+        .min(total_rows)
+        .max(start);
+
+    let spacer_height = |rows: Range<usize>| match rows.is_empty() {
+        true => 0.0,
+        false => {
+            let length = rows.len();
+            rows.map(row_height).sum::<f64>() + (length - 1) as f64 * gap
+        }
+    };
+
+    Some(VirtualGrid {
+        columns,
+        pinned_columns,
+        pinned_icons,
+        top_spacer_height: spacer_height(0..start),
+        bottom_spacer_height: spacer_height(end..total_rows),
+        rows: start..end,
+    })
+}
+
+/// Elements that contain the icons in each layout
+#[derive(Copy, Clone)]
+struct GridRefs {
+    /// List of the grid items
+    grid: NodeRef<Ul>,
+    /// Body of the table of icons of the list layout
+    table_body: NodeRef<Tbody>,
+}
+
+impl GridRefs {
+    fn container(&self, layout: &Layout) -> Option<HtmlElement> {
+        match layout {
+            Layout::List => self
+                .table_body
+                .get_untracked()
+                .map(|table_body| table_body.unchecked_into()),
+            _ => self.grid.get_untracked().map(|grid| grid.unchecked_into()),
+        }
+    }
+}
+
+fn update_virtual_grid(
+    refs: GridRefs,
+    layout: Layout,
+    virtual_grid: RwSignal<VirtualGrid>,
+    icons_grid: RwSignal<IconsGrid>,
+    measures: StoredValue<GridMeasures>,
+    pass: u8,
+) {
+    let Some(grid) = refs.container(&layout) else {
+        return;
+    };
+
+    let new_virtual_grid = measures.try_update_value(|measures| {
+        let loaded_icons = &icons_grid.read_untracked().loaded_icons;
+        let new_virtual_grid = measure_virtual_grid(
+            &grid,
+            &layout,
+            &virtual_grid.read_untracked(),
+            measures,
+            loaded_icons,
+        );
+        measures.loaded_icons.clone_from(loaded_icons);
+        measures.layout = layout;
+        new_virtual_grid
+    });
+
+    if let Some(Some(new_virtual_grid)) = new_virtual_grid
+        && new_virtual_grid != virtual_grid.get_untracked()
+    {
+        virtual_grid.set(new_virtual_grid);
+        if pass < MAX_MEASURE_PASSES {
+            request_animation_frame(move || {
+                update_virtual_grid(
+                    refs,
+                    layout,
+                    virtual_grid,
+                    icons_grid,
+                    measures,
+                    pass + 1,
+                )
+            });
+        }
+    }
+}
+
+/// Provide the window of the grid and keep it updated when the user
+/// scrolls, resizes the window, changes the layout or loads icons
+pub fn provide_virtual_grid_context(
+    grid_ref: NodeRef<Ul>,
+    table_body_ref: NodeRef<Tbody>,
+) {
+    let virtual_grid = RwSignal::new(VirtualGrid::default());
+    provide_context(VirtualGridSignal(virtual_grid));
+
+    let icons_grid = expect_context::<IconsGridSignal>().0;
+    let layout = expect_context::<LayoutSignal>().0;
+    let measures = StoredValue::new(GridMeasures::default());
+    let (_, scroll_y) = use_window_scroll();
+    let UseWindowSizeReturn { width, height } = use_window_size();
+    let refs = GridRefs {
+        grid: grid_ref,
+        table_body: table_body_ref,
+    };
+
+    // The window of a layout doesn't apply to the others
+    Effect::watch(
+        move || layout.track(),
+        move |_, _, _| virtual_grid.set(VirtualGrid::default()),
+        false,
+    );
+
+    Effect::new(move |_| {
+        scroll_y.track();
+        width.track();
+        height.track();
+        let layout = layout();
+        icons_grid.track();
+
+        // Measure after the browser has laid out the grid
+        request_animation_frame(move || {
+            update_virtual_grid(
+                refs,
+                layout,
+                virtual_grid,
+                icons_grid,
+                measures,
+                1,
+            )
+        });
+    });
+
+    // Keep the dimensions used to compute the size of the pages updated
+    Effect::new(move |_| {
+        let layout = layout();
+        let dimensions = match layout {
+            Layout::List => GridDimensions {
+                columns: 1,
+                ad_cells: 0,
+                viewport_height: height(),
+            },
+            _ => {
+                let virtual_grid = virtual_grid.read();
+                // Keep the estimated dimensions until the grid is measured
+                if *virtual_grid == VirtualGrid::default() {
+                    return;
+                }
+                GridDimensions {
+                    columns: virtual_grid.columns,
+                    ad_cells: (virtual_grid.columns
+                        - virtual_grid.pinned_columns)
+                        * 2,
+                    viewport_height: height(),
+                }
+            }
+        };
+        if icons_grid.read_untracked().dimensions != dimensions {
+            icons_grid.update(|grid| grid.set_dimensions(dimensions, &layout));
+        }
+    });
+}
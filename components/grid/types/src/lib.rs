@@ -1,5 +1,5 @@
 use leptos::prelude::RwSignal;
-use simple_icons_website_controls_layout_type::Layout;
+use simple_icons_website_controls_layout_type::{GridDimensions, Layout};
 use simple_icons_website_controls_order_types::{OrderModeVariant, sort_icons};
 use simple_icons_website_controls_search::search_icons_and_returns_first_page;
 use simple_icons_website_types::SimpleIcon;
@@ -15,6 +15,11 @@ pub struct IconsGrid {
     pub loaded_icons: Vec<&'static SimpleIcon>,
     /// Icons of the grid
     pub icons: Vec<&'static SimpleIcon>,
+    /// Dimensions of the grid used to compute the size of the pages
+    pub dimensions: GridDimensions,
+    /// Whether only the first page of icons has been loaded, so it can be
+    /// resized when the dimensions of the grid are measured or change
+    first_page_only: bool,
 }
 
 impl IconsGrid {
@@ -23,32 +28,72 @@ impl IconsGrid {
         order_mode: &OrderModeVariant,
         layout: &Layout,
         icons: Vec<&'static SimpleIcon>,
+        dimensions: GridDimensions,
     ) -> Self {
         let (icons, loaded_icons) =
-            initial_icons_from_search_value_order_mode_and_layout(
+            initial_icons_from_search_value_order_mode_and_page_size(
                 search_value,
                 order_mode,
-                layout,
+                layout.page_end(0, &dimensions),
                 icons,
             );
         Self {
             icons,
             loaded_icons,
+            dimensions,
+            first_page_only: true,
         }
     }
 
+    /// Number of icons loaded after loading a page of icons when
+    /// `loaded_icons_length` icons are loaded
+    pub fn page_end(
+        &self,
+        loaded_icons_length: usize,
+        layout: &Layout,
+    ) -> usize {
+        layout.page_end(loaded_icons_length, &self.dimensions)
+    }
+
     pub fn load_next_icons(&mut self, layout: &Layout) {
-        let icons_per_page: usize = layout.icons_per_page() as usize;
-        let icons_length = self.icons.len();
-        for i in self.loaded_icons.len()..icons_length {
-            let loaded_icons_length = self.loaded_icons.len();
-            if loaded_icons_length == icons_length {
-                break;
-            }
-            self.loaded_icons.push(self.icons[i]);
-            if (loaded_icons_length + 1).is_multiple_of(icons_per_page) {
-                break;
-            }
+        self.first_page_only = false;
+        let loaded_icons_length = self.loaded_icons.len();
+        let length = self
+            .page_end(loaded_icons_length, layout)
+            .min(self.icons.len());
+        if length > loaded_icons_length {
+            self.loaded_icons
+                .extend_from_slice(&self.icons[loaded_icons_length..length]);
+        }
+    }
+
+    /// Load pages of icons until at least `length` icons are loaded
+    pub fn load_icons_until(&mut self, length: usize, layout: &Layout) {
+        self.load_next_icons(layout);
+        while self.loaded_icons.len() < length.min(self.icons.len()) {
+            self.load_next_icons(layout);
+        }
+    }
+
+    /// Update the dimensions of the grid
+    ///
+    /// While only the first page of icons is loaded, it's resized to the
+    /// new dimensions, so the estimated first page is replaced by the one
+    /// of the grid measured. Otherwise, if the first page of icons is now
+    /// bigger than the icons loaded, the icons needed to fill it are loaded.
+    pub fn set_dimensions(
+        &mut self,
+        dimensions: GridDimensions,
+        layout: &Layout,
+    ) {
+        self.dimensions = dimensions;
+        let length = self.page_end(0, layout).min(self.icons.len());
+        let loaded_icons_length = self.loaded_icons.len();
+        if self.first_page_only && length < loaded_icons_length {
+            self.loaded_icons.truncate(length);
+        } else if length > loaded_icons_length {
+            self.loaded_icons
+                .extend_from_slice(&self.icons[loaded_icons_length..length]);
         }
     }
 }
@@ -57,13 +102,12 @@ impl IconsGrid {
 #[derive(Copy, Clone)]
 pub struct IconsGridSignal(pub RwSignal<IconsGrid>);
 
-fn initial_icons_from_search_value_order_mode_and_layout(
+fn initial_icons_from_search_value_order_mode_and_page_size(
     search_value: &str,
     order_mode: &OrderModeVariant,
-    layout: &Layout,
+    icons_per_page: usize,
     icons: Vec<&'static SimpleIcon>,
 ) -> (Vec<&'static SimpleIcon>, Vec<&'static SimpleIcon>) {
-    let icons_per_page: usize = layout.icons_per_page() as usize;
     if search_value.is_empty() {
         if order_mode != &OrderModeVariant::Alphabetic {
             let mut icons_copy = icons.clone();
@@ -8,6 +8,8 @@ use leptos_unique_ids::leptos_unique_ids;
     "preview-save-button",
     "preview-download-svg-button",
     "preview-upload-svg-button",
+    "preview-undo-button",
+    "preview-redo-button",
     "view-svg-path",
     "download-svg-path",
     "download-thin-svg-path",
@@ -16,9 +18,17 @@ use leptos_unique_ids::leptos_unique_ids;
     "upload-svg-path",
     "save-svg-path",
     "copy-svg-path",
+    "undo-svg-path",
+    "redo-svg-path",
     "preview-color",
     "preview-path",
     "preview-brand",
+    "preview-template",
+    "preview-comparison",
+    "preview-badge-label",
+    "preview-badge-message",
+    "preview-badge-style",
+    "preview-copy-shields-url-button",
     "preview-card-image-for-canvas"
 )]
 pub enum Ids {}
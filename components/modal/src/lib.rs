@@ -134,6 +134,7 @@ impl ModalOpenSignal {
     pub fn set_none(&self) {
         self.0.update(|mo| *mo = None);
         Url::params::update(&Url::params::Names::Modal, "");
+        Url::params::update(&Url::params::Names::Icon, "");
     }
 
     pub fn set_extensions(&self) {
@@ -152,12 +153,14 @@ impl ModalOpenSignal {
         );
     }
 
-    pub fn set_icon(&self) {
+    /// Open the details modal of an icon
+    ///
+    /// The modal is stored in the URL by the slug of the icon, so it can
+    /// be shared.
+    pub fn set_icon(&self, slug: &str) {
         self.0.update(|mo| *mo = Some(ModalOpen::Icon));
-        Url::params::update(
-            &Url::params::Names::Modal,
-            &ModalOpen::Icon.to_string(),
-        );
+        Url::params::update(&Url::params::Names::Modal, "");
+        Url::params::update(&Url::params::Names::Icon, slug);
     }
 
     pub fn is_open(&self, modal_open: ModalOpen) -> bool {
@@ -0,0 +1,357 @@
+use crate::{
+    canvas::update_preview_canvas, helpers::is_valid_hex_color,
+    templates::BadgesPlacement,
+};
+use badge_maker::make_badge;
+use leptos::prelude::*;
+use leptos_fluent::{move_tr, tr};
+use leptos_use::{UseClipboardReturn, use_clipboard, use_device_pixel_ratio};
+use simple_icons_website_ids::Ids;
+use simple_icons_website_svg_defs::SVGDef;
+use simple_icons_website_svg_icon::svg_with_path_opt_fill;
+use std::str::FromStr;
+
+/// Styles of the badges of shields.io
+#[derive(Default, Copy, Clone, PartialEq)]
+pub(crate) enum BadgeStyle {
+    #[default]
+    Flat,
+    FlatSquare,
+    Plastic,
+    ForTheBadge,
+    Social,
+}
+
+impl BadgeStyle {
+    /// All the styles, in the order they are displayed
+    pub(crate) const ALL: [Self; 5] = [
+        Self::Flat,
+        Self::FlatSquare,
+        Self::Plastic,
+        Self::ForTheBadge,
+        Self::Social,
+    ];
+
+    pub(crate) fn as_str(&self) -> &'static str {
+        match self {
+            Self::Flat => "flat",
+            Self::FlatSquare => "flat-square",
+            Self::Plastic => "plastic",
+            Self::ForTheBadge => "for-the-badge",
+            Self::Social => "social",
+        }
+    }
+}
+
+impl FromStr for BadgeStyle {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        Self::ALL
+            .into_iter()
+            .find(|style| style.as_str() == value)
+            .ok_or(())
+    }
+}
+
+/// Texts of the badges and style of the shields.io URLs copied
+#[derive(Clone, PartialEq)]
+pub(crate) struct BadgeOptions {
+    pub label: String,
+    pub message: String,
+    pub style: BadgeStyle,
+}
+
+impl Default for BadgeOptions {
+    fn default() -> Self {
+        Self {
+            label: "simple icons".to_string(),
+            message: "preview".to_string(),
+            style: BadgeStyle::default(),
+        }
+    }
+}
+
+/// Color of the logo of a badge
+#[derive(Copy, Clone, PartialEq)]
+enum LogoColor {
+    White,
+    Brand,
+}
+
+/// Badges of each style, from top to bottom
+static BADGES_COLUMNS: [(BadgeStyle, [LogoColor; 2]); 5] = [
+    (BadgeStyle::Flat, [LogoColor::White, LogoColor::Brand]),
+    (BadgeStyle::FlatSquare, [LogoColor::White, LogoColor::Brand]),
+    (BadgeStyle::Plastic, [LogoColor::White, LogoColor::Brand]),
+    (
+        BadgeStyle::ForTheBadge,
+        [LogoColor::White, LogoColor::Brand],
+    ),
+    (BadgeStyle::Social, [LogoColor::Brand, LogoColor::Brand]),
+];
+
+/// Vertical centers of the rows of badges
+static BADGES_ROWS_CENTERS: [f64; 2] = [13.0, 44.0];
+
+/// Horizontal space between the columns of badges
+static BADGES_COLUMNS_GAP: f64 = 14.0;
+
+/// Color of the text of the social badges with links
+static SOCIAL_LINK_COLOR: &str = "4183c4";
+
+/// Escape a text to be used as a part of the path of a shields.io badge
+fn escape_shields_text(text: &str) -> String {
+    let text = text.replace('-', "--").replace('_', "__").replace(' ', "_");
+    js_sys::encode_uri_component(&text).into()
+}
+
+/// Build the URL of a static badge of shields.io
+///
+/// The logo is embedded as a base64 encoded SVG.
+pub(crate) fn shields_url(
+    options: &BadgeOptions,
+    color: &str,
+    logo_svg: &str,
+) -> String {
+    let logo = format!(
+        "data:image/svg+xml;base64,{}",
+        window().btoa(logo_svg).unwrap()
+    );
+    format!(
+        "https://img.shields.io/badge/{}-{}-{}?style={}&logo={}",
+        escape_shields_text(&options.label),
+        escape_shields_text(&options.message),
+        color,
+        options.style.as_str(),
+        js_sys::encode_uri_component(&logo),
+    )
+}
+
+/// Value of a numeric attribute of the root element of a SVG
+fn svg_dimension(svg: &str, attribute: &str) -> f64 {
+    svg.split_once(&format!(" {attribute}=\""))
+        .and_then(|(_, rest)| rest.split_once('"'))
+        .and_then(|(value, _)| value.parse().ok())
+        .unwrap_or_default()
+}
+
+/// Make the SVG of a badge to be embedded in the preview card
+///
+/// Internal identifiers for gradients must be unique in the parent SVG
+/// document, so they're prefixed with the identifier of the badge.
+fn badge_svg(
+    options: &BadgeOptions,
+    color: &str,
+    logo_svg: &str,
+    style: BadgeStyle,
+    id: &str,
+) -> String {
+    let label = match style {
+        BadgeStyle::Social => "",
+        _ => &options.label,
+    };
+    let mut svg = make_badge(
+        label,
+        &options.message,
+        color,
+        style.as_str(),
+        &format!(
+            "data:image/svg+xml;base64,{}",
+            window().btoa(logo_svg).unwrap()
+        ),
+    );
+    for gradient_id in ["r", "s", "a", "b"] {
+        svg = svg
+            .replace(
+                &format!(r#"id="{gradient_id}""#),
+                &format!(r#"id="{id}-{gradient_id}""#),
+            )
+            .replace(
+                &format!("url(#{gradient_id})"),
+                &format!("url(#{id}-{gradient_id})"),
+            );
+    }
+    svg
+}
+
+/// Badges of the preview card with the icon in every style
+#[component]
+pub(crate) fn PreviewBadges(
+    placement: BadgesPlacement,
+    color: Signal<String>,
+    path: Signal<String>,
+) -> impl IntoView {
+    let pixel_ratio = use_device_pixel_ratio();
+    let badge_options = expect_context::<RwSignal<BadgeOptions>>();
+
+    let logo_svg = move |logo_color: LogoColor| {
+        svg_with_path_opt_fill(
+            &path(),
+            Some(match (is_valid_hex_color(&color()), logo_color) {
+                (false, _) => "CC0000".to_string(),
+                (true, LogoColor::White) => "FFFFFF".to_string(),
+                (true, LogoColor::Brand) => color(),
+            }),
+        )
+    };
+
+    // Badges with their positions, and the scale that fits all of them
+    // in the width of the placement
+    let badges = Memo::new(move |_| {
+        let options = badge_options();
+        let mut badges = vec![];
+        let mut x = 0.0;
+        for (column, (style, logo_colors)) in BADGES_COLUMNS.iter().enumerate()
+        {
+            let mut column_width: f64 = 0.0;
+            for (row, logo_color) in logo_colors.iter().enumerate() {
+                let id = format!("b{}", column * 2 + row + 1);
+                let mut svg = badge_svg(
+                    &options,
+                    &color(),
+                    &logo_svg(*logo_color),
+                    *style,
+                    &id,
+                );
+                if *style == BadgeStyle::Social && row == 1 {
+                    svg = svg.replace(
+                        "text id=\"rlink\"",
+                        &format!(
+                            "text id=\"rlink\" fill=\"#{SOCIAL_LINK_COLOR}\""
+                        ),
+                    );
+                }
+                let y = BADGES_ROWS_CENTERS[row]
+                    - svg_dimension(&svg, "height") / 2.0;
+                column_width = column_width.max(svg_dimension(&svg, "width"));
+                badges.push((x, y, svg));
+            }
+            x += column_width + BADGES_COLUMNS_GAP;
+        }
+        let width = x - BADGES_COLUMNS_GAP;
+        (placement.scale.min(placement.width / width), badges)
+    });
+
+    Effect::new(move |_| {
+        // Update canvas after changing the badges
+        badges.track();
+        set_timeout(
+            move || {
+                update_preview_canvas(pixel_ratio.get_untracked());
+            },
+            std::time::Duration::from_millis(0),
+        );
+    });
+
+    view! {
+        <g transform=move || {
+            format!("translate({},{}) scale({})", placement.x, placement.y, badges().0)
+        }>
+            {move || {
+                badges()
+                    .1
+                    .into_iter()
+                    .map(|(x, y, svg)| {
+                        view! { <g transform=format!("translate({x},{y})") inner_html=svg /> }
+                    })
+                    .collect_view()
+            }}
+        </g>
+    }
+}
+
+/// Inputs to customize the badges and copy their shields.io URL
+#[component]
+pub(crate) fn BadgeInputs(
+    color: ReadSignal<String>,
+    path: ReadSignal<String>,
+) -> impl IntoView {
+    let badge_options = expect_context::<RwSignal<BadgeOptions>>();
+    let UseClipboardReturn { copy, copied, .. } = use_clipboard();
+
+    view! {
+        <div class="preview-input-group">
+            <label for="preview-badge-label">{move_tr!("badge-label")}</label>
+            <input
+                type="text"
+                class="mr-4 w-[100px]"
+                id=Ids::PreviewBadgeLabel
+                prop:value=move || badge_options().label
+                autocomplete="off"
+                on:input=move |ev| {
+                    let value = event_target_value(&ev);
+                    badge_options.update(|options| options.label = value);
+                }
+            />
+        </div>
+        <div class="preview-input-group">
+            <label for="preview-badge-message">{move_tr!("badge-message")}</label>
+            <input
+                type="text"
+                class="mr-4 w-[100px]"
+                id=Ids::PreviewBadgeMessage
+                prop:value=move || badge_options().message
+                autocomplete="off"
+                on:input=move |ev| {
+                    let value = event_target_value(&ev);
+                    badge_options.update(|options| options.message = value);
+                }
+            />
+        </div>
+        <div class="preview-input-group">
+            <label for="preview-badge-style">{move_tr!("badge-style")}</label>
+            <select
+                id=Ids::PreviewBadgeStyle
+                on:change=move |ev| {
+                    if let Ok(style) = event_target_value(&ev).parse() {
+                        badge_options.update(|options| options.style = style);
+                    }
+                }
+            >
+                {BadgeStyle::ALL
+                    .into_iter()
+                    .map(|style| {
+                        view! {
+                            <option
+                                value=style.as_str()
+                                selected=move || badge_options().style == style
+                            >
+                                {style.as_str()}
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
+        </div>
+        <button
+            title=move || tr!("copy-shields-url")
+            class="button ml-2"
+            type="button"
+            id=Ids::PreviewCopyShieldsUrlButton
+            tabindex=0
+            on:click=move |_| {
+                let options = badge_options.get_untracked();
+                let color = color.get_untracked();
+                let color = match is_valid_hex_color(&color) {
+                    true => color,
+                    false => "CC0000".to_string(),
+                };
+                let logo_fill = match options.style {
+                    BadgeStyle::Social => color.clone(),
+                    _ => "FFFFFF".to_string(),
+                };
+                let logo_svg = svg_with_path_opt_fill(&path.get_untracked(), Some(logo_fill));
+                copy(&shields_url(&options, &color, &logo_svg));
+            }
+        >
+            <svg viewBox="0 0 24 24" width="24" height="24">
+                <Show when=copied fallback=move || view! { <path d=SVGDef::Copy.d()></path> }>
+                    <path d="M0 0h24v24H0z" fill="none"></path>
+                    <path d="M9 16.17L4.83 12l-1.42 1.41L9 19 21 7l-1.41-1.41z"></path>
+                </Show>
+            </svg>
+            {move || tr!("copy-shields-url")}
+        </button>
+    }
+}
@@ -1,5 +1,6 @@
 use crate::{
     Brand, canvas::canvas as canvas_container, helpers::is_valid_hex_color,
+    history::UndoRedoButtons, templates::PreviewTemplate,
 };
 use leptos::{prelude::*, task::spawn_local};
 use leptos_fluent::{move_tr, tr};
@@ -22,6 +23,7 @@ pub fn PreviewButtons(
         <div class="preview-buttons">
             <div>
                 <PreviewUploadSVGButton set_color set_path />
+                <UndoRedoButtons set_color />
             </div>
             <div class="float-right">
                 <PreviewCopyButton />
@@ -183,6 +185,7 @@ fn PreviewCopyButton() -> impl IntoView {
 #[component]
 fn PreviewSaveButton() -> impl IntoView {
     let brand = expect_context::<RwSignal<Brand>>();
+    let template = expect_context::<RwSignal<PreviewTemplate>>();
     view! {
         <button
             title=move || tr!("save-preview")
@@ -193,7 +196,7 @@ fn PreviewSaveButton() -> impl IntoView {
             on:click=move |_| {
                 let canvas = canvas_container();
                 let brand_title = brand().0.clone();
-                let filename = format!("{}.png", &sdk::title_to_slug(&brand_title));
+                let filename = template.get_untracked().filename(&sdk::title_to_slug(&brand_title));
                 let url = canvas.to_data_url().unwrap();
                 download(&filename, &url);
             }
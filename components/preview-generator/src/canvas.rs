@@ -2,9 +2,6 @@ use leptos::prelude::*;
 use simple_icons_website_ids::Ids;
 use wasm_bindgen::{JsCast, closure::Closure};
 
-pub(crate) static WIDTH: u32 = 740;
-pub(crate) static HEIGHT: u32 = 520;
-
 pub fn canvas() -> web_sys::HtmlCanvasElement {
     document()
         .query_selector(".preview-figure canvas")
@@ -24,6 +21,8 @@ fn canvas_ctx(
 }
 
 /// Function triggered to update the canvas with the current SVG
+///
+/// The canvas takes the size of the card of the selected template.
 pub fn update_preview_canvas(pixel_ratio: f64) {
     let ratio = js_sys::Math::max(pixel_ratio, 1.0);
 
@@ -39,6 +38,16 @@ pub fn update_preview_canvas(pixel_ratio: f64) {
         .item(0)
         .unwrap()
         .unchecked_into::<web_sys::HtmlElement>();
+    let preview_card_svg =
+        figure.get_elements_by_tag_name("svg").item(0).unwrap();
+    let card_size = |attribute: &str| {
+        preview_card_svg
+            .get_attribute(attribute)
+            .and_then(|value| value.parse::<f64>().ok())
+            .unwrap_or_default()
+    };
+    let (width, height) = (card_size("width"), card_size("height"));
+
     let canvas = figure
         .get_elements_by_tag_name("canvas")
         .item(0)
@@ -47,19 +56,19 @@ pub fn update_preview_canvas(pixel_ratio: f64) {
     canvas
         .set_attribute(
             "width",
-            &format!("{}", js_sys::Math::floor(WIDTH as f64 * ratio)),
+            &format!("{}", js_sys::Math::floor(width * ratio)),
         )
         .unwrap();
     canvas
         .set_attribute(
             "height",
-            &format!("{}", js_sys::Math::floor(HEIGHT as f64 * ratio)),
+            &format!("{}", js_sys::Math::floor(height * ratio)),
         )
         .unwrap();
     canvas
         .set_attribute(
             "style",
-            &format!("width: {WIDTH}px; height: {HEIGHT}px;"),
+            &format!("width: {width}px; height: {height}px;"),
         )
         .unwrap();
 
@@ -69,8 +78,6 @@ pub fn update_preview_canvas(pixel_ratio: f64) {
     ctx.scale(ratio, ratio).unwrap();
 
     // Draw the SVG of the preview card in the canvas
-    let preview_card_svg =
-        figure.get_elements_by_tag_name("svg").item(0).unwrap();
     let preview_card_img = document()
         .create_element("img")
         .unwrap()
@@ -0,0 +1,327 @@
+//! Comparison of the edited path with the current icon of the brand
+//!
+//! Reviewers of updated icons can see the areas added and removed by the
+//! edited path, overlaid over the current icon or side by side with it,
+//! along with the changes in the size and the bounding box of the path.
+
+use crate::Brand;
+use leptos::{prelude::*, task::spawn_local};
+use leptos_fluent::{move_tr, tr};
+use simple_icons_sdk as sdk;
+use simple_icons_website_grid_constants::ICONS;
+use simple_icons_website_ids::Ids;
+use simple_icons_website_url as Url;
+use std::str::FromStr;
+use svg_path_bbox::svg_path_bbox;
+use web_sys_simple_fetch::fetch_text;
+
+/// How the edited path is compared with the current icon
+#[derive(Default, Copy, Clone, PartialEq)]
+pub(crate) enum ComparisonMode {
+    #[default]
+    None,
+    /// Both paths drawn in the same icon
+    Overlay,
+    /// Current icon at the left and the edited one at the right
+    SideBySide,
+}
+
+impl ComparisonMode {
+    /// All the modes, in the order they are displayed
+    pub(crate) const ALL: [Self; 3] =
+        [Self::None, Self::Overlay, Self::SideBySide];
+}
+
+impl FromStr for ComparisonMode {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "none" => Ok(Self::None),
+            "overlay" => Ok(Self::Overlay),
+            "side-by-side" => Ok(Self::SideBySide),
+            _ => Err(()),
+        }
+    }
+}
+
+impl core::fmt::Display for ComparisonMode {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::None => write!(f, "none"),
+            Self::Overlay => write!(f, "overlay"),
+            Self::SideBySide => write!(f, "side-by-side"),
+        }
+    }
+}
+
+pub(crate) fn initial_comparison_mode() -> ComparisonMode {
+    Url::params::get(&Url::params::Names::PreviewComparison)
+        .and_then(|value| value.parse().ok())
+        .unwrap_or_default()
+}
+
+/// Size of the icons compared in pixels
+const COMPARISON_ICON_SIZE: u32 = 192;
+
+/// Identifier of the mask that hides the areas of the current icon
+const NOT_IN_CURRENT_MASK_ID: &str = "preview-comparison-not-in-current";
+
+/// Identifier of the mask that hides the areas of the edited icon
+const NOT_IN_EDITED_MASK_ID: &str = "preview-comparison-not-in-edited";
+
+/// Bounding box rounded to 3 decimals
+fn format_bbox(path: &str) -> String {
+    let round = |value: f64| (value * 1000.0).round() / 1000.0;
+    match svg_path_bbox(path) {
+        Ok((x0, y0, x1, y1)) => format!(
+            "[{}, {}, {}, {}]",
+            round(x0),
+            round(y0),
+            round(x1),
+            round(y1)
+        ),
+        Err(_) => "-".to_string(),
+    }
+}
+
+/// Difference between two sizes with its sign
+fn format_size_difference(before: usize, after: usize) -> String {
+    match after.cmp(&before) {
+        core::cmp::Ordering::Greater => format!("+{}", after - before),
+        core::cmp::Ordering::Less => format!("-{}", before - after),
+        core::cmp::Ordering::Equal => "0".to_string(),
+    }
+}
+
+#[component]
+pub(crate) fn ComparisonInput(mode: RwSignal<ComparisonMode>) -> impl IntoView {
+    let label = move |mode: ComparisonMode| match mode {
+        ComparisonMode::None => move_tr!("no-comparison"),
+        ComparisonMode::Overlay => move_tr!("overlay-comparison"),
+        ComparisonMode::SideBySide => move_tr!("side-by-side-comparison"),
+    };
+
+    view! {
+        <div class="preview-input-group">
+            <label for="preview-comparison">{move_tr!("compare")}</label>
+            <select
+                id=Ids::PreviewComparison
+                on:change=move |ev| {
+                    let value = event_target_value(&ev);
+                    if let Ok(new_mode) = value.parse() {
+                        mode.set(new_mode);
+                        Url::params::update(&Url::params::Names::PreviewComparison, &value);
+                    }
+                }
+            >
+                {ComparisonMode::ALL
+                    .into_iter()
+                    .map(|option| {
+                        view! {
+                            <option value=option.to_string() selected=move || mode() == option>
+                                {label(option)}
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
+        </div>
+    }
+}
+
+/// Mask that hides the areas filled by a path
+#[component]
+fn NotInPathMask(id: &'static str, path: Signal<String>) -> impl IntoView {
+    view! {
+        <mask id=id maskUnits="userSpaceOnUse" x="0" y="0" width="24" height="24">
+            <rect width="24" height="24" fill="white"></rect>
+            <path d=path fill="black"></path>
+        </mask>
+    }
+}
+
+/// Icon of a path compared, highlighting the areas not in the other path
+#[component]
+fn ComparedIcon(
+    path: Signal<String>,
+    other_path: Signal<String>,
+    /// Identifier of the mask that hides the other path
+    mask_id: &'static str,
+    /// Class of the areas only in this path
+    class: &'static str,
+    title: Signal<String>,
+) -> impl IntoView {
+    view! {
+        <figure>
+            <svg
+                viewBox="0 0 24 24"
+                width=COMPARISON_ICON_SIZE
+                height=COMPARISON_ICON_SIZE
+                xmlns="http://www.w3.org/2000/svg"
+            >
+                <defs>
+                    <NotInPathMask id=mask_id path=other_path />
+                </defs>
+                <path class="unchanged" d=path></path>
+                <path class=class d=path mask=format!("url(#{mask_id})")></path>
+            </svg>
+            <figcaption>{title}</figcaption>
+        </figure>
+    }
+}
+
+/// Both paths in the same icon, with the areas added and removed
+#[component]
+fn OverlaidIcons(
+    current_path: Signal<String>,
+    path: Signal<String>,
+) -> impl IntoView {
+    view! {
+        <figure>
+            <svg
+                viewBox="0 0 24 24"
+                width=COMPARISON_ICON_SIZE
+                height=COMPARISON_ICON_SIZE
+                xmlns="http://www.w3.org/2000/svg"
+            >
+                <defs>
+                    <NotInPathMask id=NOT_IN_CURRENT_MASK_ID path=current_path />
+                    <NotInPathMask id=NOT_IN_EDITED_MASK_ID path />
+                </defs>
+                <path class="unchanged" d=current_path></path>
+                <path class="unchanged" d=path></path>
+                <path
+                    class="removed"
+                    d=current_path
+                    mask=format!("url(#{NOT_IN_EDITED_MASK_ID})")
+                ></path>
+                <path class="added" d=path mask=format!("url(#{NOT_IN_CURRENT_MASK_ID})")></path>
+            </svg>
+        </figure>
+    }
+}
+
+/// Changes in the size and the bounding box of the path
+#[component]
+fn ComparisonReport(
+    current_path: Signal<String>,
+    path: Signal<String>,
+) -> impl IntoView {
+    view! {
+        <ul class="preview-comparison-report">
+            <li>
+                <span class="added"></span>
+                {move_tr!("added-areas")}
+                <span class="removed"></span>
+                {move_tr!("removed-areas")}
+            </li>
+            <li>
+                {move || {
+                    let (before, after) = (current_path().len(), path().len());
+                    tr!(
+                        "path-size-change",
+                        {
+                            "before" => before.to_string(),
+                            "after" => after.to_string(),
+                            "difference" => format_size_difference(before, after)
+                        }
+                    )
+                }}
+            </li>
+            <li>
+                {move || {
+                    tr!(
+                        "path-bbox-change",
+                        {
+                            "before" => format_bbox(&current_path()),
+                            "after" => format_bbox(&path())
+                        }
+                    )
+                }}
+            </li>
+        </ul>
+    }
+}
+
+/// Comparison of the edited path with the current icon of the brand
+#[component]
+pub(crate) fn PathComparison(
+    mode: RwSignal<ComparisonMode>,
+    path: ReadSignal<String>,
+) -> impl IntoView {
+    let brand = expect_context::<RwSignal<Brand>>();
+    let slug = Memo::new(move |_| brand().1);
+    let enabled = Memo::new(move |_| mode() != ComparisonMode::None);
+
+    // `None` while loading, `Some(None)` if the icon can't be loaded
+    let current_path = RwSignal::new(None::<Option<String>>);
+
+    Effect::new(move |_| {
+        if !enabled() {
+            return;
+        }
+        let requested_slug = slug();
+        current_path.set(None);
+        if !ICONS.iter().any(|icon| icon.slug == requested_slug) {
+            current_path.set(Some(None));
+            return;
+        }
+        spawn_local(async move {
+            let maybe_path =
+                fetch_text(&format!("/icons/{requested_slug}.svg"))
+                    .await
+                    .ok()
+                    .filter(|svg| svg.contains(" d=\""))
+                    .map(|svg| sdk::svg_to_path(&svg));
+            // Ignore the responses of brands changed while loading
+            if slug.get_untracked() == requested_slug {
+                current_path.set(Some(maybe_path));
+            }
+        });
+    });
+
+    let path = Signal::from(path);
+
+    view! {
+        <Show when=enabled>
+            <div class="preview-comparison">
+                {move || match current_path() {
+                    None => ().into_any(),
+                    Some(None) => view! { <p>{move_tr!("current-path-not-found")}</p> }.into_any(),
+                    Some(Some(current)) => {
+                        let current_path = Signal::stored(current);
+                        view! {
+                            <div class="preview-comparison-icons">
+                                {move || match mode() {
+                                    ComparisonMode::SideBySide => {
+                                        view! {
+                                            <ComparedIcon
+                                                path=current_path
+                                                other_path=path
+                                                mask_id=NOT_IN_EDITED_MASK_ID
+                                                class="removed"
+                                                title=Signal::derive(move || tr!("current-path"))
+                                            />
+                                            <ComparedIcon
+                                                path
+                                                other_path=current_path
+                                                mask_id=NOT_IN_CURRENT_MASK_ID
+                                                class="added"
+                                                title=Signal::derive(move || tr!("edited-path"))
+                                            />
+                                        }
+                                            .into_any()
+                                    }
+                                    _ => view! { <OverlaidIcons current_path path /> }.into_any(),
+                                }}
+                            </div>
+                            <ComparisonReport current_path path />
+                        }
+                            .into_any()
+                    }
+                }}
+            </div>
+        </Show>
+    }
+}
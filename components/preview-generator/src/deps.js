@@ -1,14 +1,6 @@
 /* global document */
 
-export const add_scripts_ = (badgeMakerVersion, svgPathBboxVersion) => {
-	if (!document.querySelector('script#badge-maker')) {
-		const script = document.createElement('script');
-		script.src = `/js/badge-maker-${badgeMakerVersion}.js`;
-		script.id = 'badge-maker';
-		script.defer = true;
-		document.body.append(script);
-	}
-
+export const add_scripts_ = (svgPathBboxVersion) => {
 	if (!document.querySelector('script#svg-path-bbox')) {
 		const script = document.createElement('script');
 		script.src = `/js/svg-path-bbox-${svgPathBboxVersion}.js`;
@@ -17,6 +9,3 @@ export const add_scripts_ = (badgeMakerVersion, svgPathBboxVersion) => {
 		document.body.append(script);
 	}
 };
-
-export const is_badge_maker_loaded = () =>
-	globalThis.makeBadgeWithBadgeMaker !== undefined;
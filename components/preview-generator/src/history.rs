@@ -0,0 +1,317 @@
+//! History of the changes of the preview
+//!
+//! Every change of the brand, the color or the path is recorded as a step
+//! that can be undone and redone. Changes made in a short time, like typing
+//! in an input or selecting a brand suggestion, which updates the brand,
+//! the color and the path, are grouped in the same step.
+
+use crate::{Brand, canvas::update_preview_canvas};
+use leptos::prelude::*;
+use leptos_fluent::{move_tr, tr};
+use leptos_use::use_device_pixel_ratio;
+use simple_icons_website_ids::Ids;
+use simple_icons_website_svg_defs::SVGDef;
+use simple_icons_website_svg_icon::SVGIcon;
+use std::time::Duration;
+use wasm_bindgen::JsCast;
+use web_sys_simple_events::dispatch_input_event_on_input;
+
+/// Maximum number of steps remembered
+const MAX_HISTORY_STEPS: usize = 30;
+
+/// Time without changes after which they are recorded as a step
+const STEP_DEBOUNCE: Duration = Duration::from_millis(500);
+
+/// State of the preview recorded in each step
+#[derive(Clone, PartialEq)]
+pub(crate) struct PreviewState {
+    pub brand: Brand,
+    pub color: String,
+    pub path: String,
+}
+
+/// Bounded stack of the states of the preview
+#[derive(Default)]
+pub(crate) struct History {
+    steps: Vec<PreviewState>,
+    /// Index of the step displayed
+    current: usize,
+    /// State changed less than [`STEP_DEBOUNCE`] ago, not recorded yet
+    pending: Option<PreviewState>,
+}
+
+impl History {
+    /// Record a new state, discarding the steps undone
+    ///
+    /// Returns whether the oldest step has been discarded.
+    fn record(&mut self, state: PreviewState) -> bool {
+        if self.steps.get(self.current) == Some(&state) {
+            return false;
+        }
+        if !self.steps.is_empty() {
+            self.steps.truncate(self.current + 1);
+        }
+        self.steps.push(state);
+        let discard_oldest = self.steps.len() > MAX_HISTORY_STEPS;
+        if discard_oldest {
+            self.steps.remove(0);
+        }
+        self.current = self.steps.len() - 1;
+        discard_oldest
+    }
+
+    /// Record the pending state, if any
+    ///
+    /// Returns whether the oldest step has been discarded.
+    fn flush(&mut self) -> bool {
+        self.pending.take().is_some_and(|state| self.record(state))
+    }
+
+    fn can_undo(&self) -> bool {
+        self.current > 0 || self.pending.is_some()
+    }
+
+    /// Recording the pending state discards the steps undone
+    fn can_redo(&self) -> bool {
+        self.pending.is_none() && self.current + 1 < self.steps.len()
+    }
+
+    /// Move to a step, returning its state
+    ///
+    /// The pending state is recorded before, so it can be restored later.
+    fn go_to(&mut self, index: usize) -> Option<PreviewState> {
+        let index = match self.flush() {
+            true => index.checked_sub(1)?,
+            false => index,
+        };
+        let state = self.steps.get(index)?.clone();
+        self.current = index;
+        Some(state)
+    }
+
+    /// Move to the previous step, returning its state
+    fn undo(&mut self) -> Option<PreviewState> {
+        self.flush();
+        self.go_to(self.current.checked_sub(1)?)
+    }
+
+    /// Move to the next step, returning its state
+    fn redo(&mut self) -> Option<PreviewState> {
+        self.flush();
+        self.go_to(self.current + 1)
+    }
+}
+
+/// Change made by a step
+enum Change {
+    Brand(String, String),
+    Color(String, String),
+    Path(usize, usize),
+}
+
+fn changes(previous: &PreviewState, state: &PreviewState) -> Vec<Change> {
+    let mut changes = vec![];
+    if previous.brand.0 != state.brand.0 {
+        changes.push(Change::Brand(
+            previous.brand.0.clone(),
+            state.brand.0.clone(),
+        ));
+    }
+    if previous.color != state.color {
+        changes
+            .push(Change::Color(previous.color.clone(), state.color.clone()));
+    }
+    if previous.path != state.path {
+        changes.push(Change::Path(previous.path.len(), state.path.len()));
+    }
+    changes
+}
+
+fn tr_change(change: &Change) -> String {
+    match change {
+        Change::Brand(before, after) => tr!(
+            "brand-changed",
+            { "before" => before.clone(), "after" => after.clone() }
+        ),
+        Change::Color(before, after) => tr!(
+            "color-changed",
+            { "before" => before.clone(), "after" => after.clone() }
+        ),
+        Change::Path(before, after) => tr!(
+            "path-changed",
+            { "before" => before.to_string(), "after" => after.to_string() }
+        ),
+    }
+}
+
+/// Record the changes of the preview in the history
+pub(crate) fn track_history(
+    color: ReadSignal<String>,
+    path: ReadSignal<String>,
+) {
+    let brand = expect_context::<RwSignal<Brand>>();
+    let history = expect_context::<RwSignal<History>>();
+    let pending_step = StoredValue::new(None::<TimeoutHandle>);
+
+    Effect::new(move |_| {
+        let state = PreviewState {
+            brand: brand(),
+            color: color(),
+            path: path(),
+        };
+        if let Some(handle) = pending_step.get_value() {
+            handle.clear();
+        }
+        // The path is empty while the initial icon is loading
+        if state.path.is_empty() {
+            history.update(|history| history.pending = None);
+            return;
+        }
+        // The initial state is recorded without waiting for more changes
+        if history.with_untracked(|history| history.steps.is_empty()) {
+            history.update(|history| _ = history.record(state));
+            return;
+        }
+        history.update(|history| history.pending = Some(state));
+        pending_step.set_value(
+            set_timeout_with_handle(
+                move || history.update(|history| _ = history.flush()),
+                STEP_DEBOUNCE,
+            )
+            .ok(),
+        );
+    });
+}
+
+/// Setters of the state of the preview
+#[derive(Copy, Clone)]
+struct PreviewStateSetters {
+    history: RwSignal<History>,
+    brand: RwSignal<Brand>,
+    set_color: WriteSignal<String>,
+    pixel_ratio: Signal<f64>,
+}
+
+impl PreviewStateSetters {
+    fn new(set_color: WriteSignal<String>) -> Self {
+        Self {
+            history: expect_context::<RwSignal<History>>(),
+            brand: expect_context::<RwSignal<Brand>>(),
+            set_color,
+            pixel_ratio: use_device_pixel_ratio(),
+        }
+    }
+
+    /// Restore a state of the preview
+    ///
+    /// The path is set through its input to lint it as if it was typed.
+    fn restore(&self, state: PreviewState) {
+        self.brand.set(state.brand);
+        self.set_color.set(state.color);
+        let path_input = document()
+            .get_element_by_id(Ids::PreviewPath.as_str())
+            .unwrap()
+            .unchecked_into::<web_sys::HtmlInputElement>();
+        path_input.set_value(&state.path);
+        dispatch_input_event_on_input(&path_input);
+        // Execute at the next re-paint, when the preview is rendered
+        let pixel_ratio = self.pixel_ratio;
+        set_timeout(
+            move || update_preview_canvas(pixel_ratio.get_untracked()),
+            Duration::from_millis(0),
+        );
+    }
+
+    /// Move in the history and restore the state of the new step
+    fn move_to(&self, step: impl FnOnce(&mut History) -> Option<PreviewState>) {
+        let mut state = None;
+        self.history.update(|history| state = step(history));
+        if let Some(state) = state {
+            self.restore(state);
+        }
+    }
+}
+
+#[component]
+pub(crate) fn UndoRedoButtons(set_color: WriteSignal<String>) -> impl IntoView {
+    let setters = PreviewStateSetters::new(set_color);
+    let history = setters.history;
+
+    view! {
+        <button
+            title=move_tr!("undo")
+            class="button"
+            type="button"
+            id=Ids::PreviewUndoButton
+            tabindex=0
+            disabled=move || !history.with(History::can_undo)
+            on:click=move |_| setters.move_to(History::undo)
+        >
+            <SVGIcon width="24" height="24" aria_hidden=true path=SVGDef::Undo.d() />
+            {move_tr!("undo")}
+        </button>
+        <button
+            title=move_tr!("redo")
+            class="button"
+            type="button"
+            id=Ids::PreviewRedoButton
+            tabindex=0
+            disabled=move || !history.with(History::can_redo)
+            on:click=move |_| setters.move_to(History::redo)
+        >
+            <SVGIcon width="24" height="24" aria_hidden=true path=SVGDef::Redo.d() />
+            {move_tr!("redo")}
+        </button>
+    }
+}
+
+/// List of the steps of the history with what each one changed
+#[component]
+pub(crate) fn HistoryList(set_color: WriteSignal<String>) -> impl IntoView {
+    let setters = PreviewStateSetters::new(set_color);
+    let history = setters.history;
+
+    view! {
+        <Show when=move || history.with(|history| history.steps.len() > 1)>
+            <div class="preview-history">
+                <span>{move_tr!("history")}</span>
+                <ol>
+                    {move || {
+                        history
+                            .with(|history| {
+                                history
+                                    .steps
+                                    .iter()
+                                    .enumerate()
+                                    .map(|(index, state)| {
+                                        let description = match index {
+                                            0 => tr!("initial-state"),
+                                            _ => {
+                                                changes(&history.steps[index - 1], state)
+                                                    .iter()
+                                                    .map(tr_change)
+                                                    .collect::<Vec<_>>()
+                                                    .join(", ")
+                                            }
+                                        };
+                                        view! {
+                                            <li
+                                                class:current=index == history.current
+                                                role="button"
+                                                tabindex=0
+                                                on:click=move |_| {
+                                                    setters.move_to(|history| history.go_to(index))
+                                                }
+                                            >
+                                                {description}
+                                            </li>
+                                        }
+                                    })
+                                    .collect_view()
+                            })
+                    }}
+                </ol>
+            </div>
+        </Show>
+    }
+}
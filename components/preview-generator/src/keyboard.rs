@@ -3,6 +3,18 @@ use simple_icons_website_ids::Ids;
 use std::boxed::Box;
 use wasm_bindgen::JsCast;
 
+/// Whether the target of an event is a field whose text can be edited,
+/// which has its own undo history in the browser
+fn is_editable_target(event: &web_sys::KeyboardEvent) -> bool {
+    event
+        .target()
+        .and_then(|target| target.dyn_into::<web_sys::HtmlElement>().ok())
+        .is_some_and(|element| {
+            matches!(element.tag_name().as_str(), "INPUT" | "TEXTAREA")
+                || element.is_content_editable()
+        })
+}
+
 pub(crate) fn listen_keyboard_shortcuts() {
     let keys_pressed = RwSignal::new(vec![]);
 
@@ -15,11 +27,6 @@ pub(crate) fn listen_keyboard_shortcuts() {
             let pressed_codes = keys_pressed.get_untracked();
             let code = event.code().to_lowercase();
 
-            if pressed_codes.len() != 2 {
-                return;
-            }
-            let previous_code = pressed_codes[0].clone();
-
             let click_button = |button_id: &str| {
                 document()
                     .get_element_by_id(button_id)
@@ -31,6 +38,28 @@ pub(crate) fn listen_keyboard_shortcuts() {
                 });
             };
 
+            if pressed_codes.len() == 3 {
+                // Ctrl + Shift + Z
+                let modifiers = &pressed_codes[..2];
+                if code == "keyz"
+                    && !is_editable_target(&event)
+                    && modifiers.iter().any(|key| {
+                        ["controlleft", "controlright"].contains(&key.as_str())
+                    })
+                    && modifiers.iter().any(|key| {
+                        ["shiftleft", "shiftright"].contains(&key.as_str())
+                    })
+                {
+                    click_button(Ids::PreviewRedoButton.as_str());
+                    event.prevent_default();
+                }
+                return;
+            }
+            if pressed_codes.len() != 2 {
+                return;
+            }
+            let previous_code = pressed_codes[0].clone();
+
             // Ctrl + Up
             if code == "arrowup"
                 && ["controlleft", "controlright"]
@@ -67,6 +96,14 @@ pub(crate) fn listen_keyboard_shortcuts() {
             {
                 click_button(Ids::PreviewSaveButton.as_str());
                 event.prevent_default();
+            } else if code == "keyz"
+                && ["controlleft", "controlright"]
+                    .contains(&previous_code.as_str())
+                && !is_editable_target(&event)
+            {
+                // Ctrl + Z, out of the text fields
+                click_button(Ids::PreviewUndoButton.as_str());
+                event.prevent_default();
             }
         },
     )
@@ -1,26 +1,34 @@
+mod badges;
 mod buttons;
 mod canvas;
+mod comparison;
 mod deps;
 mod helpers;
+mod history;
 mod inputs;
 mod keyboard;
+mod templates;
 
-use badge_maker::make_badge;
+use badges::{BadgeInputs, BadgeOptions, PreviewBadges};
 use buttons::PreviewButtons;
 use canvas::update_preview_canvas;
+use comparison::{ComparisonInput, PathComparison, initial_comparison_mode};
 pub use deps::add_preview_generator_scripts;
 use fast_fuzzy::search;
 use helpers::contrast_color_for;
 use helpers::is_valid_hex_color;
+use history::{History, HistoryList, track_history};
 use inputs::{BrandInput, ColorInput, PathInput};
 use leptos::{prelude::*, task::spawn_local};
 use leptos_use::use_device_pixel_ratio;
 use simple_icons_macros::{get_number_of_icons, get_simple_icon_svg_path};
 use simple_icons_sdk as sdk;
 use simple_icons_website_grid_constants::ICONS;
-use simple_icons_website_svg_icon::svg_with_path_opt_fill;
 use simple_icons_website_types::SimpleIcon;
 use simple_icons_website_url as Url;
+use templates::{
+    PreviewTemplate, TemplateInput, TextContent, TextSlot, initial_template,
+};
 use web_sys_simple_fetch::fetch_text;
 
 static DEFAULT_INITIAL_BRAND: &str = "Simple Icons";
@@ -82,7 +90,13 @@ pub fn PreviewGenerator() -> impl IntoView {
     let (color, set_color) = signal(initial_color);
     let (path, set_path) = signal(initial_path.clone());
 
+    let template = RwSignal::new(initial_template());
+    let comparison_mode = RwSignal::new(initial_comparison_mode());
+
     provide_context::<RwSignal<Brand>>(brand);
+    provide_context::<RwSignal<PreviewTemplate>>(template);
+    provide_context(RwSignal::new(BadgeOptions::default()));
+    provide_context(RwSignal::new(History::default()));
 
     if path.get_untracked().is_empty() {
         spawn_local(async move {
@@ -100,7 +114,16 @@ pub fn PreviewGenerator() -> impl IntoView {
 
     let pixel_ratio = use_device_pixel_ratio();
     Effect::new(move |_| update_preview_canvas(pixel_ratio()));
+    Effect::new(move |_| {
+        template.track();
+        // Execute at the next re-paint, when the new card is rendered
+        set_timeout(
+            move || update_preview_canvas(pixel_ratio.get_untracked()),
+            std::time::Duration::from_millis(0),
+        );
+    });
 
+    track_history(color, path);
     keyboard::listen_keyboard_shortcuts();
 
     view! {
@@ -110,13 +133,53 @@ pub fn PreviewGenerator() -> impl IntoView {
                 <ColorInput color set_color />
             </div>
             <PathInput path set_path />
+            <div>
+                <TemplateInput template />
+                <ComparisonInput mode=comparison_mode />
+            </div>
+            <div>
+                <BadgeInputs color path />
+            </div>
 
             <PreviewFigure color path />
+            <PathComparison mode=comparison_mode path />
             <PreviewButtons path set_color set_path />
+            <HistoryList set_color />
         </div>
     }
 }
 
+/// Split a title in two lines if it's longer than the maximum length
+///
+/// The second line is truncated with an ellipsis if the title doesn't fit.
+fn split_title(title: &str, max_length: usize) -> Option<(String, String)> {
+    if title.len() <= max_length {
+        return None;
+    }
+    let mut title_1 = String::with_capacity(max_length);
+    let mut title_2 = String::with_capacity(max_length);
+    for part in title.split(' ') {
+        if title_1.len() + part.len() < max_length {
+            title_1.push_str(part);
+            title_1.push(' ');
+        } else if title_2.len() + part.len() < max_length {
+            title_2.push_str(part);
+            title_2.push(' ');
+        } else {
+            for ch in part.chars() {
+                if title_2.len() + 1 < max_length {
+                    title_2.push(ch);
+                } else {
+                    title_2.push('…');
+                    break;
+                }
+            }
+            break;
+        }
+    }
+    Some((title_1, title_2))
+}
+
 #[component]
 fn PreviewFigure(
     color: ReadSignal<String>,
@@ -138,314 +201,166 @@ fn PreviewFigure(
             color
         }
     });
-    let brand = expect_context::<RwSignal<Brand>>();
-
-    let (width, height) = (canvas::WIDTH, canvas::HEIGHT);
+    let template = expect_context::<RwSignal<PreviewTemplate>>();
 
     view! {
         <figure class="preview-figure">
-            <svg
-                width=width
-                height=height
-                viewBox=format!("0 0 {width} {height}")
-                xmlns="http://www.w3.org/2000/svg"
-                class="pt-3"
-            >
-                <rect
-                    fill=move || format!("#{}", color())
-                    height=height - 100
-                    rx="10"
-                    ry="10"
-                    width=width
-                    x="0"
-                    y="0"
-                ></rect>
-                <svg viewBox="0 0 24 24" width="24" height="24" x="18" y="20">
-                    <path d=path fill=fill_color></path>
-                </svg>
-                <svg viewBox="0 0 24 24" width="80" height="80" x="70" y="20">
-                    <path d=path fill=fill_color></path>
-                </svg>
-                <svg viewBox="0 0 24 24" width="138" height="138" x="174" y="20">
-                    <path d=path fill=fill_color></path>
-                </svg>
-                <svg viewBox="0 0 24 24" width="375" height="375" x="350" y="20">
-                    <path d=path fill=fill_color></path>
-                </svg>
-
-                <g transform="translate(21,235)" style="font-family: Helvetica">
-                    {move || {
-                        let title = brand().0.clone();
-                        let preview_title = format!("{title} Preview");
-                        if preview_title.len() > 24 {
-                            let mut title_1 = String::with_capacity(24);
-                            let mut title_2 = String::with_capacity(24);
-                            for part in preview_title.split(' ') {
-                                if title_1.len() + part.len() < 24 {
-                                    title_1.push_str(part);
-                                    title_1.push(' ');
-                                } else if title_2.len() + part.len() < 24 {
-                                    title_2.push_str(part);
-                                    title_2.push(' ');
-                                } else {
-                                    for ch in part.chars() {
-                                        if title_2.len() + 1 < 24 {
-                                            title_2.push(ch);
-                                        } else {
-                                            title_2.push('…');
-                                            break;
-                                        }
-                                    }
-                                    break;
-                                }
-                            }
-                            view! {
-                                <text fill=fill_color font-size="25" y="-31">
-                                    {title_1}
-                                </text>
-                                <text fill=fill_color font-size="25">
-                                    {title_2}
-                                </text>
-                            }
-                                .into_any()
-                        } else {
-                            view! {
-                                <text fill=fill_color font-size="25">
-                                    {preview_title}
-                                </text>
-                            }
-                                .into_any()
-                        }
-                    }}
-
-                    <text fill=fill_color font-size="17" y="25">
-                        {move || format!("{}.svg", sdk::title_to_slug(&brand().0))}
-                    </text>
-                    <text fill=fill_color font-size="16" y="61">
-                        {move || format!("Brand: {}", brand().0)}
-                    </text>
-                    <text fill=fill_color font-size="16" y="84">
-                        {move || format!("Color: #{}", color())}
-                    </text>
-
-                    <g transform="translate(3, 142)" style="font-family: Helvetica">
-                        <svg viewBox="0 0 24 24" width="24" height="24">
-                            <path d=DEFAULT_INITIAL_PATH.to_string() fill=fill_color></path>
-                        </svg>
-                        <text fill=fill_color x="30" y="7" font-size="12">
-                            {format!("{} SVG brand icons", get_number_of_icons!())}
-                        </text>
-                        <text fill=fill_color x="30" y="25" font-size="12">
-                            available at simpleicons.org
-                        </text>
-                    </g>
-                </g>
-                <PreviewBadges color=color_or_error_color.into() path=path.into() />
-            </svg>
-            <canvas width=width height=height></canvas>
-        </figure>
-    }
-}
-
-#[component]
-fn PreviewBadges(color: Signal<String>, path: Signal<String>) -> impl IntoView {
-    let pixel_ratio = use_device_pixel_ratio();
-
-    let white_svg = Signal::derive(move || {
-        svg_with_path_opt_fill(
-            &path(),
-            if is_valid_hex_color(&color()) {
-                Some("FFFFFF".to_string())
-            } else {
-                Some("CC0000".to_string())
-            },
-        )
-    });
-    let color_svg = Signal::derive(move || {
-        svg_with_path_opt_fill(
-            &path(),
-            if is_valid_hex_color(&color()) {
-                Some(color().to_string())
-            } else {
-                Some("CC0000".to_string())
-            },
-        )
-    });
-
-    let badge_maker_loaded = RwSignal::new(false);
-
-    let badge_maker_loaded_interval = set_interval_with_handle(
-        move || {
-            if deps::is_badge_maker_loaded() {
-                badge_maker_loaded(true);
-
-                // Execute at the next re-paint
-                set_timeout(
-                    move || {
-                        update_preview_canvas(
-                            use_device_pixel_ratio().get_untracked(),
-                        );
-                    },
-                    std::time::Duration::from_millis(0),
-                );
-            }
-        },
-        std::time::Duration::from_millis(100),
-    )
-    .unwrap();
-
-    Effect::new(move |_| {
-        // Update canvas after changing the path or color
-        let _path = path();
-        let _color = color();
-        set_timeout(
-            move || {
-                update_preview_canvas(pixel_ratio.get_untracked());
-            },
-            std::time::Duration::from_millis(0),
-        );
-    });
-
-    let translate_y = 34;
-
-    view! {
-        <g transform="translate(10,437) scale(1.03)">
             {move || {
-                match badge_maker_loaded() {
-                    false => {
-                        view! {
-                            <text fill="white" transform="translate(310,35) scale(1.5)">
-                                ...
-                            </text>
-                        }
-                            .into_any()
-                    }
-                    true => {
-                        badge_maker_loaded_interval.clear();
-                        view! {
-                            <PreviewBadge
-                                color
-                                svg=white_svg
-                                style="flat"
-                                translate_x=10
-                                translate_y=3
-                                id="b1"
-                            />
-                            <PreviewBadge
-                                color
-                                svg=color_svg
-                                style="flat"
-                                translate_x=10
-                                translate_y=translate_y
-                                id="b2"
-                            />
-                            <PreviewBadge
-                                color
-                                svg=white_svg
-                                style="plastic"
-                                translate_x=188
-                                translate_y=7
-                                id="b3"
-                            />
-                            <PreviewBadge
-                                color
-                                svg=color_svg
-                                style="plastic"
-                                translate_x=188
-                                translate_y=translate_y + 1
-                                id="b4"
-                            />
-                            <PreviewBadge
-                                color
-                                svg=white_svg
-                                style="for-the-badge"
-                                translate_x=365
-                                translate_y=-3
-                                id="b5"
-                            />
-                            <PreviewBadge
-                                color
-                                svg=color_svg
-                                style="for-the-badge"
-                                translate_x=365
-                                translate_y=translate_y - 3
-                                id="b6"
-                            />
-                            <PreviewBadge
-                                color
-                                svg=color_svg
-                                style="social"
-                                translate_x=610
-                                translate_y=3
-                                id="b7"
-                            />
-                            <PreviewBadge
-                                color
-                                svg=color_svg
-                                style="social"
-                                text_color="4183c4"
-                                translate_x=610
-                                translate_y=translate_y - 2
-                                id="b8"
-                            />
-                        }
-                            .into_any()
-                    }
+                let layout = template().layout();
+                let (width, height) = (layout.width, layout.height);
+                view! {
+                    <svg
+                        width=width
+                        height=height
+                        viewBox=format!("0 0 {width} {height}")
+                        xmlns="http://www.w3.org/2000/svg"
+                        class="pt-3"
+                    >
+                        {layout
+                            .backgrounds
+                            .iter()
+                            .map(|background| {
+                                view! {
+                                    <rect
+                                        fill=move || background.paint.fill(&color(), &fill_color())
+                                        height=background.height
+                                        rx=background.radius
+                                        ry=background.radius
+                                        width=background.width
+                                        x=background.x
+                                        y=background.y
+                                    ></rect>
+                                }
+                            })
+                            .collect_view()}
+                        {layout
+                            .icons
+                            .iter()
+                            .map(|icon| {
+                                view! {
+                                    <svg
+                                        viewBox="0 0 24 24"
+                                        width=icon.size
+                                        height=icon.size
+                                        x=icon.x
+                                        y=icon.y
+                                    >
+                                        <path
+                                            d=path
+                                            fill=move || {
+                                                icon.paint.fill(&color_or_error_color(), &fill_color())
+                                            }
+                                        ></path>
+                                    </svg>
+                                }
+                            })
+                            .collect_view()}
+                        <g
+                            transform=format!("translate({},{})", layout.texts.x, layout.texts.y)
+                            style="font-family: Helvetica"
+                        >
+                            {layout
+                                .texts
+                                .slots
+                                .iter()
+                                .map(|slot| {
+                                    let fill = Signal::derive(move || {
+                                        slot.paint.fill(&color_or_error_color(), &fill_color())
+                                    });
+                                    view! { <PreviewText text=slot color fill /> }
+                                })
+                                .collect_view()}
+                        </g>
+                        {layout
+                            .badges
+                            .map(|placement| {
+                                view! {
+                                    <PreviewBadges
+                                        placement
+                                        color=color_or_error_color.into()
+                                        path=path.into()
+                                    />
+                                }
+                            })}
+                    </svg>
+                    <canvas width=width height=height></canvas>
                 }
             }}
-        </g>
+        </figure>
     }
 }
 
 #[component]
-fn PreviewBadge(
-    color: Signal<String>,
-    svg: Signal<String>,
-    style: &'static str,
-    #[prop(optional)] text_color: Option<&'static str>,
-    translate_x: usize,
-    translate_y: isize,
-    id: &'static str,
+fn PreviewText(
+    text: &'static TextSlot,
+    color: ReadSignal<String>,
+    fill: Signal<String>,
 ) -> impl IntoView {
-    fn badge_svg(color_: &str, svg_: &str, style_: &str, id: &str) -> String {
-        make_badge(
-            match style_ {
-                "social" => "",
-                _ => "simple icons",
-            },
-            "preview",
-            color_,
-            style_,
-            &format!(
-                "data:image/svg+xml;base64,{}",
-                window().btoa(svg_).unwrap()
-            ),
-        )
-        // Internal identifiers for gradients must be unique in the parent SVG document
-        .replace(r#"id="r""#, format!(r#"id="{id}-r""#).as_str())
-        .replace("url(#r)", format!("url(#{id}-r)").as_str())
-        .replace(r#"id="s""#, format!(r#"id="{id}-s""#).as_str())
-        .replace("url(#s)", format!("url(#{id}-s)").as_str())
-        .replace(r#"id="a""#, format!(r#"id="{id}-a""#).as_str())
-        .replace("url(#a)", format!("url(#{id}-a)").as_str())
-        .replace(r#"id="b""#, format!(r#"id="{id}-b""#).as_str())
-        .replace("url(#b)", format!("url(#{id}-b)").as_str())
-    }
+    let brand = expect_context::<RwSignal<Brand>>();
+    let (x, y, font_size) = (text.x, text.y, text.font_size);
 
-    view! {
-        <g
-            transform=format!("translate({translate_x},{translate_y})")
-            inner_html=move || {
-                let mut badge_svg = badge_svg(&color(), &svg(), style, id);
-                if let Some(text_color) = text_color {
-                    badge_svg = badge_svg
-                        .replace(
-                            "text id=\"rlink\"",
-                            &format!("text id=\"rlink\" fill=\"#{}\"", &text_color),
-                        );
+    match text.content {
+        TextContent::Title {
+            max_length,
+            line_height,
+        } => (move || {
+            let preview_title = format!("{} Preview", brand().0);
+            match split_title(&preview_title, max_length) {
+                Some((title_1, title_2)) => view! {
+                    <text fill=fill font-size=font_size x=x y=y - line_height>
+                        {title_1}
+                    </text>
+                    <text fill=fill font-size=font_size x=x y=y>
+                        {title_2}
+                    </text>
+                }
+                .into_any(),
+                None => view! {
+                    <text fill=fill font-size=font_size x=x y=y>
+                        {preview_title}
+                    </text>
                 }
-                badge_svg
+                .into_any(),
             }
-        />
+        })
+        .into_any(),
+        TextContent::Filename => view! {
+            <text fill=fill font-size=font_size x=x y=y>
+                {move || format!("{}.svg", sdk::title_to_slug(&brand().0))}
+            </text>
+        }
+        .into_any(),
+        TextContent::Brand => view! {
+            <text fill=fill font-size=font_size x=x y=y>
+                {move || format!("Brand: {}", brand().0)}
+            </text>
+        }
+        .into_any(),
+        TextContent::Color => view! {
+            <text fill=fill font-size=font_size x=x y=y>
+                {move || format!("Color: #{}", color())}
+            </text>
+        }
+        .into_any(),
+        TextContent::Footer => {
+            let scale = font_size as f64 / 12.0;
+            view! {
+                <g
+                    transform=format!("translate({x}, {y}) scale({scale})")
+                    style="font-family: Helvetica"
+                >
+                    <svg viewBox="0 0 24 24" width="24" height="24">
+                        <path d=DEFAULT_INITIAL_PATH.to_string() fill=fill></path>
+                    </svg>
+                    <text fill=fill x="30" y="7" font-size="12">
+                        {format!("{} SVG brand icons", get_number_of_icons!())}
+                    </text>
+                    <text fill=fill x="30" y="25" font-size="12">
+                        available at simpleicons.org
+                    </text>
+                </g>
+            }
+            .into_any()
+        }
     }
 }
@@ -0,0 +1,501 @@
+//! Templates of the preview cards
+//!
+//! Each template is defined as data: the size of the card and where its
+//! backgrounds, icons, texts and badges are placed.
+
+use leptos::prelude::*;
+use leptos_fluent::move_tr;
+use simple_icons_website_ids::Ids;
+use simple_icons_website_url as Url;
+use std::str::FromStr;
+
+/// Template of the preview card
+#[derive(Default, Copy, Clone, PartialEq)]
+pub(crate) enum PreviewTemplate {
+    /// Card attached to pull requests of the Simple Icons repository
+    #[default]
+    PullRequest,
+    /// Open Graph image of 1200x630
+    OpenGraph,
+    /// Square image for social networks
+    Square,
+    /// Icon over dark and light backgrounds side by side
+    DarkLight,
+}
+
+impl PreviewTemplate {
+    /// All the templates, in the order they are displayed
+    pub(crate) const ALL: [Self; 4] = [
+        Self::PullRequest,
+        Self::OpenGraph,
+        Self::Square,
+        Self::DarkLight,
+    ];
+
+    /// Layout of the card of the template
+    pub(crate) fn layout(&self) -> &'static TemplateLayout {
+        match self {
+            Self::PullRequest => &PULL_REQUEST_LAYOUT,
+            Self::OpenGraph => &OPEN_GRAPH_LAYOUT,
+            Self::Square => &SQUARE_LAYOUT,
+            Self::DarkLight => &DARK_LIGHT_LAYOUT,
+        }
+    }
+
+    /// Name of the image of the card for a brand slug
+    pub(crate) fn filename(&self, slug: &str) -> String {
+        match self {
+            Self::PullRequest => format!("{slug}.png"),
+            template => format!("{slug}-{template}.png"),
+        }
+    }
+}
+
+impl FromStr for PreviewTemplate {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "pull-request" => Ok(Self::PullRequest),
+            "open-graph" => Ok(Self::OpenGraph),
+            "square" => Ok(Self::Square),
+            "dark-light" => Ok(Self::DarkLight),
+            _ => Err(()),
+        }
+    }
+}
+
+impl core::fmt::Display for PreviewTemplate {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::PullRequest => write!(f, "pull-request"),
+            Self::OpenGraph => write!(f, "open-graph"),
+            Self::Square => write!(f, "square"),
+            Self::DarkLight => write!(f, "dark-light"),
+        }
+    }
+}
+
+pub(crate) fn initial_template() -> PreviewTemplate {
+    Url::params::get(&Url::params::Names::PreviewTemplate)
+        .and_then(|value| value.parse().ok())
+        .unwrap_or_default()
+}
+
+/// Color of an element of a card
+#[derive(Copy, Clone)]
+pub(crate) enum Paint {
+    /// Color of the brand
+    Brand,
+    /// Black or white, whichever contrasts with the color of the brand
+    Contrast,
+    /// Fixed hex color
+    Hex(&'static str),
+}
+
+impl Paint {
+    /// Value of the `fill` attribute of an element painted
+    pub(crate) fn fill(
+        &self,
+        brand_color: &str,
+        contrast_color: &str,
+    ) -> String {
+        match self {
+            Self::Brand => format!("#{brand_color}"),
+            Self::Contrast => contrast_color.to_string(),
+            Self::Hex(hex) => format!("#{hex}"),
+        }
+    }
+}
+
+/// Rectangle drawn behind the rest of the elements
+pub(crate) struct Background {
+    pub x: u32,
+    pub y: u32,
+    pub width: u32,
+    pub height: u32,
+    /// Radius of the corners
+    pub radius: u32,
+    pub paint: Paint,
+}
+
+/// Icon of the brand
+pub(crate) struct IconPlacement {
+    pub x: u32,
+    pub y: u32,
+    pub size: u32,
+    pub paint: Paint,
+}
+
+/// What a text slot displays
+#[derive(Copy, Clone)]
+pub(crate) enum TextContent {
+    /// "{brand} Preview", split in two lines if longer than `max_length`
+    Title {
+        max_length: usize,
+        /// Distance from the first line to the second one
+        line_height: i32,
+    },
+    /// Filename of the icon
+    Filename,
+    /// Name of the brand
+    Brand,
+    /// Hex color of the brand
+    Color,
+    /// Simple Icons logo with the number of icons available
+    Footer,
+}
+
+/// Text of a card, relative to the origin of the texts
+pub(crate) struct TextSlot {
+    pub content: TextContent,
+    pub x: i32,
+    pub y: i32,
+    /// Font size in pixels, the footer is scaled from its 12 pixels
+    pub font_size: u32,
+    pub paint: Paint,
+}
+
+/// Texts of a card, grouped at the same origin
+pub(crate) struct TextsPlacement {
+    pub x: u32,
+    pub y: u32,
+    pub slots: &'static [TextSlot],
+}
+
+/// Badges made with the icon in different styles
+#[derive(Copy, Clone)]
+pub(crate) struct BadgesPlacement {
+    pub x: u32,
+    pub y: u32,
+    /// Available width, the badges are scaled down to fit in it
+    pub width: f64,
+    /// Maximum scale of the badges
+    pub scale: f64,
+}
+
+/// Layout of the card of a template
+pub(crate) struct TemplateLayout {
+    pub width: u32,
+    pub height: u32,
+    pub backgrounds: &'static [Background],
+    pub icons: &'static [IconPlacement],
+    pub texts: TextsPlacement,
+    pub badges: Option<BadgesPlacement>,
+}
+
+static PULL_REQUEST_LAYOUT: TemplateLayout = TemplateLayout {
+    width: 740,
+    height: 520,
+    backgrounds: &[Background {
+        x: 0,
+        y: 0,
+        width: 740,
+        height: 420,
+        radius: 10,
+        paint: Paint::Brand,
+    }],
+    icons: &[
+        IconPlacement {
+            x: 18,
+            y: 20,
+            size: 24,
+            paint: Paint::Contrast,
+        },
+        IconPlacement {
+            x: 70,
+            y: 20,
+            size: 80,
+            paint: Paint::Contrast,
+        },
+        IconPlacement {
+            x: 174,
+            y: 20,
+            size: 138,
+            paint: Paint::Contrast,
+        },
+        IconPlacement {
+            x: 350,
+            y: 20,
+            size: 375,
+            paint: Paint::Contrast,
+        },
+    ],
+    texts: TextsPlacement {
+        x: 21,
+        y: 235,
+        slots: &[
+            TextSlot {
+                content: TextContent::Title {
+                    max_length: 24,
+                    line_height: 31,
+                },
+                x: 0,
+                y: 0,
+                font_size: 25,
+                paint: Paint::Contrast,
+            },
+            TextSlot {
+                content: TextContent::Filename,
+                x: 0,
+                y: 25,
+                font_size: 17,
+                paint: Paint::Contrast,
+            },
+            TextSlot {
+                content: TextContent::Brand,
+                x: 0,
+                y: 61,
+                font_size: 16,
+                paint: Paint::Contrast,
+            },
+            TextSlot {
+                content: TextContent::Color,
+                x: 0,
+                y: 84,
+                font_size: 16,
+                paint: Paint::Contrast,
+            },
+            TextSlot {
+                content: TextContent::Footer,
+                x: 3,
+                y: 142,
+                font_size: 12,
+                paint: Paint::Contrast,
+            },
+        ],
+    },
+    badges: Some(BadgesPlacement {
+        x: 10,
+        y: 437,
+        width: 720.0,
+        scale: 1.03,
+    }),
+};
+
+static OPEN_GRAPH_LAYOUT: TemplateLayout = TemplateLayout {
+    width: 1200,
+    height: 630,
+    backgrounds: &[Background {
+        x: 0,
+        y: 0,
+        width: 1200,
+        height: 630,
+        radius: 0,
+        paint: Paint::Brand,
+    }],
+    icons: &[
+        IconPlacement {
+            x: 80,
+            y: 80,
+            size: 48,
+            paint: Paint::Contrast,
+        },
+        IconPlacement {
+            x: 700,
+            y: 105,
+            size: 420,
+            paint: Paint::Contrast,
+        },
+    ],
+    texts: TextsPlacement {
+        x: 80,
+        y: 330,
+        slots: &[
+            TextSlot {
+                content: TextContent::Title {
+                    max_length: 22,
+                    line_height: 56,
+                },
+                x: 0,
+                y: 0,
+                font_size: 48,
+                paint: Paint::Contrast,
+            },
+            TextSlot {
+                content: TextContent::Filename,
+                x: 0,
+                y: 48,
+                font_size: 28,
+                paint: Paint::Contrast,
+            },
+            TextSlot {
+                content: TextContent::Footer,
+                x: 3,
+                y: 190,
+                font_size: 20,
+                paint: Paint::Contrast,
+            },
+        ],
+    },
+    badges: None,
+};
+
+static SQUARE_LAYOUT: TemplateLayout = TemplateLayout {
+    width: 1080,
+    height: 1080,
+    backgrounds: &[Background {
+        x: 0,
+        y: 0,
+        width: 1080,
+        height: 1080,
+        radius: 0,
+        paint: Paint::Brand,
+    }],
+    icons: &[IconPlacement {
+        x: 240,
+        y: 140,
+        size: 600,
+        paint: Paint::Contrast,
+    }],
+    texts: TextsPlacement {
+        x: 80,
+        y: 880,
+        slots: &[
+            TextSlot {
+                content: TextContent::Title {
+                    max_length: 30,
+                    line_height: 56,
+                },
+                x: 0,
+                y: 0,
+                font_size: 48,
+                paint: Paint::Contrast,
+            },
+            TextSlot {
+                content: TextContent::Filename,
+                x: 0,
+                y: 48,
+                font_size: 28,
+                paint: Paint::Contrast,
+            },
+            TextSlot {
+                content: TextContent::Footer,
+                x: 3,
+                y: 110,
+                font_size: 20,
+                paint: Paint::Contrast,
+            },
+        ],
+    },
+    badges: None,
+};
+
+static DARK_LIGHT_LAYOUT: TemplateLayout = TemplateLayout {
+    width: 1200,
+    height: 630,
+    backgrounds: &[
+        Background {
+            x: 0,
+            y: 0,
+            width: 600,
+            height: 630,
+            radius: 0,
+            paint: Paint::Hex("FFFFFF"),
+        },
+        Background {
+            x: 600,
+            y: 0,
+            width: 600,
+            height: 630,
+            radius: 0,
+            paint: Paint::Hex("111111"),
+        },
+    ],
+    icons: &[
+        IconPlacement {
+            x: 40,
+            y: 40,
+            size: 48,
+            paint: Paint::Hex("111111"),
+        },
+        IconPlacement {
+            x: 1112,
+            y: 40,
+            size: 48,
+            paint: Paint::Hex("FFFFFF"),
+        },
+        IconPlacement {
+            x: 140,
+            y: 120,
+            size: 320,
+            paint: Paint::Brand,
+        },
+        IconPlacement {
+            x: 740,
+            y: 120,
+            size: 320,
+            paint: Paint::Brand,
+        },
+    ],
+    texts: TextsPlacement {
+        x: 40,
+        y: 560,
+        slots: &[
+            TextSlot {
+                content: TextContent::Title {
+                    max_length: 28,
+                    line_height: 34,
+                },
+                x: 0,
+                y: 0,
+                font_size: 28,
+                paint: Paint::Hex("111111"),
+            },
+            TextSlot {
+                content: TextContent::Color,
+                x: 0,
+                y: 36,
+                font_size: 18,
+                paint: Paint::Hex("111111"),
+            },
+            TextSlot {
+                content: TextContent::Footer,
+                x: 600,
+                y: 10,
+                font_size: 16,
+                paint: Paint::Hex("FFFFFF"),
+            },
+        ],
+    },
+    badges: None,
+};
+
+#[component]
+pub(crate) fn TemplateInput(
+    template: RwSignal<PreviewTemplate>,
+) -> impl IntoView {
+    let label = move |template: PreviewTemplate| match template {
+        PreviewTemplate::PullRequest => move_tr!("pull-request-card"),
+        PreviewTemplate::OpenGraph => move_tr!("open-graph-card"),
+        PreviewTemplate::Square => move_tr!("square-card"),
+        PreviewTemplate::DarkLight => move_tr!("dark-light-card"),
+    };
+
+    view! {
+        <div class="preview-input-group">
+            <label for="preview-template">{move_tr!("template")}</label>
+            <select
+                id=Ids::PreviewTemplate
+                on:change=move |ev| {
+                    let value = event_target_value(&ev);
+                    if let Ok(new_template) = value.parse() {
+                        template.set(new_template);
+                        Url::params::update(&Url::params::Names::PreviewTemplate, &value);
+                    }
+                }
+            >
+                {PreviewTemplate::ALL
+                    .into_iter()
+                    .map(|option| {
+                        view! {
+                            <option value=option.to_string() selected=move || template() == option>
+                                {label(option)}
+                            </option>
+                        }
+                    })
+                    .collect_view()}
+            </select>
+        </div>
+    }
+}
@@ -7,6 +7,10 @@ pub mod LocalStorage {
         ColorScheme,
         Layout,
         Language,
+        Favorites,
+        Filters,
+        PreviewMode,
+        ExportOptions,
     }
 
     impl Keys {
@@ -18,6 +22,10 @@ pub mod LocalStorage {
                 Keys::ColorScheme => "color-scheme",
                 Keys::Layout => "layout",
                 Keys::Language => "language",
+                Keys::Favorites => "favorites",
+                Keys::Filters => "filters",
+                Keys::PreviewMode => "preview-mode",
+                Keys::ExportOptions => "export-options",
             }
         }
     }
@@ -41,4 +49,14 @@ pub mod LocalStorage {
             .set_item(key.as_str(), value)
             .unwrap()
     }
+
+    pub fn remove(key: Keys) {
+        web_sys::window()
+            .unwrap()
+            .local_storage()
+            .unwrap()
+            .unwrap()
+            .remove_item(key.as_str())
+            .unwrap()
+    }
 }
@@ -22,6 +22,10 @@ pub enum SVGDef {
     Save,
     /// Copy icon
     Copy,
+    /// Undo icon
+    Undo,
+    /// Redo icon
+    Redo,
 }
 
 impl SVGDef {
@@ -35,6 +39,8 @@ impl SVGDef {
             Self::Upload => Ids::UploadSvgPath.as_str(),
             Self::Save => Ids::SaveSvgPath.as_str(),
             Self::Copy => Ids::CopySvgPath.as_str(),
+            Self::Undo => Ids::UndoSvgPath.as_str(),
+            Self::Redo => Ids::RedoSvgPath.as_str(),
         }
     }
 
@@ -60,6 +66,12 @@ impl SVGDef {
             Self::Copy => {
                 "M18,2H9C7.9,2,7,2.9,7,4v12c0,1.1,0.9,2,2,2h9c1.1,0,2-0.9,2-2V4C20,2.9,19.1,2,18,2z M18,16H9V4h9V16z M3,15v-2h2v2H3z M3,9.5h2v2H3V9.5z M10,20h2v2h-2V20z M3,18.5v-2h2v2H3z M5,22c-1.1,0-2-0.9-2-2h2V22z M8.5,22h-2v-2h2V22z M13.5,22L13.5,22l0-2h2 v0C15.5,21.1,14.6,22,13.5,22z M5,6L5,6l0,2H3v0C3,6.9,3.9,6,5,6z"
             }
+            Self::Undo => {
+                "M12.5 8c-2.65 0-5.05.99-6.9 2.6L2 7v9h9l-3.62-3.62c1.39-1.16 3.16-1.88 5.12-1.88 3.54 0 6.55 2.31 7.6 5.5l2.37-.78C21.08 11.03 17.15 8 12.5 8z"
+            }
+            Self::Redo => {
+                "M18.4 10.6C16.55 8.99 14.15 8 11.5 8c-4.65 0-8.58 3.03-9.96 7.22L3.9 16c1.05-3.19 4.05-5.5 7.6-5.5 1.95 0 3.73.72 5.12 1.88L13 16h9V7l-3.6 3.6z"
+            }
         }
     }
 }
@@ -91,6 +103,8 @@ pub fn SVGDefsDefinition() -> impl IntoView {
                     <path id=SVGDef::Upload.id() d=SVGDef::Upload.d()></path>
                     <path id=SVGDef::Save.id() d=SVGDef::Save.d()></path>
                     <path id=SVGDef::Copy.id() d=SVGDef::Copy.d()></path>
+                    <path id=SVGDef::Undo.id() d=SVGDef::Undo.d()></path>
+                    <path id=SVGDef::Redo.id() d=SVGDef::Redo.d()></path>
                 </defs>
             </svg>
         }
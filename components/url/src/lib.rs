@@ -11,6 +11,12 @@ pub mod params {
         ColorScheme,
         Modal,
         OrderMode,
+        Collection,
+        Filters,
+        PreviewMode,
+        PreviewTemplate,
+        PreviewComparison,
+        Icon,
     }
 
     impl Names {
@@ -23,6 +29,12 @@ pub mod params {
                 Self::ColorScheme => "color-scheme",
                 Self::Modal => "modal",
                 Self::OrderMode => "order",
+                Self::Collection => "collection",
+                Self::Filters => "filters",
+                Self::PreviewMode => "preview",
+                Self::PreviewTemplate => "template",
+                Self::PreviewComparison => "compare",
+                Self::Icon => "icon",
             }
         }
     }
@@ -1,2 +0,0 @@
-/* eslint-disable unicorn/prefer-module */
-globalThis.makeBadgeWithBadgeMaker = require('badge-maker').makeBadge;
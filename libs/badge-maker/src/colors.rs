@@ -0,0 +1,84 @@
+//! Colors of the badges
+
+/// Color of the message of the badges without a valid color
+pub(crate) const DEFAULT_COLOR: &str = "#4c1";
+
+/// Color of the label of the badges
+pub(crate) const DEFAULT_LABEL_COLOR: &str = "#555";
+
+/// Colors named by shields.io, with their aliases
+static NAMED_COLORS: [(&str, &str); 16] = [
+    ("brightgreen", "#4c1"),
+    ("green", "#97ca00"),
+    ("yellow", "#dfb317"),
+    ("yellowgreen", "#a4a61d"),
+    ("orange", "#fe7d37"),
+    ("red", "#e05d44"),
+    ("blue", "#007ec6"),
+    ("grey", "#555"),
+    ("gray", "#555"),
+    ("lightgrey", "#9f9f9f"),
+    ("lightgray", "#9f9f9f"),
+    ("critical", "#e05d44"),
+    ("important", "#fe7d37"),
+    ("success", "#4c1"),
+    ("informational", "#007ec6"),
+    ("inactive", "#9f9f9f"),
+];
+
+fn is_hex(value: &str) -> bool {
+    matches!(value.len(), 3 | 6)
+        && value.chars().all(|ch| ch.is_ascii_hexdigit())
+}
+
+/// Convert a color to the value used in the SVG of a badge
+///
+/// Accepts the named colors of shields.io and hex colors of 3 or 6 digits,
+/// with or without `#`. Any other value is replaced by the default color.
+pub(crate) fn to_svg_color(color: &str) -> String {
+    let color = color.trim();
+    if let Some((_, hex)) = NAMED_COLORS
+        .iter()
+        .find(|(name, _)| name.eq_ignore_ascii_case(color))
+    {
+        return hex.to_string();
+    }
+    match color.strip_prefix('#') {
+        Some(hex) if is_hex(hex) => format!("#{}", hex.to_ascii_lowercase()),
+        None if is_hex(color) => format!("#{}", color.to_ascii_lowercase()),
+        _ => DEFAULT_COLOR.to_string(),
+    }
+}
+
+/// Colors of a text and its shadow drawn over a background
+pub(crate) struct TextColors {
+    pub text: &'static str,
+    pub shadow: &'static str,
+}
+
+/// Colors of the texts over a SVG color, dark over bright backgrounds
+pub(crate) fn text_colors(svg_color: &str) -> TextColors {
+    let hex = svg_color.trim_start_matches('#');
+    let channel = |index: usize| -> f64 {
+        let value = match hex.len() {
+            3 => hex.get(index..index + 1).map(|digit| digit.repeat(2)),
+            _ => hex.get(index * 2..index * 2 + 2).map(str::to_string),
+        };
+        value
+            .and_then(|value| u8::from_str_radix(&value, 16).ok())
+            .unwrap_or_default() as f64
+    };
+    let brightness =
+        (channel(0) * 299.0 + channel(1) * 587.0 + channel(2) * 114.0)
+            / 255000.0;
+    match brightness <= 0.69 {
+        true => TextColors {
+            text: "#fff",
+            shadow: "#010101",
+        },
+        false => TextColors {
+            text: "#333",
+            shadow: "#ccc",
+        },
+    }
+}
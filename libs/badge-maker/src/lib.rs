@@ -1,12 +1,447 @@
-use wasm_bindgen::prelude::*;
-
-#[wasm_bindgen(module = "/src/lib.js")]
-extern "C" {
-    pub fn make_badge(
-        label: &str,
-        message: &str,
-        color: &str,
-        style: &str,
-        logo_base_64: &str,
-    ) -> String;
+//! Badges of shields.io rendered as SVG
+//!
+//! Port of the renderers of the `badge-maker` npm package, producing the
+//! same markup for the flat, flat square, plastic, for the badge and
+//! social styles. Texts are measured with embedded tables of the widths
+//! of the characters of the fonts used by shields.io, so the badges don't
+//! need a browser to be laid out.
+
+mod colors;
+mod widths;
+
+use colors::{DEFAULT_LABEL_COLOR, TextColors, text_colors, to_svg_color};
+use icon_format::escape_xml;
+use widths::{Font, text_width};
+
+/// Font family of the texts of the badges, except the social ones
+const FONT_FAMILY: &str = "Verdana,Geneva,DejaVu Sans,sans-serif";
+
+/// Side of the logos
+const LOGO_SIZE: f64 = 14.0;
+
+/// Space between the logo and the label
+const LOGO_PADDING: f64 = 3.0;
+
+/// Styles of the badges
+#[derive(Copy, Clone, PartialEq)]
+enum Style {
+    Flat,
+    FlatSquare,
+    Plastic,
+    ForTheBadge,
+    Social,
+}
+
+impl Style {
+    /// Parse a style, rendering unknown ones as flat
+    fn parse(style: &str) -> Self {
+        match style {
+            "flat-square" => Self::FlatSquare,
+            "plastic" => Self::Plastic,
+            "for-the-badge" => Self::ForTheBadge,
+            "social" => Self::Social,
+            _ => Self::Flat,
+        }
+    }
+}
+
+/// Parameters of a badge, already normalized
+struct Params<'a> {
+    label: &'a str,
+    message: &'a str,
+    /// Data URL of the logo, empty without logo
+    logo: &'a str,
+    color: String,
+    label_color: String,
+}
+
+impl Params<'_> {
+    fn has_logo(&self) -> bool {
+        !self.logo.is_empty()
+    }
+
+    fn logo_width(&self) -> f64 {
+        match self.has_logo() {
+            true => LOGO_SIZE,
+            false => 0.0,
+        }
+    }
+
+    fn logo_padding(&self) -> f64 {
+        match self.has_logo() && !self.label.is_empty() {
+            true => LOGO_PADDING,
+            false => 0.0,
+        }
+    }
+}
+
+/// Make the SVG of a badge
+///
+/// `style` is one of `flat`, `flat-square`, `plastic`, `for-the-badge` or
+/// `social`, unknown styles are rendered as flat. `color` can be a named
+/// color of shields.io or a hex color. `logo_base_64` is the data URL of
+/// the logo, an empty string renders the badge without logo.
+pub fn make_badge(
+    label: &str,
+    message: &str,
+    color: &str,
+    style: &str,
+    logo_base_64: &str,
+) -> String {
+    let label = label.trim();
+    let color = to_svg_color(color);
+    let label_color = match !label.is_empty() || !logo_base_64.is_empty() {
+        true => DEFAULT_LABEL_COLOR.to_string(),
+        false => color.clone(),
+    };
+    let params = Params {
+        label,
+        message: message.trim(),
+        logo: logo_base_64,
+        color,
+        label_color,
+    };
+    match Style::parse(style) {
+        Style::ForTheBadge => for_the_badge(&params),
+        Style::Social => social(&params),
+        style => basic(&params, style),
+    }
+}
+
+/// Round a width up to an odd number, increasing the chances of pixel
+/// grid alignment of the centered texts
+fn round_up_to_odd(value: f64) -> f64 {
+    match value % 2.0 == 0.0 {
+        true => value + 1.0,
+        false => value,
+    }
+}
+
+/// Width reserved for a text
+fn preferred_width_of(text: &str, font: Font, size: f64) -> f64 {
+    round_up_to_odd(text_width(text, font, size).trunc())
+}
+
+fn accessible_text(label: &str, message: &str) -> String {
+    match label.is_empty() {
+        true => message.to_string(),
+        false => format!("{label}: {message}"),
+    }
+}
+
+/// Root element of a badge with its content
+fn render_badge(
+    width: f64,
+    height: f64,
+    accessible_text: &str,
+    content: &str,
+) -> String {
+    let title = escape_xml(accessible_text);
+    format!(
+        r#"<svg xmlns="http://www.w3.org/2000/svg" width="{width}" height="{height}" role="img" aria-label="{title}"><title>{title}</title>{content}</svg>"#
+    )
+}
+
+fn render_logo(logo: &str, x: f64, y: f64) -> String {
+    match logo.is_empty() {
+        true => String::new(),
+        false => format!(
+            r#"<image x="{x}" y="{y}" width="{LOGO_SIZE}" height="{LOGO_SIZE}" href="{}"/>"#,
+            escape_xml(logo)
+        ),
+    }
+}
+
+/// Text of the flat, flat square and plastic badges with its width
+fn render_basic_text(
+    content: &str,
+    left_margin: f64,
+    vertical_margin: f64,
+    shadow: bool,
+    colors: &TextColors,
+) -> (String, f64) {
+    if content.is_empty() {
+        return (String::new(), 0.0);
+    }
+    let text_length = preferred_width_of(content, Font::Verdana, 11.0);
+    let escaped = escape_xml(content);
+    let x = 10.0 * (left_margin + 0.5 * text_length + 5.0);
+    let out_text_length = 10.0 * text_length;
+    let shadow_text = match shadow {
+        true => format!(
+            r#"<text aria-hidden="true" x="{x}" y="{}" fill="{}" fill-opacity=".3" transform="scale(.1)" textLength="{out_text_length}">{escaped}</text>"#,
+            150.0 + vertical_margin,
+            colors.shadow,
+        ),
+        false => String::new(),
+    };
+    let text = format!(
+        r#"<text x="{x}" y="{}" transform="scale(.1)" fill="{}" textLength="{out_text_length}">{escaped}</text>"#,
+        140.0 + vertical_margin,
+        colors.text,
+    );
+    (shadow_text + &text, text_length)
+}
+
+/// Flat, flat square and plastic badges, which share their layout
+fn basic(params: &Params, style: Style) -> String {
+    let (height, vertical_margin, shadow) = match style {
+        Style::Plastic => (18.0, -10.0, true),
+        Style::FlatSquare => (20.0, 0.0, false),
+        _ => (20.0, 0.0, true),
+    };
+    let horiz_padding = 5.0;
+    let has_label = !params.label.is_empty();
+    let has_message = !params.message.is_empty();
+    let total_logo_width = params.logo_width() + params.logo_padding();
+    let rendered_logo =
+        render_logo(params.logo, horiz_padding, (height - LOGO_SIZE) / 2.0);
+
+    let (rendered_label, label_width) = render_basic_text(
+        params.label,
+        total_logo_width + 1.0,
+        vertical_margin,
+        shadow,
+        &text_colors(&params.label_color),
+    );
+    let left_width = match has_label {
+        true => label_width + 2.0 * horiz_padding + total_logo_width,
+        false => 0.0,
+    };
+
+    let mut message_margin = left_width - if has_message { 1.0 } else { 0.0 };
+    if !has_label {
+        message_margin += match params.has_logo() {
+            true => total_logo_width + horiz_padding,
+            false => 1.0,
+        };
+    }
+    let (rendered_message, message_width) = render_basic_text(
+        params.message,
+        message_margin,
+        vertical_margin,
+        shadow,
+        &text_colors(&params.color),
+    );
+    let mut right_width = message_width + 2.0 * horiz_padding;
+    if params.has_logo() && !has_label {
+        right_width += total_logo_width
+            + if has_message {
+                horiz_padding - 1.0
+            } else {
+                0.0
+            };
+    }
+
+    let width = left_width + right_width;
+    let rects = format!(
+        r#"<rect width="{left_width}" height="{height}" fill="{}"/><rect x="{left_width}" width="{right_width}" height="{height}" fill="{}"/>"#,
+        params.label_color, params.color,
+    );
+    let background = match style {
+        Style::FlatSquare => {
+            format!(r#"<g shape-rendering="crispEdges">{rects}</g>"#)
+        }
+        _ => {
+            let (stops, radius) = match style {
+                Style::Plastic => (
+                    r##"<stop offset="0" stop-color="#fff" stop-opacity=".7"/><stop offset=".1" stop-color="#aaa" stop-opacity=".1"/><stop offset=".9" stop-color="#000" stop-opacity=".3"/><stop offset="1" stop-color="#000" stop-opacity=".5"/>"##,
+                    4,
+                ),
+                _ => (
+                    r##"<stop offset="0" stop-color="#bbb" stop-opacity=".1"/><stop offset="1" stop-opacity=".1"/>"##,
+                    3,
+                ),
+            };
+            format!(
+                r##"<linearGradient id="s" x2="0" y2="100%">{stops}</linearGradient><clipPath id="r"><rect width="{width}" height="{height}" rx="{radius}" fill="#fff"/></clipPath><g clip-path="url(#r)">{rects}<rect width="{width}" height="{height}" fill="url(#s)"/></g>"##
+            )
+        }
+    };
+    let foreground = format!(
+        r##"<g fill="#fff" text-anchor="middle" font-family="{FONT_FAMILY}" text-rendering="geometricPrecision" font-size="110">{rendered_logo}{rendered_label}{rendered_message}</g>"##
+    );
+
+    render_badge(
+        width,
+        height,
+        &accessible_text(params.label, params.message),
+        &(background + &foreground),
+    )
+}
+
+/// Badges with uppercase texts, bigger and with spaced letters
+fn for_the_badge(params: &Params) -> String {
+    const HEIGHT: f64 = 28.0;
+    const TEXT_MARGIN: f64 = 12.0;
+    const LOGO_MARGIN: f64 = 9.0;
+    const LOGO_TEXT_GUTTER: f64 = 6.0;
+    const LETTER_SPACING: f64 = 1.25;
+
+    let label = params.label.to_uppercase();
+    let message = params.message.to_uppercase();
+    let text_width_of = |text: &str, font: Font| match text.is_empty() {
+        true => 0.0,
+        false => {
+            text_width(text, font, 10.0).trunc()
+                + LETTER_SPACING * text.chars().count() as f64
+        }
+    };
+    let label_text_width = text_width_of(&label, Font::Verdana);
+    let message_text_width = text_width_of(&message, Font::VerdanaBold);
+
+    let text_min_x = match params.has_logo() {
+        true => LOGO_MARGIN + params.logo_width() + LOGO_TEXT_GUTTER,
+        false => TEXT_MARGIN,
+    };
+    let (label_text_min_x, label_rect_width, message_text_min_x) = match label
+        .is_empty()
+    {
+        true => (0.0, 0.0, text_min_x),
+        false => {
+            let label_rect_width = text_min_x + label_text_width + TEXT_MARGIN;
+            (text_min_x, label_rect_width, label_rect_width + TEXT_MARGIN)
+        }
+    };
+    let message_rect_width = match label.is_empty() {
+        true => message_text_min_x + message_text_width + TEXT_MARGIN,
+        false => 2.0 * TEXT_MARGIN + message_text_width,
+    };
+
+    let render_text = |text: &str,
+                       min_x: f64,
+                       width: f64,
+                       color: &str,
+                       weight: &str| {
+        format!(
+            r#"<text transform="scale(.1)" x="{}" y="175" textLength="{}" fill="{}"{weight}>{}</text>"#,
+            10.0 * (min_x + 0.5 * width),
+            10.0 * width,
+            text_colors(color).text,
+            escape_xml(text),
+        )
+    };
+    let rendered_label = match label.is_empty() {
+        true => String::new(),
+        false => render_text(
+            &label,
+            label_text_min_x,
+            label_text_width,
+            &params.label_color,
+            "",
+        ),
+    };
+    let rendered_message = match message.is_empty() {
+        true => String::new(),
+        false => render_text(
+            &message,
+            message_text_min_x,
+            message_text_width,
+            &params.color,
+            r#" font-weight="bold""#,
+        ),
+    };
+
+    let label_rect = match label.is_empty() {
+        true => String::new(),
+        false => format!(
+            r#"<rect width="{label_rect_width}" height="{HEIGHT}" fill="{}"/>"#,
+            params.label_color
+        ),
+    };
+    let background = format!(
+        r#"<g shape-rendering="crispEdges">{label_rect}<rect x="{label_rect_width}" width="{message_rect_width}" height="{HEIGHT}" fill="{}"/></g>"#,
+        params.color
+    );
+    let foreground = format!(
+        r##"<g fill="#fff" text-anchor="middle" font-family="{FONT_FAMILY}" text-rendering="geometricPrecision" font-size="100">{}{rendered_label}{rendered_message}</g>"##,
+        render_logo(params.logo, LOGO_MARGIN, (HEIGHT - LOGO_SIZE) / 2.0),
+    );
+
+    render_badge(
+        label_rect_width + message_rect_width,
+        HEIGHT,
+        &accessible_text(&label, &message),
+        &(background + &foreground),
+    )
+}
+
+/// Badges that look like the buttons of GitHub, ignoring the colors
+fn social(params: &Params) -> String {
+    const EXTERNAL_HEIGHT: f64 = 20.0;
+    const INTERNAL_HEIGHT: f64 = 19.0;
+    const LABEL_HORIZ_PADDING: f64 = 5.0;
+    const MESSAGE_HORIZ_PADDING: f64 = 4.0;
+    const HORIZ_GUTTER: f64 = 6.0;
+    const FONT_SIZE: f64 = 11.0;
+
+    let mut chars = params.label.chars();
+    let label = chars
+        .next()
+        .map(|first| first.to_uppercase().chain(chars).collect::<String>())
+        .unwrap_or_default();
+    let message = params.message;
+    let has_message = !message.is_empty();
+    let total_logo_width = params.logo_width() + params.logo_padding();
+
+    let label_text_width =
+        preferred_width_of(&label, Font::HelveticaBold, FONT_SIZE);
+    let message_text_width =
+        preferred_width_of(message, Font::HelveticaBold, FONT_SIZE);
+    let label_rect_width =
+        label_text_width + total_logo_width + 2.0 * LABEL_HORIZ_PADDING;
+    let message_rect_width = message_text_width + 2.0 * MESSAGE_HORIZ_PADDING;
+
+    let message_bubble = match has_message {
+        true => {
+            let main_x = label_rect_width + HORIZ_GUTTER + 0.5;
+            let notch_x = label_rect_width + HORIZ_GUTTER;
+            format!(
+                r##"<rect x="{main_x}" y="0.5" width="{message_rect_width}" height="{INTERNAL_HEIGHT}" rx="2" fill="#fafafa"/><rect x="{notch_x}" y="7.5" width="0.5" height="5" stroke="#fafafa"/><path d="M{main_x} 6.5 l-3 3v1 l3 3" stroke="d5d5d5" fill="#fafafa"/>"##
+            )
+        }
+        false => String::new(),
+    };
+
+    let escaped_label = escape_xml(&label);
+    let label_text_x = 10.0
+        * (total_logo_width + label_text_width / 2.0 + LABEL_HORIZ_PADDING);
+    let label_text_length = 10.0 * label_text_width;
+    let rendered_label = format!(
+        r##"<rect id="llink" stroke="#d5d5d5" fill="url(#a)" x=".5" y=".5" width="{label_rect_width}" height="{INTERNAL_HEIGHT}" rx="2"/><text aria-hidden="true" x="{label_text_x}" y="150" fill="#fff" transform="scale(.1)" textLength="{label_text_length}">{escaped_label}</text><text x="{label_text_x}" y="140" transform="scale(.1)" textLength="{label_text_length}">{escaped_label}</text>"##
+    );
+    let rendered_message = match has_message {
+        true => {
+            let escaped_message = escape_xml(message);
+            let message_text_x = 10.0
+                * (label_rect_width + HORIZ_GUTTER + message_rect_width / 2.0);
+            let message_text_length = 10.0 * message_text_width;
+            format!(
+                r##"<text aria-hidden="true" x="{message_text_x}" y="150" fill="#fff" transform="scale(.1)" textLength="{message_text_length}">{escaped_message}</text><text id="rlink" x="{message_text_x}" y="140" transform="scale(.1)" textLength="{message_text_length}">{escaped_message}</text>"##
+            )
+        }
+        false => String::new(),
+    };
+
+    let content = format!(
+        r##"<style>a:hover #llink{{fill:url(#b);stroke:#ccc}}a:hover #rlink{{fill:#4183c4}}</style><linearGradient id="a" x2="0" y2="100%"><stop offset="0" stop-color="#fcfcfc" stop-opacity="0"/><stop offset="1" stop-opacity=".1"/></linearGradient><linearGradient id="b" x2="0" y2="100%"><stop offset="0" stop-color="#ccc" stop-opacity=".1"/><stop offset="1" stop-opacity=".1"/></linearGradient><g stroke="#d5d5d5"><rect stroke="none" fill="#fcfcfc" x="0.5" y="0.5" width="{label_rect_width}" height="{INTERNAL_HEIGHT}" rx="2"/>{message_bubble}</g>{}<g aria-hidden="true" fill="#333" text-anchor="middle" font-family="Helvetica Neue,Helvetica,Arial,sans-serif" text-rendering="geometricPrecision" font-weight="700" font-size="110px" line-height="14px">{rendered_label}{rendered_message}</g>"##,
+        render_logo(
+            params.logo,
+            LABEL_HORIZ_PADDING,
+            (EXTERNAL_HEIGHT - LOGO_SIZE) / 2.0
+        ),
+    );
+
+    let width = label_rect_width
+        + 1.0
+        + match has_message {
+            true => HORIZ_GUTTER + message_rect_width,
+            false => 0.0,
+        };
+    render_badge(
+        width,
+        EXTERNAL_HEIGHT,
+        &accessible_text(&label, message),
+        &content,
+    )
 }
@@ -0,0 +1,81 @@
+//! Widths of the characters of the fonts used by the badges
+//!
+//! The tables contain the advance widths of the printable ASCII characters,
+//! from the space (U+0020) to the tilde (U+007E), in units of the em square
+//! of each font. Characters out of the tables are measured as an `m`.
+
+/// Fonts the texts of the badges are measured with
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub(crate) enum Font {
+    /// Verdana, used by the flat, flat square and plastic styles
+    Verdana,
+    /// Bold Verdana, used by the messages of the for the badge style. The
+    /// widths are the ones of DejaVu Sans Bold, its fallback font.
+    VerdanaBold,
+    /// Bold Helvetica, used by the social style
+    HelveticaBold,
+}
+
+/// Verdana in units of an em square of 2048
+static VERDANA: [u16; 95] = [
+    720, 821, 942, 1706, 1303, 2217, 1493, 548, 1018, 1018, 1303, 1706, 745,
+    924, 745, 1303, 1303, 1303, 1303, 1303, 1303, 1303, 1303, 1303, 1303, 1303,
+    889, 889, 1706, 1706, 1706, 1112, 2048, 1401, 1405, 1430, 1577, 1294, 1178,
+    1587, 1540, 866, 924, 1425, 1145, 1763, 1540, 1614, 1276, 1614, 1432, 1397,
+    1243, 1499, 1401, 2025, 1405, 1243, 1397, 1018, 1303, 1018, 1706, 1303,
+    1303, 1229, 1276, 1067, 1276, 1220, 720, 1276, 1296, 561, 677, 1205, 561,
+    1992, 1296, 1243, 1276, 1276, 874, 1064, 807, 1296, 1205, 1646, 1205, 1205,
+    1056, 1300, 922, 1300, 1706,
+];
+
+/// DejaVu Sans Bold in units of an em square of 2048
+static DEJAVU_SANS_BOLD: [u16; 95] = [
+    713, 934, 1067, 1716, 1425, 2052, 1786, 627, 936, 936, 1071, 1716, 778,
+    850, 778, 748, 1425, 1425, 1425, 1425, 1425, 1425, 1425, 1425, 1425, 1425,
+    819, 819, 1716, 1716, 1716, 1188, 2048, 1585, 1561, 1503, 1700, 1399, 1399,
+    1681, 1714, 762, 762, 1587, 1305, 2038, 1714, 1741, 1501, 1741, 1577, 1475,
+    1397, 1663, 1585, 2259, 1579, 1483, 1485, 936, 748, 936, 1716, 1024, 1024,
+    1382, 1466, 1214, 1466, 1389, 891, 1466, 1458, 702, 702, 1362, 702, 2134,
+    1458, 1407, 1466, 1466, 1010, 1219, 979, 1458, 1335, 1892, 1321, 1335,
+    1192, 1458, 748, 1458, 1716,
+];
+
+/// Helvetica Bold in units of an em square of 1000
+static HELVETICA_BOLD: [u16; 95] = [
+    278, 333, 474, 556, 556, 889, 722, 238, 333, 333, 389, 584, 278, 333, 278,
+    278, 556, 556, 556, 556, 556, 556, 556, 556, 556, 556, 333, 333, 584, 584,
+    584, 611, 975, 722, 722, 722, 722, 667, 611, 778, 722, 278, 556, 722, 611,
+    833, 722, 778, 667, 778, 722, 667, 611, 722, 667, 944, 667, 667, 611, 333,
+    278, 333, 584, 556, 333, 556, 611, 556, 611, 556, 333, 611, 611, 278, 278,
+    556, 278, 889, 611, 611, 611, 611, 389, 556, 333, 611, 556, 778, 556, 556,
+    500, 389, 280, 389, 584,
+];
+
+impl Font {
+    fn table(&self) -> (&'static [u16; 95], f64) {
+        match self {
+            Self::Verdana => (&VERDANA, 2048.0),
+            Self::VerdanaBold => (&DEJAVU_SANS_BOLD, 2048.0),
+            Self::HelveticaBold => (&HELVETICA_BOLD, 1000.0),
+        }
+    }
+}
+
+/// Width of a character in units of the em square of the font
+fn char_width(table: &[u16; 95], ch: char) -> u16 {
+    let index = match ch {
+        ' '..='~' => ch as usize - ' ' as usize,
+        _ => 'm' as usize - ' ' as usize,
+    };
+    table[index]
+}
+
+/// Width in pixels of a text rendered with a font at a size in pixels
+pub(crate) fn text_width(text: &str, font: Font, size: f64) -> f64 {
+    let (table, units_per_em) = font.table();
+    let units = text
+        .chars()
+        .map(|ch| char_width(table, ch) as f64)
+        .sum::<f64>();
+    units * size / units_per_em
+}
@@ -0,0 +1,136 @@
+//! Snapshot tests
+//!
+//! The badges rendered are compared with the SVG files of the `snapshots`
+//! directory. To regenerate them after an intended change in the
+//! rendering, run the tests with the `UPDATE_SNAPSHOTS` environment
+//! variable set.
+
+use badge_maker::make_badge;
+use std::path::PathBuf;
+
+/// Data URL of a square logo
+const LOGO: &str = "data:image/svg+xml;base64,PHN2ZyB4bWxucz0iaHR0cDovL3d3dy53My5vcmcvMjAwMC9zdmciIHZpZXdCb3g9IjAgMCAyNCAyNCI+PHBhdGggZD0iTTAgMGgyNHYyNEgweiIvPjwvc3ZnPg==";
+
+fn snapshot_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("snapshots")
+        .join(format!("{name}.svg"))
+}
+
+/// Compare a badge with its snapshot
+fn assert_snapshot(name: &str, svg: &str) {
+    let snapshot = snapshot_path(name);
+    if std::env::var_os("UPDATE_SNAPSHOTS").is_some() {
+        std::fs::write(&snapshot, svg).unwrap();
+    }
+    let expected = std::fs::read_to_string(&snapshot).unwrap();
+    assert_eq!(svg, expected, "{name}: different from its snapshot");
+}
+
+/// Value of an attribute of the first element that defines it
+fn attribute<'a>(svg: &'a str, name: &str) -> &'a str {
+    svg.split_once(&format!(" {name}=\""))
+        .and_then(|(_, rest)| rest.split_once('"'))
+        .map(|(value, _)| value)
+        .unwrap()
+}
+
+#[test]
+fn flat() {
+    assert_snapshot(
+        "flat",
+        &make_badge("build", "passing", "brightgreen", "flat", ""),
+    );
+}
+
+#[test]
+fn flat_with_logo() {
+    assert_snapshot(
+        "flat-logo",
+        &make_badge("simple icons", "preview", "5865F2", "flat", LOGO),
+    );
+}
+
+#[test]
+fn flat_square_without_label() {
+    assert_snapshot(
+        "flat-square-no-label",
+        &make_badge("", "preview", "#FFD700", "flat-square", LOGO),
+    );
+}
+
+#[test]
+fn plastic() {
+    assert_snapshot(
+        "plastic",
+        &make_badge("license", "MIT", "blue", "plastic", LOGO),
+    );
+}
+
+#[test]
+fn for_the_badge() {
+    assert_snapshot(
+        "for-the-badge",
+        &make_badge("simple icons", "preview", "ff0000", "for-the-badge", LOGO),
+    );
+}
+
+#[test]
+fn social() {
+    assert_snapshot(
+        "social",
+        &make_badge("", "preview", "5865F2", "social", LOGO),
+    );
+}
+
+#[test]
+fn flat_layout_matches_shields() {
+    let svg = make_badge("build", "passing", "brightgreen", "flat", "");
+    assert_eq!(attribute(&svg, "width"), "88");
+    assert_eq!(attribute(&svg, "height"), "20");
+    assert_eq!(attribute(&svg, "aria-label"), "build: passing");
+    assert!(svg.contains(
+        r##"<text x="195" y="140" transform="scale(.1)" fill="#fff" textLength="270">build</text>"##
+    ));
+    assert!(svg.contains(
+        r##"<text x="615" y="140" transform="scale(.1)" fill="#fff" textLength="410">passing</text>"##
+    ));
+}
+
+#[test]
+fn texts_are_escaped() {
+    let svg = make_badge("a<b", "\"c\" & 'd'", "red", "flat", "");
+    assert!(svg.contains(">a&lt;b</text>"));
+    assert!(svg.contains(">&quot;c&quot; &amp; &apos;d&apos;</text>"));
+}
+
+#[test]
+fn colors_are_normalized() {
+    for (color, expected) in [
+        ("critical", "#e05d44"),
+        ("ABC", "#abc"),
+        ("#5865F2", "#5865f2"),
+        ("not a color", "#4c1"),
+    ] {
+        let svg = make_badge("label", "message", color, "flat-square", "");
+        assert!(
+            svg.contains(&format!(r#"fill="{expected}"/></g>"#)),
+            "{color}: expected {expected}",
+        );
+    }
+}
+
+#[test]
+fn dark_text_over_bright_colors() {
+    let svg = make_badge("label", "message", "ffd700", "flat", "");
+    assert!(svg.contains(r##"fill="#333" textLength"##));
+}
+
+#[test]
+fn unknown_styles_are_flat() {
+    assert_eq!(
+        make_badge("build", "passing", "green", "unknown", ""),
+        make_badge("build", "passing", "green", "flat", ""),
+    );
+}
@@ -0,0 +1,42 @@
+use snafu::prelude::*;
+
+/// Errors that can occur parsing a color
+#[derive(Debug, PartialEq, Snafu, Clone)]
+pub enum ColorError {
+    /// A color is not a valid hex color
+    #[snafu(display("Invalid hex color \"{hex}\""))]
+    InvalidHexColor {
+        /// Invalid color
+        hex: String,
+    },
+}
+
+/// Opaque RGB color
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub struct Color {
+    pub red: u8,
+    pub green: u8,
+    pub blue: u8,
+}
+
+impl Color {
+    pub const fn new(red: u8, green: u8, blue: u8) -> Self {
+        Self { red, green, blue }
+    }
+
+    /// Parse a hex color of 6 digits, with or without `#`
+    pub fn from_hex(hex: &str) -> Result<Self, ColorError> {
+        let digits = hex.strip_prefix('#').unwrap_or(hex);
+        let channel = |index: usize| {
+            digits
+                .get(index..index + 2)
+                .and_then(|channel| u8::from_str_radix(channel, 16).ok())
+        };
+        match (digits.len(), channel(0), channel(2), channel(4)) {
+            (6, Some(red), Some(green), Some(blue)) => {
+                Ok(Self::new(red, green, blue))
+            }
+            _ => InvalidHexColorSnafu { hex }.fail(),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+//! Colors, paths and formatting helpers shared by the exports of the icons
+//!
+//! The icons are exported to documents of several formats, like SVG
+//! sprites, badges, VectorDrawables, images or PDFs. All of them draw the
+//! paths in the same view box, fill them with the same colors and write
+//! texts and numbers the same way.
+
+mod color;
+mod path;
+
+pub use color::{Color, ColorError};
+pub use path::{PathError, parse_path};
+pub use svgtypes::SimplePathSegment;
+
+/// Side of the view box of the icons
+pub const VIEW_BOX_SIZE: f64 = 24.0;
+
+/// Escape a text to be included in XML or HTML content and attributes
+pub fn escape_xml(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+        .replace('\'', "&apos;")
+}
+
+/// Format a number rounded to a maximum number of decimals, without
+/// trailing zeros
+pub fn format_number(value: f64, max_decimals: usize) -> String {
+    let formatted = format!("{value:.max_decimals$}");
+    let trimmed = match formatted.contains('.') {
+        true => formatted.trim_end_matches('0').trim_end_matches('.'),
+        false => &formatted,
+    };
+    match trimmed {
+        "-0" => "0".to_string(),
+        trimmed => trimmed.to_string(),
+    }
+}
@@ -0,0 +1,38 @@
+use snafu::prelude::*;
+use svgtypes::{SimplePathSegment, SimplifyingPathParser};
+
+/// Errors that can occur parsing the path of an icon
+#[derive(Debug, PartialEq, Snafu, Clone)]
+pub enum PathError {
+    /// The path of the icon can't be parsed
+    #[snafu(display("Invalid path: {message}"))]
+    InvalidPath {
+        /// Error of the path parser
+        message: String,
+    },
+
+    /// The path of the icon doesn't draw anything
+    #[snafu(display("Empty path"))]
+    EmptyPath,
+}
+
+/// Parse the SVG path data of an icon
+///
+/// Relative, horizontal, vertical, smooth and arc commands are converted
+/// to absolute moves, lines, quadratic and cubic Bézier curves and
+/// closes, so the exports only have to draw these ones.
+pub fn parse_path(path: &str) -> Result<Vec<SimplePathSegment>, PathError> {
+    let segments = SimplifyingPathParser::from(path)
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|error| PathError::InvalidPath {
+            message: error.to_string(),
+        })?;
+    ensure!(
+        segments.iter().any(|segment| !matches!(
+            segment,
+            SimplePathSegment::MoveTo { .. } | SimplePathSegment::ClosePath
+        )),
+        EmptyPathSnafu
+    );
+    Ok(segments)
+}
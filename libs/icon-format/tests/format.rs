@@ -0,0 +1,78 @@
+use icon_format::{
+    Color, ColorError, PathError, SimplePathSegment, escape_xml, format_number,
+    parse_path,
+};
+
+#[test]
+fn special_characters_are_escaped() {
+    assert_eq!(
+        escape_xml(r#"<a href="x">Tom & Jerry's</a>"#),
+        "&lt;a href=&quot;x&quot;&gt;Tom &amp; Jerry&apos;s&lt;/a&gt;",
+    );
+    assert_eq!(escape_xml("&amp;"), "&amp;amp;");
+    assert_eq!(escape_xml("Simple Icons"), "Simple Icons");
+}
+
+#[test]
+fn numbers_are_rounded_without_trailing_zeros() {
+    assert_eq!(format_number(1.23456, 4), "1.2346");
+    assert_eq!(format_number(3.1, 4), "3.1");
+    assert_eq!(format_number(24.0, 4), "24");
+    assert_eq!(format_number(10.0, 0), "10");
+    assert_eq!(format_number(-2.5, 5), "-2.5");
+}
+
+#[test]
+fn zeros_have_no_sign() {
+    assert_eq!(format_number(0.0, 4), "0");
+    assert_eq!(format_number(-0.0, 4), "0");
+    assert_eq!(format_number(-0.00001, 4), "0");
+}
+
+#[test]
+fn hex_colors_are_parsed() {
+    assert_eq!(Color::from_hex("5865F2"), Ok(Color::new(0x58, 0x65, 0xF2)));
+    assert_eq!(Color::from_hex("#ffffff"), Ok(Color::new(255, 255, 255)));
+}
+
+#[test]
+fn invalid_hex_color() {
+    for hex in ["", "#FFF", "GGGGGG", "1234567"] {
+        assert_eq!(
+            Color::from_hex(hex),
+            Err(ColorError::InvalidHexColor {
+                hex: hex.to_string()
+            }),
+        );
+    }
+}
+
+#[test]
+fn paths_are_simplified_to_absolute_segments() {
+    assert_eq!(
+        parse_path("m1 2h3v4q1 1 2 0z"),
+        Ok(vec![
+            SimplePathSegment::MoveTo { x: 1.0, y: 2.0 },
+            SimplePathSegment::LineTo { x: 4.0, y: 2.0 },
+            SimplePathSegment::LineTo { x: 4.0, y: 6.0 },
+            SimplePathSegment::Quadratic {
+                x1: 5.0,
+                y1: 7.0,
+                x: 6.0,
+                y: 6.0
+            },
+            SimplePathSegment::ClosePath,
+        ]),
+    );
+}
+
+#[test]
+fn paths_that_dont_draw_are_rejected() {
+    for path in ["", "M12 12", "M0 0zM1 1z"] {
+        assert_eq!(parse_path(path), Err(PathError::EmptyPath), "{path:?}");
+    }
+    assert!(matches!(
+        parse_path("M0 0L"),
+        Err(PathError::InvalidPath { .. })
+    ));
+}
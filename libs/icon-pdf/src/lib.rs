@@ -0,0 +1,206 @@
+//! Vector PDF documents of the icons
+//!
+//! The path of an icon, drawn in a view box of 24x24 units, is written as
+//! the content of a square page, optionally surrounded by padding and over
+//! a solid background. The documents are serialized by hand, as they only
+//! contain a page with a path filled with a color.
+//!
+//! The colors and the paths are parsed with [`icon_format`], like the ones
+//! of the images, so the icons are exported with the same options to
+//! images and PDFs.
+
+use icon_format::{
+    Color, PathError, SimplePathSegment, VIEW_BOX_SIZE, format_number,
+    parse_path,
+};
+use snafu::prelude::*;
+
+/// Maximum width and height of the pages in points allowed by PDF readers
+pub const MAX_PAGE_SIZE: u32 = 14400;
+
+/// Errors that can occur generating the PDF of an icon
+#[derive(Debug, PartialEq, Snafu, Clone)]
+pub enum PdfError {
+    /// The path of the icon can't be parsed
+    #[snafu(display("Invalid path: {message}"))]
+    InvalidPath {
+        /// Error of the path parser
+        message: String,
+    },
+
+    /// The path of the icon doesn't draw anything
+    #[snafu(display("Empty path"))]
+    EmptyPath,
+
+    /// The size of the page is 0 or greater than the maximum
+    #[snafu(display(
+        "Invalid page size {size}, must be between 1 and {MAX_PAGE_SIZE}"
+    ))]
+    InvalidPageSize {
+        /// Requested size
+        size: u32,
+    },
+}
+
+/// Options of the PDFs generated
+#[derive(Debug, Clone, PartialEq)]
+pub struct PdfOptions {
+    /// Width and height of the page in points
+    pub page_size: u32,
+    /// Color of the icon
+    pub fill: Color,
+    /// Color of the background, transparent if `None`
+    pub background: Option<Color>,
+    /// Padding around the icon as a percentage of the page size
+    pub padding: u32,
+}
+
+/// Format a number as an operand of a PDF content stream
+fn number(value: f64) -> String {
+    format_number(value, 4)
+}
+
+/// Operands of a color for the `rg` operator
+fn color_operands(color: &Color) -> String {
+    [color.red, color.green, color.blue]
+        .iter()
+        .map(|channel| number(*channel as f64 / 255.0))
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Operators of a PDF content stream that build an icon path
+///
+/// Coordinates are kept in the view box of the icon, so the path
+/// must be transformed to the page with a `cm` operator.
+fn path_operators(path: &str) -> Result<String, PdfError> {
+    let segments = parse_path(path).map_err(|error| match error {
+        PathError::InvalidPath { message } => PdfError::InvalidPath { message },
+        PathError::EmptyPath => PdfError::EmptyPath,
+    })?;
+    let mut operators = vec![];
+    let (mut current, mut subpath_start) = ((0.0, 0.0), (0.0, 0.0));
+    for segment in segments {
+        match segment {
+            SimplePathSegment::MoveTo { x, y } => {
+                operators.push(format!("{} {} m", number(x), number(y)));
+                (current, subpath_start) = ((x, y), (x, y));
+            }
+            SimplePathSegment::LineTo { x, y } => {
+                operators.push(format!("{} {} l", number(x), number(y)));
+                current = (x, y);
+            }
+            SimplePathSegment::CurveTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                x,
+                y,
+            } => {
+                operators.push(format!(
+                    "{} {} {} {} {} {} c",
+                    number(x1),
+                    number(y1),
+                    number(x2),
+                    number(y2),
+                    number(x),
+                    number(y),
+                ));
+                current = (x, y);
+            }
+            SimplePathSegment::Quadratic { x1, y1, x, y } => {
+                // PDF only supports cubic Bézier curves, so quadratic
+                // ones are elevated to cubic
+                let (x0, y0) = current;
+                operators.push(format!(
+                    "{} {} {} {} {} {} c",
+                    number(x0 + 2.0 / 3.0 * (x1 - x0)),
+                    number(y0 + 2.0 / 3.0 * (y1 - y0)),
+                    number(x + 2.0 / 3.0 * (x1 - x)),
+                    number(y + 2.0 / 3.0 * (y1 - y)),
+                    number(x),
+                    number(y),
+                ));
+                current = (x, y);
+            }
+            SimplePathSegment::ClosePath => {
+                operators.push("h".to_string());
+                current = subpath_start;
+            }
+        }
+    }
+    Ok(operators.join("\n"))
+}
+
+/// Content stream of the page of an icon
+fn content_stream(
+    path: &str,
+    options: &PdfOptions,
+) -> Result<String, PdfError> {
+    let page_size = options.page_size as f64;
+    let padding = VIEW_BOX_SIZE * options.padding as f64 / 100.0;
+    let scale = page_size / (VIEW_BOX_SIZE + 2.0 * padding);
+
+    let mut content = String::new();
+    if let Some(background) = options.background {
+        content.push_str(&format!(
+            "{} rg\n0 0 {page_size} {page_size} re\nf\n",
+            color_operands(&background),
+        ));
+    }
+    // The Y axis of SVG points down while the one of PDF points up
+    content.push_str(&format!(
+        "q\n{} 0 0 {} {} {} cm\n{} rg\n{}\nf\nQ\n",
+        number(scale),
+        number(-scale),
+        number(scale * padding),
+        number(page_size - scale * padding),
+        color_operands(&options.fill),
+        path_operators(path)?,
+    ));
+    Ok(content)
+}
+
+/// Generate a vector PDF with one page for an icon given its SVG path data
+pub fn icon_pdf(path: &str, options: &PdfOptions) -> Result<Vec<u8>, PdfError> {
+    let size = options.page_size;
+    ensure!(
+        (1..=MAX_PAGE_SIZE).contains(&size),
+        InvalidPageSizeSnafu { size }
+    );
+    let content = content_stream(path, options)?;
+    let objects = [
+        "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+        "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+        format!(
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 {size} {size}] /Resources << >> /Contents 4 0 R >>"
+        ),
+        format!(
+            "<< /Length {} >>\nstream\n{content}endstream",
+            content.len()
+        ),
+    ];
+
+    // The binary comment marks the file as binary for transfer programs
+    let mut pdf = b"%PDF-1.4\n%\xE2\xE3\xCF\xD3\n".to_vec();
+    let mut offsets = vec![];
+    for (index, object) in objects.iter().enumerate() {
+        offsets.push(pdf.len());
+        pdf.extend_from_slice(
+            format!("{} 0 obj\n{object}\nendobj\n", index + 1).as_bytes(),
+        );
+    }
+    let xref_offset = pdf.len();
+    let mut xref =
+        format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1);
+    for offset in offsets {
+        xref.push_str(&format!("{offset:010} 00000 n \n"));
+    }
+    xref.push_str(&format!(
+        "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{xref_offset}\n%%EOF\n",
+        objects.len() + 1
+    ));
+    pdf.extend_from_slice(xref.as_bytes());
+    Ok(pdf)
+}
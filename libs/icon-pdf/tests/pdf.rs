@@ -0,0 +1,167 @@
+//! Tests of the structure and the content of the PDFs generated
+//!
+//! The documents are parsed back to check that the cross-reference table
+//! and the lengths of the streams point to the right bytes, and that the
+//! operators of the content stream draw the path in the page.
+
+use icon_format::Color;
+use icon_pdf::{MAX_PAGE_SIZE, PdfError, PdfOptions, icon_pdf};
+
+const SQUARE_PATH: &str = "M0 0h24v24H0z";
+
+fn options(page_size: u32, padding: u32) -> PdfOptions {
+    PdfOptions {
+        page_size,
+        fill: Color::new(255, 0, 0),
+        background: None,
+        padding,
+    }
+}
+
+/// Position of the first occurrence of a sequence of bytes
+fn find(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Text between two delimiters of a document
+fn between<'a>(document: &'a str, start: &str, end: &str) -> &'a str {
+    document
+        .split_once(start)
+        .unwrap()
+        .1
+        .split_once(end)
+        .unwrap()
+        .0
+}
+
+/// Content stream of the page of a PDF
+fn content_stream(pdf: &[u8]) -> String {
+    let start = find(pdf, b"stream\n").unwrap() + b"stream\n".len();
+    let end = find(pdf, b"endstream").unwrap();
+    String::from_utf8(pdf[start..end].to_vec()).unwrap()
+}
+
+#[test]
+fn xref_offsets_point_to_the_objects() {
+    let pdf = icon_pdf(SQUARE_PATH, &options(24, 0)).unwrap();
+    let text = String::from_utf8_lossy(&pdf);
+    assert!(pdf.starts_with(b"%PDF-1.4\n"));
+    assert!(text.ends_with("%%EOF\n"));
+
+    let xref_offset = between(&text, "startxref\n", "\n")
+        .parse::<usize>()
+        .unwrap();
+    assert!(pdf[xref_offset..].starts_with(b"xref\n0 5\n"));
+
+    let entries = between(&text, "xref\n0 5\n", "trailer")
+        .lines()
+        .collect::<Vec<_>>();
+    assert_eq!(entries.len(), 5);
+    assert_eq!(entries[0], "0000000000 65535 f ");
+    for (number, entry) in entries.iter().enumerate().skip(1) {
+        assert_eq!(entry.len(), 19, "{entry:?}");
+        assert!(entry.ends_with(" 00000 n "), "{entry:?}");
+        let offset = entry[..10].parse::<usize>().unwrap();
+        let object = format!("{number} 0 obj\n");
+        assert!(pdf[offset..].starts_with(object.as_bytes()), "{entry:?}");
+    }
+    assert!(text.contains("<< /Size 5 /Root 1 0 R >>"));
+}
+
+#[test]
+fn length_is_the_size_of_the_stream() {
+    for padding in [0, 10, 40] {
+        let pdf = icon_pdf(SQUARE_PATH, &options(512, padding)).unwrap();
+        let text = String::from_utf8_lossy(&pdf);
+        let length =
+            between(&text, "/Length ", " >>").parse::<usize>().unwrap();
+        assert_eq!(length, content_stream(&pdf).len());
+    }
+}
+
+#[test]
+fn page_has_the_size_requested() {
+    let pdf = icon_pdf(SQUARE_PATH, &options(640, 0)).unwrap();
+    let text = String::from_utf8_lossy(&pdf);
+    assert!(text.contains("/MediaBox [0 0 640 640]"));
+}
+
+#[test]
+fn path_is_flipped_and_scaled_to_the_page() {
+    let content =
+        content_stream(&icon_pdf(SQUARE_PATH, &options(240, 0)).unwrap());
+    // The origin of the view box is moved to the top left corner
+    assert!(content.contains("q\n10 0 0 -10 0 240 cm\n"), "{content}");
+
+    // The padding of each side is 10% of the view box, so the icon is
+    // drawn in the 24 units of the center of 28.8 units
+    let content =
+        content_stream(&icon_pdf(SQUARE_PATH, &options(288, 10)).unwrap());
+    assert!(content.contains("q\n10 0 0 -10 24 264 cm\n"), "{content}");
+}
+
+#[test]
+fn colors_are_operands_of_rg() {
+    let content = content_stream(
+        &icon_pdf(
+            SQUARE_PATH,
+            &PdfOptions {
+                background: Some(Color::from_hex("000000").unwrap()),
+                ..options(24, 0)
+            },
+        )
+        .unwrap(),
+    );
+    assert!(
+        content.starts_with("0 0 0 rg\n0 0 24 24 re\nf\nq\n"),
+        "{content}"
+    );
+    assert!(content.contains("cm\n1 0 0 rg\n"), "{content}");
+    assert!(content.ends_with("h\nf\nQ\n"), "{content}");
+}
+
+#[test]
+fn quadratic_curves_are_elevated_to_cubic() {
+    let content =
+        content_stream(&icon_pdf("M0 0Q12 24 24 0z", &options(24, 0)).unwrap());
+    assert!(
+        content.contains("0 0 m\n8 16 16 16 24 0 c\nh\n"),
+        "{content}"
+    );
+}
+
+#[test]
+fn numbers_are_rounded_to_4_decimals() {
+    let content = content_stream(
+        &icon_pdf("M0.5-0.00001L1.23456 2 3.10000 4z", &options(24, 0))
+            .unwrap(),
+    );
+    assert!(
+        content.contains("0.5 0 m\n1.2346 2 l\n3.1 4 l\nh\n"),
+        "{content}"
+    );
+}
+
+#[test]
+fn invalid_page_sizes_are_rejected() {
+    for size in [0, MAX_PAGE_SIZE + 1] {
+        assert_eq!(
+            icon_pdf(SQUARE_PATH, &options(size, 0)),
+            Err(PdfError::InvalidPageSize { size })
+        );
+    }
+}
+
+#[test]
+fn paths_that_dont_draw_are_rejected() {
+    assert_eq!(
+        icon_pdf("M12 12", &options(24, 0)),
+        Err(PdfError::EmptyPath)
+    );
+    assert!(matches!(
+        icon_pdf("M12 12 L", &options(24, 0)),
+        Err(PdfError::InvalidPath { .. })
+    ));
+}
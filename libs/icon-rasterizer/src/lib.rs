@@ -0,0 +1,291 @@
+//! Rasterization of the icons to PNG, JPEG, WebP and ICO images
+//!
+//! The path of an icon, drawn in a view box of 24x24 units, is scaled to
+//! fill a square image, optionally surrounded by padding and over a solid
+//! background. Rendering doesn't depend on the browser, so the images are
+//! the same wherever they are generated.
+
+use icon_format::{
+    Color, PathError, SimplePathSegment, VIEW_BOX_SIZE, parse_path,
+};
+use snafu::prelude::*;
+use tiny_skia::{
+    FillRule, Paint, Path, PathBuilder, Pixmap, PremultipliedColorU8, Transform,
+};
+
+/// Maximum width and height of the images in pixels
+pub const MAX_SIZE: u32 = 4096;
+
+/// Maximum width and height of the images of ICO files in pixels
+pub const MAX_ICO_SIZE: u32 = 256;
+
+/// Quality of the JPEG images, from 1 to 100
+const JPEG_QUALITY: u8 = 90;
+
+/// Background of the JPEG images without background, which don't
+/// support transparency
+const JPEG_DEFAULT_BACKGROUND: Color = Color::new(255, 255, 255);
+
+/// Errors that can occur rasterizing an icon
+#[derive(Debug, PartialEq, Snafu, Clone)]
+pub enum RasterizeError {
+    /// The path of the icon is not valid
+    #[snafu(display("{source}"), context(false))]
+    Path {
+        /// Error of the path
+        source: PathError,
+    },
+
+    /// The size of the image is 0 or greater than the maximum
+    #[snafu(display("Invalid size {size}, must be between 1 and {MAX_SIZE}"))]
+    InvalidSize {
+        /// Requested size
+        size: u32,
+    },
+
+    /// The size of an image of an ICO file is 0 or greater than the maximum
+    #[snafu(display(
+        "Invalid ICO image size {size}, must be between 1 and {MAX_ICO_SIZE}"
+    ))]
+    InvalidIcoSize {
+        /// Requested size
+        size: u32,
+    },
+
+    /// The encoder of the image format failed
+    #[snafu(display("Error encoding {format} image: {message}"))]
+    Encoding {
+        /// Format of the image
+        format: ImageFormat,
+        /// Error of the encoder
+        message: String,
+    },
+}
+
+/// Formats of the images generated
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum ImageFormat {
+    Png,
+    Jpeg,
+    /// Lossless WebP
+    Webp,
+}
+
+impl ImageFormat {
+    /// Extension of the files of the format
+    pub fn extension(&self) -> &'static str {
+        match self {
+            Self::Png => "png",
+            Self::Jpeg => "jpg",
+            Self::Webp => "webp",
+        }
+    }
+
+    /// MIME type of the format
+    pub fn mime_type(&self) -> &'static str {
+        match self {
+            Self::Png => "image/png",
+            Self::Jpeg => "image/jpeg",
+            Self::Webp => "image/webp",
+        }
+    }
+}
+
+impl core::fmt::Display for ImageFormat {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Png => write!(f, "PNG"),
+            Self::Jpeg => write!(f, "JPEG"),
+            Self::Webp => write!(f, "WebP"),
+        }
+    }
+}
+
+/// Color of tiny-skia of an opaque color
+fn skia_color(color: Color) -> tiny_skia::Color {
+    tiny_skia::Color::from_rgba8(color.red, color.green, color.blue, 255)
+}
+
+/// Options of the images generated
+#[derive(Debug, Clone, PartialEq)]
+pub struct RasterOptions {
+    /// Width and height of the image in pixels
+    pub size: u32,
+    /// Color of the icon
+    pub fill: Color,
+    /// Color of the background, transparent if `None`
+    pub background: Option<Color>,
+    /// Padding around the icon as a percentage of the size
+    pub padding: u32,
+}
+
+impl Default for RasterOptions {
+    fn default() -> Self {
+        Self {
+            size: 640,
+            fill: Color::new(0, 0, 0),
+            background: None,
+            padding: 0,
+        }
+    }
+}
+
+/// Build the path of an icon from its SVG path data
+fn build_path(path: &str) -> Result<Path, RasterizeError> {
+    let mut builder = PathBuilder::new();
+    for segment in parse_path(path)? {
+        match segment {
+            SimplePathSegment::MoveTo { x, y } => {
+                builder.move_to(x as f32, y as f32)
+            }
+            SimplePathSegment::LineTo { x, y } => {
+                builder.line_to(x as f32, y as f32)
+            }
+            SimplePathSegment::CurveTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                x,
+                y,
+            } => builder.cubic_to(
+                x1 as f32, y1 as f32, x2 as f32, y2 as f32, x as f32, y as f32,
+            ),
+            SimplePathSegment::Quadratic { x1, y1, x, y } => {
+                builder.quad_to(x1 as f32, y1 as f32, x as f32, y as f32)
+            }
+            SimplePathSegment::ClosePath => builder.close(),
+        }
+    }
+    Ok(builder.finish().ok_or(PathError::EmptyPath)?)
+}
+
+/// Draw an icon given its SVG path data
+fn render(
+    path: &str,
+    options: &RasterOptions,
+    background: Option<Color>,
+) -> Result<Pixmap, RasterizeError> {
+    let size = options.size;
+    ensure!((1..=MAX_SIZE).contains(&size), InvalidSizeSnafu { size });
+    let path = build_path(path)?;
+    let mut pixmap =
+        Pixmap::new(size, size).context(InvalidSizeSnafu { size })?;
+    if let Some(background) = background {
+        pixmap.fill(skia_color(background));
+    }
+
+    let view_box_size = VIEW_BOX_SIZE as f32;
+    let padding = view_box_size * options.padding as f32 / 100.0;
+    let scale = size as f32 / (view_box_size + 2.0 * padding);
+    let transform =
+        Transform::from_translate(padding, padding).post_scale(scale, scale);
+    let mut paint = Paint::default();
+    paint.set_color(skia_color(options.fill));
+    paint.anti_alias = true;
+    pixmap.fill_path(&path, &paint, FillRule::Winding, transform, None);
+    Ok(pixmap)
+}
+
+/// RGBA pixels of an image, not premultiplied by the alpha
+fn demultiplied_rgba(pixels: &[PremultipliedColorU8]) -> Vec<u8> {
+    pixels
+        .iter()
+        .flat_map(|pixel| {
+            let color = pixel.demultiply();
+            [color.red(), color.green(), color.blue(), color.alpha()]
+        })
+        .collect()
+}
+
+/// Rasterize an icon given its SVG path data
+///
+/// Returns the bytes of the image encoded in the given format.
+pub fn rasterize(
+    path: &str,
+    options: &RasterOptions,
+    format: ImageFormat,
+) -> Result<Vec<u8>, RasterizeError> {
+    let encoding_error =
+        |message: String| RasterizeError::Encoding { format, message };
+    match format {
+        ImageFormat::Png => render(path, options, options.background)?
+            .encode_png()
+            .map_err(|error| encoding_error(error.to_string())),
+        ImageFormat::Jpeg => {
+            let background =
+                options.background.unwrap_or(JPEG_DEFAULT_BACKGROUND);
+            let pixmap = render(path, options, Some(background))?;
+            let mut bytes = vec![];
+            jpeg_encoder::Encoder::new(&mut bytes, JPEG_QUALITY)
+                .encode(
+                    &demultiplied_rgba(pixmap.pixels()),
+                    options.size as u16,
+                    options.size as u16,
+                    jpeg_encoder::ColorType::Rgba,
+                )
+                .map_err(|error| encoding_error(error.to_string()))?;
+            Ok(bytes)
+        }
+        ImageFormat::Webp => {
+            let pixmap = render(path, options, options.background)?;
+            let mut bytes = vec![];
+            image_webp::WebPEncoder::new(&mut bytes)
+                .encode(
+                    &demultiplied_rgba(pixmap.pixels()),
+                    options.size,
+                    options.size,
+                    image_webp::ColorType::Rgba8,
+                )
+                .map_err(|error| encoding_error(error.to_string()))?;
+            Ok(bytes)
+        }
+    }
+}
+
+/// Rasterize an icon to an ICO file given its SVG path data
+///
+/// The file contains a PNG image for each size, which all the browsers
+/// supporting favicons are able to read. The size of the options is
+/// ignored.
+pub fn rasterize_ico(
+    path: &str,
+    options: &RasterOptions,
+    sizes: &[u32],
+) -> Result<Vec<u8>, RasterizeError> {
+    let images = sizes
+        .iter()
+        .map(|&size| {
+            ensure!(
+                (1..=MAX_ICO_SIZE).contains(&size),
+                InvalidIcoSizeSnafu { size }
+            );
+            let options = RasterOptions {
+                size,
+                ..options.clone()
+            };
+            rasterize(path, &options, ImageFormat::Png).map(|png| (size, png))
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    // Header of 6 bytes: reserved, type (1 for icons) and number of images
+    let mut ico = vec![0, 0, 1, 0];
+    ico.extend_from_slice(&(images.len() as u16).to_le_bytes());
+    // Directory with an entry of 16 bytes per image, followed by the images
+    let mut offset = 6 + 16 * images.len() as u32;
+    for (size, png) in &images {
+        // A size of 256 pixels is stored as 0
+        let side = (*size % MAX_ICO_SIZE) as u8;
+        ico.extend_from_slice(&[side, side, 0, 0]);
+        // Color planes and bits per pixel
+        ico.extend_from_slice(&1u16.to_le_bytes());
+        ico.extend_from_slice(&32u16.to_le_bytes());
+        ico.extend_from_slice(&(png.len() as u32).to_le_bytes());
+        ico.extend_from_slice(&offset.to_le_bytes());
+        offset += png.len() as u32;
+    }
+    for (_, png) in images {
+        ico.extend_from_slice(&png);
+    }
+    Ok(ico)
+}
@@ -0,0 +1,292 @@
+//! Golden image tests
+//!
+//! The images rendered are compared with the PNG images of the `golden`
+//! directory. To regenerate them after an intended change in the
+//! rendering, run the tests with the `UPDATE_GOLDEN_IMAGES` environment
+//! variable set.
+
+use icon_format::{Color, PathError};
+use icon_rasterizer::{
+    ImageFormat, MAX_ICO_SIZE, MAX_SIZE, RasterOptions, RasterizeError,
+    rasterize, rasterize_ico,
+};
+use std::path::PathBuf;
+use tiny_skia::Pixmap;
+
+/// Ring with relative commands and two subpaths
+const RING_PATH: &str = "M12 0C5.37 0 0 5.37 0 12s5.37 12 12 12 12-5.37 12-12S18.63 0 12 0zm0 4a8 8 0 110 16 8 8 0 010-16z";
+
+/// Arrow with horizontal, vertical and quadratic commands
+const ARROW_PATH: &str = "M3 10h10V4l8 8-8 8v-6H3zM1 2q4 0 4 4T1 10";
+
+const SQUARE_PATH: &str = "M0 0h24v24H0z";
+
+/// Maximum difference allowed per channel, so the tests pass with the
+/// small differences of floating point arithmetic between platforms
+const TOLERANCE: u8 = 2;
+
+fn golden_path(name: &str) -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+        .join(format!("{name}.png"))
+}
+
+/// Pixels as non premultiplied RGBA
+fn rgba(pixmap: &Pixmap) -> Vec<[u8; 4]> {
+    pixmap
+        .pixels()
+        .iter()
+        .map(|pixel| {
+            let color = pixel.demultiply();
+            [color.red(), color.green(), color.blue(), color.alpha()]
+        })
+        .collect()
+}
+
+fn assert_similar_pixels(name: &str, actual: &[[u8; 4]], expected: &[[u8; 4]]) {
+    assert_eq!(actual.len(), expected.len(), "{name}: different sizes");
+    for (index, (a, e)) in actual.iter().zip(expected).enumerate() {
+        // The color of fully transparent pixels doesn't matter
+        if a[3] == 0 && e[3] == 0 {
+            continue;
+        }
+        assert!(
+            a.iter().zip(e).all(|(a, e)| a.abs_diff(*e) <= TOLERANCE),
+            "{name}: pixel {index} is {a:?}, expected {e:?}",
+        );
+    }
+}
+
+/// Compare a PNG rendered with its golden image
+fn assert_golden(name: &str, path: &str, options: &RasterOptions) {
+    let png = rasterize(path, options, ImageFormat::Png).unwrap();
+    let golden = golden_path(name);
+    if std::env::var_os("UPDATE_GOLDEN_IMAGES").is_some() {
+        std::fs::write(&golden, &png).unwrap();
+    }
+    let expected = Pixmap::load_png(&golden).unwrap();
+    let actual = Pixmap::decode_png(&png).unwrap();
+    assert_eq!(actual.width(), options.size);
+    assert_eq!(actual.height(), options.size);
+    assert_similar_pixels(name, &rgba(&actual), &rgba(&expected));
+}
+
+#[test]
+fn ring_black_transparent() {
+    assert_golden(
+        "ring-black-transparent",
+        RING_PATH,
+        &RasterOptions {
+            size: 64,
+            ..Default::default()
+        },
+    );
+}
+
+#[test]
+fn ring_brand_color_with_background_and_padding() {
+    assert_golden(
+        "ring-brand-background-padding",
+        RING_PATH,
+        &RasterOptions {
+            size: 48,
+            fill: Color::from_hex("5865F2").unwrap(),
+            background: Some(Color::from_hex("#FFFFFF").unwrap()),
+            padding: 20,
+        },
+    );
+}
+
+#[test]
+fn arrow_white_on_black() {
+    assert_golden(
+        "arrow-white-on-black",
+        ARROW_PATH,
+        &RasterOptions {
+            size: 32,
+            fill: Color::new(255, 255, 255),
+            background: Some(Color::new(0, 0, 0)),
+            padding: 10,
+        },
+    );
+}
+
+#[test]
+fn webp_is_lossless() {
+    let options = RasterOptions {
+        size: 64,
+        fill: Color::from_hex("CB3837").unwrap(),
+        background: None,
+        padding: 5,
+    };
+    let png = rasterize(RING_PATH, &options, ImageFormat::Png).unwrap();
+    let webp = rasterize(RING_PATH, &options, ImageFormat::Webp).unwrap();
+    assert_eq!(&webp[..4], b"RIFF");
+    assert_eq!(&webp[8..12], b"WEBP");
+
+    let mut decoder =
+        image_webp::WebPDecoder::new(std::io::Cursor::new(webp)).unwrap();
+    assert_eq!(decoder.dimensions(), (64, 64));
+    let mut buffer = vec![0; decoder.output_buffer_size().unwrap()];
+    decoder.read_image(&mut buffer).unwrap();
+    let webp_pixels = match decoder.has_alpha() {
+        true => buffer
+            .chunks(4)
+            .map(|pixel| [pixel[0], pixel[1], pixel[2], pixel[3]])
+            .collect::<Vec<_>>(),
+        false => buffer
+            .chunks(3)
+            .map(|pixel| [pixel[0], pixel[1], pixel[2], 255])
+            .collect::<Vec<_>>(),
+    };
+    assert_similar_pixels(
+        "webp",
+        &webp_pixels,
+        &rgba(&Pixmap::decode_png(&png).unwrap()),
+    );
+}
+
+#[test]
+fn jpeg_has_the_requested_size() {
+    let jpeg = rasterize(
+        RING_PATH,
+        &RasterOptions {
+            size: 100,
+            ..Default::default()
+        },
+        ImageFormat::Jpeg,
+    )
+    .unwrap();
+    assert_eq!(&jpeg[..2], &[0xFF, 0xD8]);
+    assert_eq!(&jpeg[jpeg.len() - 2..], &[0xFF, 0xD9]);
+
+    // Height and width of the baseline frame header
+    let frame_header = jpeg
+        .windows(2)
+        .position(|marker| marker == [0xFF, 0xC0])
+        .unwrap();
+    assert_eq!(&jpeg[frame_header + 5..frame_header + 9], &[0, 100, 0, 100]);
+}
+
+#[test]
+fn square_fills_the_whole_image() {
+    let png = rasterize(
+        SQUARE_PATH,
+        &RasterOptions {
+            size: 16,
+            fill: Color::new(17, 133, 254),
+            ..Default::default()
+        },
+        ImageFormat::Png,
+    )
+    .unwrap();
+    let pixels = rgba(&Pixmap::decode_png(&png).unwrap());
+    assert!(pixels.iter().all(|pixel| *pixel == [17, 133, 254, 255]));
+}
+
+#[test]
+fn padding_surrounds_the_icon() {
+    let png = rasterize(
+        SQUARE_PATH,
+        &RasterOptions {
+            size: 20,
+            fill: Color::new(0, 0, 0),
+            background: Some(Color::new(255, 255, 255)),
+            // 24 * 25% = 6 units of padding in each side, so the
+            // icon covers 24 / 36 of the image
+            padding: 25,
+        },
+        ImageFormat::Png,
+    )
+    .unwrap();
+    let pixels = rgba(&Pixmap::decode_png(&png).unwrap());
+    assert_eq!(pixels[0], [255, 255, 255, 255]);
+    assert_eq!(pixels[10 * 20 + 10], [0, 0, 0, 255]);
+}
+
+#[test]
+fn ico_contains_a_png_per_size() {
+    let options = RasterOptions {
+        fill: Color::from_hex("5865F2").unwrap(),
+        ..Default::default()
+    };
+    let ico = rasterize_ico(RING_PATH, &options, &[16, 32, 256]).unwrap();
+    assert_eq!(&ico[..6], &[0, 0, 1, 0, 3, 0]);
+
+    for (index, size) in [16, 32, 256].into_iter().enumerate() {
+        let entry = &ico[6 + 16 * index..6 + 16 * (index + 1)];
+        let side = if size == MAX_ICO_SIZE { 0 } else { size as u8 };
+        assert_eq!(&entry[..2], &[side, side]);
+        let length =
+            u32::from_le_bytes(entry[8..12].try_into().unwrap()) as usize;
+        let offset =
+            u32::from_le_bytes(entry[12..16].try_into().unwrap()) as usize;
+        let png = &ico[offset..offset + length];
+        assert_eq!(
+            png,
+            rasterize(
+                RING_PATH,
+                &RasterOptions {
+                    size,
+                    ..options.clone()
+                },
+                ImageFormat::Png,
+            )
+            .unwrap(),
+        );
+    }
+}
+
+#[test]
+fn invalid_ico_size() {
+    assert_eq!(
+        rasterize_ico(
+            SQUARE_PATH,
+            &RasterOptions::default(),
+            &[16, MAX_ICO_SIZE + 1]
+        ),
+        Err(RasterizeError::InvalidIcoSize {
+            size: MAX_ICO_SIZE + 1
+        }),
+    );
+}
+
+#[test]
+fn invalid_path() {
+    let error = rasterize("M0 0L", &RasterOptions::default(), ImageFormat::Png)
+        .unwrap_err();
+    assert!(matches!(
+        error,
+        RasterizeError::Path {
+            source: PathError::InvalidPath { .. }
+        }
+    ));
+}
+
+#[test]
+fn empty_path() {
+    assert_eq!(
+        rasterize("", &RasterOptions::default(), ImageFormat::Png),
+        Err(RasterizeError::Path {
+            source: PathError::EmptyPath
+        }),
+    );
+}
+
+#[test]
+fn invalid_size() {
+    for size in [0, MAX_SIZE + 1] {
+        assert_eq!(
+            rasterize(
+                SQUARE_PATH,
+                &RasterOptions {
+                    size,
+                    ..Default::default()
+                },
+                ImageFormat::Webp,
+            ),
+            Err(RasterizeError::InvalidSize { size }),
+        );
+    }
+}
@@ -0,0 +1,156 @@
+//! Compact encoding of collections of icons
+//!
+//! A collection is a set of indexes of icons in alphabetical order
+//! (`order_alpha`). The indexes are sorted, converted to the differences
+//! between consecutive indexes, written as LEB128 variable length integers
+//! and encoded as base64url without padding, so they can be used in URLs.
+//!
+//! The indexes of the icons change when icons are added or removed, so a
+//! collection decoded with other version of the icons can reference
+//! different icons or indexes out of range.
+
+use snafu::prelude::*;
+
+static BASE64URL_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789-_";
+
+/// Errors that can occur decoding a collection
+#[derive(Debug, PartialEq, Snafu, Clone)]
+pub enum CollectionDecodeError {
+    /// Character not included in the base64url alphabet
+    #[snafu(display("Invalid character \"{character}\" at index {index}"))]
+    InvalidCharacter {
+        /// Invalid character
+        character: char,
+        /// Index of the invalid character
+        index: usize,
+    },
+
+    /// The length of the encoded string is not valid for base64url
+    #[snafu(display("Invalid length {length}"))]
+    InvalidLength {
+        /// Length of the encoded string
+        length: usize,
+    },
+
+    /// The last variable length integer is not terminated
+    #[snafu(display("Truncated integer at the end of the collection"))]
+    TruncatedInteger,
+
+    /// A variable length integer does not fit in an index
+    #[snafu(display("Integer overflow at byte {index}"))]
+    IntegerOverflow {
+        /// Index of the byte where the overflow happens
+        index: usize,
+    },
+}
+
+fn base64url_encode(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+    for chunk in bytes.chunks(3) {
+        let group = chunk.iter().enumerate().fold(0u32, |group, (i, byte)| {
+            group | ((*byte as u32) << (16 - i * 8))
+        });
+        for i in 0..=chunk.len() {
+            let sextet = (group >> (18 - i * 6)) & 0b11_1111;
+            encoded.push(BASE64URL_ALPHABET[sextet as usize] as char);
+        }
+    }
+    encoded
+}
+
+fn base64url_decode(value: &str) -> Result<Vec<u8>, CollectionDecodeError> {
+    if value.len() % 4 == 1 {
+        return Err(CollectionDecodeError::InvalidLength {
+            length: value.len(),
+        });
+    }
+
+    let sextets = value
+        .char_indices()
+        .map(|(index, character)| {
+            BASE64URL_ALPHABET
+                .iter()
+                .position(|c| *c as char == character)
+                .map(|sextet| sextet as u32)
+                .ok_or(CollectionDecodeError::InvalidCharacter {
+                    character,
+                    index,
+                })
+        })
+        .collect::<Result<Vec<u32>, CollectionDecodeError>>()?;
+
+    let mut bytes = Vec::with_capacity(sextets.len() * 3 / 4);
+    for chunk in sextets.chunks(4) {
+        let group = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |group, (i, sextet)| group | (sextet << (18 - i * 6)));
+        for i in 0..chunk.len() - 1 {
+            bytes.push((group >> (16 - i * 8)) as u8);
+        }
+    }
+    Ok(bytes)
+}
+
+/// Encode a collection of indexes of icons
+///
+/// The order of the indexes and the duplicates are not preserved.
+pub fn encode(indexes: impl IntoIterator<Item = usize>) -> String {
+    let mut indexes = indexes.into_iter().collect::<Vec<usize>>();
+    indexes.sort_unstable();
+    indexes.dedup();
+
+    let mut bytes = vec![];
+    let mut previous = 0;
+    for index in indexes {
+        let mut delta = index - previous;
+        previous = index;
+        loop {
+            let byte = (delta & 0b111_1111) as u8;
+            delta >>= 7;
+            if delta == 0 {
+                bytes.push(byte);
+                break;
+            }
+            bytes.push(byte | 0b1000_0000);
+        }
+    }
+
+    base64url_encode(&bytes)
+}
+
+/// Decode a collection of indexes of icons
+///
+/// The indexes are returned sorted and without duplicates.
+pub fn decode(value: &str) -> Result<Vec<usize>, CollectionDecodeError> {
+    let bytes = base64url_decode(value)?;
+
+    let mut indexes = vec![];
+    let mut previous = 0usize;
+    let mut delta = 0usize;
+    let mut shift = 0;
+    for (index, byte) in bytes.iter().enumerate() {
+        let bits = (byte & 0b111_1111) as usize;
+        delta |= bits
+            .checked_shl(shift)
+            .filter(|value| value >> shift == bits)
+            .ok_or(CollectionDecodeError::IntegerOverflow { index })?;
+        shift += 7;
+        if byte & 0b1000_0000 == 0 {
+            previous = previous
+                .checked_add(delta)
+                .ok_or(CollectionDecodeError::IntegerOverflow { index })?;
+            if indexes.last() != Some(&previous) {
+                indexes.push(previous);
+            }
+            delta = 0;
+            shift = 0;
+        }
+    }
+    if shift != 0 {
+        return Err(CollectionDecodeError::TruncatedInteger);
+    }
+
+    Ok(indexes)
+}
@@ -0,0 +1,102 @@
+//! Tests of the encoding and decoding of the collections of icons
+
+use icons_collection::{CollectionDecodeError, decode, encode};
+
+#[test]
+fn round_trip() {
+    for indexes in [
+        vec![],
+        vec![0],
+        vec![0, 1, 2],
+        vec![127, 128, 255, 256],
+        vec![3, 16_383, 16_384, 3400],
+        vec![usize::MAX],
+    ] {
+        let encoded = encode(indexes.iter().copied());
+        let mut sorted = indexes.clone();
+        sorted.sort_unstable();
+        assert_eq!(decode(&encoded), Ok(sorted), "{encoded}");
+    }
+}
+
+#[test]
+fn encoding_is_url_safe() {
+    let encoded = encode(0..2000);
+    assert!(
+        encoded
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_'),
+        "{encoded}"
+    );
+}
+
+#[test]
+fn order_and_duplicates_are_not_preserved() {
+    assert_eq!(encode([5, 0, 5, 2, 0]), encode([0, 2, 5]));
+    assert_eq!(decode(&encode([5, 0, 5, 2, 0])), Ok(vec![0, 2, 5]));
+}
+
+#[test]
+fn index_zero_is_encoded() {
+    assert_eq!(encode([0]), "AA");
+    assert_eq!(decode("AA"), Ok(vec![0]));
+    assert_eq!(decode(""), Ok(vec![]));
+}
+
+#[test]
+fn duplicates_are_removed_when_decoding() {
+    // Deltas 1, 0, 0 and 2
+    assert_eq!(decode("AQAAAg"), Ok(vec![1, 3]));
+}
+
+#[test]
+fn invalid_length() {
+    for value in ["A", "AAAAA"] {
+        assert_eq!(
+            decode(value),
+            Err(CollectionDecodeError::InvalidLength {
+                length: value.len()
+            })
+        );
+    }
+}
+
+#[test]
+fn invalid_character() {
+    assert_eq!(
+        decode("AA+A"),
+        Err(CollectionDecodeError::InvalidCharacter {
+            character: '+',
+            index: 2
+        })
+    );
+    // Padding is not used
+    assert_eq!(
+        decode("AA=="),
+        Err(CollectionDecodeError::InvalidCharacter {
+            character: '=',
+            index: 2
+        })
+    );
+}
+
+#[test]
+fn truncated_integer() {
+    // The continuation bit of the last byte is set
+    assert_eq!(decode("gA"), Err(CollectionDecodeError::TruncatedInteger));
+    assert_eq!(decode("Af8"), Err(CollectionDecodeError::TruncatedInteger));
+}
+
+#[test]
+fn integer_overflow() {
+    // The tenth byte of a delta has more bits than the 64 of an index
+    assert_eq!(
+        decode("____________fw"),
+        Err(CollectionDecodeError::IntegerOverflow { index: 9 })
+    );
+    // The sum of the deltas is greater than the maximum index
+    assert_eq!(
+        decode("____________AQE"),
+        Err(CollectionDecodeError::IntegerOverflow { index: 10 })
+    );
+}
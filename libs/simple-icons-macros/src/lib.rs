@@ -9,6 +9,7 @@ use simple_icons::{
     get_simple_icon_svg_path as get_simple_icon_svg_path_impl,
     get_simple_icons,
 };
+use std::collections::HashMap;
 use std::fs;
 use std::path::Path;
 use syn::{LitStr, parse_macro_input};
@@ -127,6 +128,24 @@ pub fn get_simple_icons_3rd_party_libraries(_: TokenStream) -> TokenStream {
     )
 }
 
+/// Code of the localized titles of an icon or a duplicate, sorted by language
+fn localized_titles_code(loc: &Option<HashMap<String, String>>) -> String {
+    match loc {
+        Some(loc) => {
+            let mut loc = loc.iter().collect::<Vec<_>>();
+            loc.sort();
+            format!(
+                "Some(&[{}])",
+                loc.iter()
+                    .map(|(lang, title)| format!("(\"{lang}\", \"{title}\")"))
+                    .collect::<Vec<_>>()
+                    .join(", ")
+            )
+        }
+        None => "None".to_string(),
+    }
+}
+
 fn icons_array_impl(only_include_deprecated: bool) -> String {
     let simple_icons = get_simple_icons();
 
@@ -217,8 +236,28 @@ fn icons_array_impl(only_include_deprecated: bool) -> String {
                                 "Some(&[{}])",
                                 dup.iter()
                                     .map(|dup| format!(
-                                        "\"{}\"",
-                                        dup.title.clone()
+                                        concat!(
+                                            "::simple_icons_website_types::SimpleIconDuplicate{{",
+                                            "title: \"{}\",",
+                                            "hex: {},",
+                                            "hex_is_relatively_light: {},",
+                                            "guidelines: {},",
+                                            "loc: {},",
+                                            "}}",
+                                        ),
+                                        dup.title,
+                                        match dup.hex {
+                                            Some(ref hex) => format!("Some(\"{hex}\")"),
+                                            None => "None".to_string(),
+                                        },
+                                        dup.hex.as_ref().is_some_and(
+                                            |hex| is_relatively_light_icon_hex(hex)
+                                        ),
+                                        match dup.guidelines {
+                                            Some(ref url) => format!("Some(\"{url}\")"),
+                                            None => "None".to_string(),
+                                        },
+                                        localized_titles_code(&dup.loc),
                                     ))
                                     .collect::<Vec<_>>()
                                     .join(", ")
@@ -226,52 +265,7 @@ fn icons_array_impl(only_include_deprecated: bool) -> String {
                             None => "None".to_string(),
                         }
                     ),
-                    &format!("loc: {}", {
-                        let mut result = "".to_string();
-                        let mut alias_dup_locs: Vec<(String, String)> = vec![];
-                        if let Some(ref aliases_dup) = aliases.dup {
-                            for alias_dup in aliases_dup {
-                                if let Some(ref alias_dup_loc) = alias_dup.loc {
-                                    alias_dup_locs.extend(
-                                        alias_dup_loc.iter().map(
-                                            |(lang, title)| {
-                                                (lang.clone(), title.clone())
-                                            },
-                                        ),
-                                    );
-                                }
-                            }
-                        }
-
-                        if aliases.loc.is_some() || !alias_dup_locs.is_empty() {
-                            result.push_str("Some(&[");
-                            if let Some(ref aliases_loc) = aliases.loc {
-                                result.push_str(
-                                    &aliases_loc
-                                        .iter()
-                                        .map(|(lang, title)| {
-                                            format!("(\"{lang}\", \"{title}\")")
-                                        })
-                                        .collect::<Vec<_>>()
-                                        .join(", "),
-                                );
-                            }
-                            if !alias_dup_locs.is_empty() {
-                                if aliases.loc.is_some() {
-                                    result.push_str(", ");
-                                }
-                                for (lang, title) in alias_dup_locs {
-                                    result.push_str(&format!(
-                                        "(\"{lang}\", \"{title}\"),"
-                                    ));
-                                }
-                            }
-                            result.push_str("])");
-                        } else {
-                            result.push_str("None");
-                        }
-                        result
-                    }),
+                    &format!("loc: {}", localized_titles_code(&aliases.loc)),
                     &format!(
                         "old: {}",
                         match &aliases.old {
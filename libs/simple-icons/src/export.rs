@@ -0,0 +1,530 @@
+//! Conversion of the paths of the icons to vector formats of other platforms
+//!
+//! The converters take the concrete syntax tree of a path built by
+//! [`svg_path_cst`](svg_path_cst::svg_path_cst) and emit documents of
+//! 24x24 units filled with a hex color:
+//!
+//! - [Android VectorDrawable](https://developer.android.com/develop/ui/views/graphics/vector-drawable-resources)
+//! - [XAML PathGeometry](https://learn.microsoft.com/en-us/dotnet/desktop/wpf/graphics-multimedia/path-markup-syntax)
+//! - [Encapsulated PostScript](https://www.adobe.com/jp/print/postscript/pdfs/5002.EPSF_Spec.pdf)
+//!
+//! Elliptical arcs are kept in the formats that support them and
+//! converted to cubic Bézier curves in the ones that don't.
+use core::f64::consts::{FRAC_PI_2, PI};
+use icon_format::{VIEW_BOX_SIZE, escape_xml, format_number};
+use svg_path_cst::{SVGPathCSTNode, SVGPathCommand};
+
+/// Maximum number of decimals of the coordinates emitted
+static MAX_DECIMALS: usize = 5;
+
+/// Path command with absolute coordinates
+///
+/// Horizontal, vertical and smooth commands are expanded to their
+/// general form, so converters only have to handle these ones.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathCommand {
+    MoveTo {
+        x: f64,
+        y: f64,
+    },
+    LineTo {
+        x: f64,
+        y: f64,
+    },
+    CurveTo {
+        x1: f64,
+        y1: f64,
+        x2: f64,
+        y2: f64,
+        x: f64,
+        y: f64,
+    },
+    Quadratic {
+        x1: f64,
+        y1: f64,
+        x: f64,
+        y: f64,
+    },
+    Arc {
+        rx: f64,
+        ry: f64,
+        x_axis_rotation: f64,
+        large_arc: bool,
+        sweep: bool,
+        x: f64,
+        y: f64,
+    },
+    ClosePath,
+}
+
+/// Commands with absolute coordinates of a path
+pub fn absolute_commands(cst: &[SVGPathCSTNode]) -> Vec<PathCommand> {
+    let mut commands = vec![];
+    let (mut x, mut y) = (0.0, 0.0);
+    let (mut start_x, mut start_y) = (0.0, 0.0);
+    // Last control point of the previous command, to reflect it in
+    // smooth curves
+    let mut last_cubic_control: Option<(f64, f64)> = None;
+    let mut last_quadratic_control: Option<(f64, f64)> = None;
+
+    for node in cst {
+        let SVGPathCSTNode::Segment(segment) = node else {
+            continue;
+        };
+        let args = &segment.args;
+        let relative = (*segment.command as u8).is_ascii_lowercase();
+        let (dx, dy) = match relative {
+            true => (x, y),
+            false => (0.0, 0.0),
+        };
+        let reflect = |control: Option<(f64, f64)>| match control {
+            Some((cx, cy)) => (2.0 * x - cx, 2.0 * y - cy),
+            None => (x, y),
+        };
+
+        let command = match segment.command {
+            // Coordinate pairs after the first one of a moveto are
+            // implicit linetos, which don't start a new subpath
+            SVGPathCommand::MovetoUpper | SVGPathCommand::MovetoLower
+                if segment.chained =>
+            {
+                PathCommand::LineTo {
+                    x: args[0] + dx,
+                    y: args[1] + dy,
+                }
+            }
+            SVGPathCommand::MovetoUpper | SVGPathCommand::MovetoLower => {
+                (start_x, start_y) = (args[0] + dx, args[1] + dy);
+                PathCommand::MoveTo {
+                    x: start_x,
+                    y: start_y,
+                }
+            }
+            SVGPathCommand::LinetoUpper | SVGPathCommand::LinetoLower => {
+                PathCommand::LineTo {
+                    x: args[0] + dx,
+                    y: args[1] + dy,
+                }
+            }
+            SVGPathCommand::HorizontalUpper
+            | SVGPathCommand::HorizontalLower => {
+                PathCommand::LineTo { x: args[0] + dx, y }
+            }
+            SVGPathCommand::VerticalUpper | SVGPathCommand::VerticalLower => {
+                PathCommand::LineTo { x, y: args[0] + dy }
+            }
+            SVGPathCommand::CurvetoUpper | SVGPathCommand::CurvetoLower => {
+                PathCommand::CurveTo {
+                    x1: args[0] + dx,
+                    y1: args[1] + dy,
+                    x2: args[2] + dx,
+                    y2: args[3] + dy,
+                    x: args[4] + dx,
+                    y: args[5] + dy,
+                }
+            }
+            SVGPathCommand::SmoothCurvetoUpper
+            | SVGPathCommand::SmoothCurvetoLower => {
+                let (x1, y1) = reflect(last_cubic_control);
+                PathCommand::CurveTo {
+                    x1,
+                    y1,
+                    x2: args[0] + dx,
+                    y2: args[1] + dy,
+                    x: args[2] + dx,
+                    y: args[3] + dy,
+                }
+            }
+            SVGPathCommand::QuadraticUpper | SVGPathCommand::QuadraticLower => {
+                PathCommand::Quadratic {
+                    x1: args[0] + dx,
+                    y1: args[1] + dy,
+                    x: args[2] + dx,
+                    y: args[3] + dy,
+                }
+            }
+            SVGPathCommand::SmoothQuadraticUpper
+            | SVGPathCommand::SmoothQuadraticLower => {
+                let (x1, y1) = reflect(last_quadratic_control);
+                PathCommand::Quadratic {
+                    x1,
+                    y1,
+                    x: args[0] + dx,
+                    y: args[1] + dy,
+                }
+            }
+            SVGPathCommand::ArcUpper | SVGPathCommand::ArcLower => {
+                PathCommand::Arc {
+                    rx: args[0],
+                    ry: args[1],
+                    x_axis_rotation: args[2],
+                    large_arc: args[3] != 0.0,
+                    sweep: args[4] != 0.0,
+                    x: args[5] + dx,
+                    y: args[6] + dy,
+                }
+            }
+            SVGPathCommand::ClosepathUpper | SVGPathCommand::ClosepathLower => {
+                PathCommand::ClosePath
+            }
+        };
+
+        (last_cubic_control, last_quadratic_control) = match command {
+            PathCommand::CurveTo { x2, y2, .. } => (Some((x2, y2)), None),
+            PathCommand::Quadratic { x1, y1, .. } => (None, Some((x1, y1))),
+            _ => (None, None),
+        };
+        (x, y) = match command {
+            PathCommand::MoveTo { x, y }
+            | PathCommand::LineTo { x, y }
+            | PathCommand::CurveTo { x, y, .. }
+            | PathCommand::Quadratic { x, y, .. }
+            | PathCommand::Arc { x, y, .. } => (x, y),
+            PathCommand::ClosePath => (start_x, start_y),
+        };
+        commands.push(command);
+    }
+    commands
+}
+
+/// Approximate an elliptical arc with cubic Bézier curves
+///
+/// Implements the conversion from endpoint to center parameterization of
+/// the [SVG specification], splitting the arc in segments of 90 degrees at
+/// most. Returns the control and end points of each curve.
+///
+/// [SVG specification]: https://www.w3.org/TR/SVG2/implnote.html#ArcImplementationNotes
+fn arc_to_curves(
+    (x1, y1): (f64, f64),
+    rx: f64,
+    ry: f64,
+    x_axis_rotation: f64,
+    large_arc: bool,
+    sweep: bool,
+    (x2, y2): (f64, f64),
+) -> Vec<PathCommand> {
+    if x1 == x2 && y1 == y2 {
+        return vec![];
+    }
+    let (mut rx, mut ry) = (rx.abs(), ry.abs());
+    if rx == 0.0 || ry == 0.0 {
+        return vec![PathCommand::LineTo { x: x2, y: y2 }];
+    }
+    let (sin_phi, cos_phi) = x_axis_rotation.to_radians().sin_cos();
+
+    // Midpoint between the endpoints in the coordinates of the ellipse
+    let (half_dx, half_dy) = ((x1 - x2) / 2.0, (y1 - y2) / 2.0);
+    let x1p = cos_phi * half_dx + sin_phi * half_dy;
+    let y1p = -sin_phi * half_dx + cos_phi * half_dy;
+
+    // Scale up the radii if they are too small to reach the endpoints
+    let lambda = (x1p / rx).powi(2) + (y1p / ry).powi(2);
+    if lambda > 1.0 {
+        rx *= lambda.sqrt();
+        ry *= lambda.sqrt();
+    }
+
+    let numerator = (rx * ry).powi(2) - (rx * y1p).powi(2) - (ry * x1p).powi(2);
+    let denominator = (rx * y1p).powi(2) + (ry * x1p).powi(2);
+    let sign = if large_arc == sweep { -1.0 } else { 1.0 };
+    let coefficient = sign * (numerator / denominator).max(0.0).sqrt();
+    let cxp = coefficient * rx * y1p / ry;
+    let cyp = -coefficient * ry * x1p / rx;
+    let cx = cos_phi * cxp - sin_phi * cyp + (x1 + x2) / 2.0;
+    let cy = sin_phi * cxp + cos_phi * cyp + (y1 + y2) / 2.0;
+
+    let angle = |ux: f64, uy: f64| uy.atan2(ux);
+    let start_angle = angle((x1p - cxp) / rx, (y1p - cyp) / ry);
+    let mut sweep_angle =
+        angle((-x1p - cxp) / rx, (-y1p - cyp) / ry) - start_angle;
+    if sweep && sweep_angle < 0.0 {
+        sweep_angle += 2.0 * PI;
+    } else if !sweep && sweep_angle > 0.0 {
+        sweep_angle -= 2.0 * PI;
+    }
+
+    let point = |theta: f64| {
+        let (sin, cos) = theta.sin_cos();
+        (
+            cx + rx * cos * cos_phi - ry * sin * sin_phi,
+            cy + rx * cos * sin_phi + ry * sin * cos_phi,
+        )
+    };
+    let derivative = |theta: f64| {
+        let (sin, cos) = theta.sin_cos();
+        (
+            -rx * sin * cos_phi - ry * cos * sin_phi,
+            -rx * sin * sin_phi + ry * cos * cos_phi,
+        )
+    };
+
+    let segments = (sweep_angle.abs() / FRAC_PI_2 - 1e-9).ceil().max(1.0);
+    let delta = sweep_angle / segments;
+    let handle = 4.0 / 3.0 * (delta / 4.0).tan();
+    (0..segments as usize)
+        .map(|index| {
+            let theta1 = start_angle + delta * index as f64;
+            let theta2 = theta1 + delta;
+            let (px1, py1) = point(theta1);
+            let (dx1, dy1) = derivative(theta1);
+            let (dx2, dy2) = derivative(theta2);
+            // The last point is the endpoint of the arc, exactly
+            let (x, y) = match index + 1 == segments as usize {
+                true => (x2, y2),
+                false => point(theta2),
+            };
+            PathCommand::CurveTo {
+                x1: px1 + handle * dx1,
+                y1: py1 + handle * dy1,
+                x2: x - handle * dx2,
+                y2: y - handle * dy2,
+                x,
+                y,
+            }
+        })
+        .collect()
+}
+
+/// Replace the arcs and quadratic curves of a path with cubic curves
+///
+/// Returns commands that only move, draw lines, draw cubic curves
+/// and close subpaths.
+pub fn cubic_commands(commands: &[PathCommand]) -> Vec<PathCommand> {
+    let mut cubic = vec![];
+    let (mut x, mut y) = (0.0, 0.0);
+    let (mut start_x, mut start_y) = (0.0, 0.0);
+    for command in commands {
+        match *command {
+            PathCommand::Quadratic {
+                x1,
+                y1,
+                x: qx,
+                y: qy,
+            } => {
+                cubic.push(PathCommand::CurveTo {
+                    x1: x + 2.0 / 3.0 * (x1 - x),
+                    y1: y + 2.0 / 3.0 * (y1 - y),
+                    x2: qx + 2.0 / 3.0 * (x1 - qx),
+                    y2: qy + 2.0 / 3.0 * (y1 - qy),
+                    x: qx,
+                    y: qy,
+                });
+                (x, y) = (qx, qy);
+            }
+            PathCommand::Arc {
+                rx,
+                ry,
+                x_axis_rotation,
+                large_arc,
+                sweep,
+                x: ax,
+                y: ay,
+            } => {
+                cubic.extend(arc_to_curves(
+                    (x, y),
+                    rx,
+                    ry,
+                    x_axis_rotation,
+                    large_arc,
+                    sweep,
+                    (ax, ay),
+                ));
+                (x, y) = (ax, ay);
+            }
+            PathCommand::MoveTo { x: mx, y: my } => {
+                cubic.push(*command);
+                (x, y, start_x, start_y) = (mx, my, mx, my);
+            }
+            PathCommand::LineTo { x: lx, y: ly }
+            | PathCommand::CurveTo { x: lx, y: ly, .. } => {
+                cubic.push(*command);
+                (x, y) = (lx, ly);
+            }
+            PathCommand::ClosePath => {
+                cubic.push(*command);
+                (x, y) = (start_x, start_y);
+            }
+        }
+    }
+    cubic
+}
+
+/// Format a coordinate of the documents emitted
+fn number(value: f64) -> String {
+    format_number(value, MAX_DECIMALS)
+}
+
+/// Path data with absolute commands in the syntax of SVG
+///
+/// Both VectorDrawable and XAML use the syntax of SVG for path data,
+/// including arcs.
+fn path_data(commands: &[PathCommand]) -> String {
+    commands
+        .iter()
+        .map(|command| match *command {
+            PathCommand::MoveTo { x, y } => {
+                format!("M{},{}", number(x), number(y))
+            }
+            PathCommand::LineTo { x, y } => {
+                format!("L{},{}", number(x), number(y))
+            }
+            PathCommand::CurveTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                x,
+                y,
+            } => format!(
+                "C{},{} {},{} {},{}",
+                number(x1),
+                number(y1),
+                number(x2),
+                number(y2),
+                number(x),
+                number(y),
+            ),
+            PathCommand::Quadratic { x1, y1, x, y } => format!(
+                "Q{},{} {},{}",
+                number(x1),
+                number(y1),
+                number(x),
+                number(y),
+            ),
+            PathCommand::Arc {
+                rx,
+                ry,
+                x_axis_rotation,
+                large_arc,
+                sweep,
+                x,
+                y,
+            } => format!(
+                "A{},{} {} {} {} {},{}",
+                number(rx),
+                number(ry),
+                number(x_axis_rotation),
+                large_arc as u8,
+                sweep as u8,
+                number(x),
+                number(y),
+            ),
+            PathCommand::ClosePath => "Z".to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Convert a path to an Android VectorDrawable given its fill hex color
+pub fn to_vector_drawable(cst: &[SVGPathCSTNode], hex: &str) -> String {
+    format!(
+        concat!(
+            "<?xml version=\"1.0\" encoding=\"utf-8\"?>\n",
+            "<vector xmlns:android=\"http://schemas.android.com/apk/res/android\"\n",
+            "    android:width=\"{size}dp\"\n",
+            "    android:height=\"{size}dp\"\n",
+            "    android:viewportWidth=\"{size}\"\n",
+            "    android:viewportHeight=\"{size}\">\n",
+            "    <path\n",
+            "        android:fillColor=\"#FF{hex}\"\n",
+            "        android:pathData=\"{path}\" />\n",
+            "</vector>\n",
+        ),
+        size = VIEW_BOX_SIZE,
+        hex = hex.to_ascii_uppercase(),
+        path = path_data(&absolute_commands(cst)),
+    )
+}
+
+/// Convert a path to a XAML `PathGeometry` given its title and fill
+/// hex color
+///
+/// The geometry is wrapped in a `Viewbox`, so it can be scaled.
+pub fn to_xaml(cst: &[SVGPathCSTNode], title: &str, hex: &str) -> String {
+    format!(
+        concat!(
+            "<Viewbox xmlns=\"http://schemas.microsoft.com/winfx/2006/xaml/presentation\"\n",
+            "         xmlns:x=\"http://schemas.microsoft.com/winfx/2006/xaml\"\n",
+            "         Width=\"{size}\" Height=\"{size}\">\n",
+            "  <Canvas Width=\"{size}\" Height=\"{size}\">\n",
+            "    <Path x:Name=\"Icon\" ToolTip=\"{title}\" Fill=\"#{hex}\">\n",
+            "      <Path.Data>\n",
+            "        <PathGeometry FillRule=\"Nonzero\" Figures=\"{path}\" />\n",
+            "      </Path.Data>\n",
+            "    </Path>\n",
+            "  </Canvas>\n",
+            "</Viewbox>\n",
+        ),
+        size = VIEW_BOX_SIZE,
+        title = escape_xml(title),
+        hex = hex.to_ascii_uppercase(),
+        path = path_data(&absolute_commands(cst)),
+    )
+}
+
+/// Convert a path to an Encapsulated PostScript file given its title
+/// and fill hex color
+///
+/// PostScript only draws circular arcs, so the arcs are converted to
+/// cubic curves.
+pub fn to_eps(cst: &[SVGPathCSTNode], title: &str, hex: &str) -> String {
+    let channel = |index: usize| {
+        hex.get(index..index + 2)
+            .and_then(|channel| u8::from_str_radix(channel, 16).ok())
+            .map(|channel| number(channel as f64 / 255.0))
+            .unwrap_or_else(|| "0".to_string())
+    };
+    let operators = cubic_commands(&absolute_commands(cst))
+        .iter()
+        .map(|command| match *command {
+            PathCommand::MoveTo { x, y } => {
+                format!("{} {} moveto", number(x), number(y))
+            }
+            PathCommand::LineTo { x, y } => {
+                format!("{} {} lineto", number(x), number(y))
+            }
+            PathCommand::CurveTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                x,
+                y,
+            } => format!(
+                "{} {} {} {} {} {} curveto",
+                number(x1),
+                number(y1),
+                number(x2),
+                number(y2),
+                number(x),
+                number(y),
+            ),
+            _ => "closepath".to_string(),
+        })
+        .collect::<Vec<_>>()
+        .join("\n");
+    format!(
+        concat!(
+            "%!PS-Adobe-3.0 EPSF-3.0\n",
+            "%%BoundingBox: 0 0 {size} {size}\n",
+            "%%Title: ({title})\n",
+            "%%EndComments\n",
+            "gsave\n",
+            // The Y axis of SVG points down while the one of PostScript
+            // points up
+            "[1 0 0 -1 0 {size}] concat\n",
+            "{red} {green} {blue} setrgbcolor\n",
+            "newpath\n",
+            "{operators}\n",
+            "fill\n",
+            "grestore\n",
+            "%%EOF\n",
+        ),
+        size = VIEW_BOX_SIZE,
+        title = title.replace(['(', ')', '\\'], ""),
+        red = channel(0),
+        green = channel(2),
+        blue = channel(4),
+        operators = operators,
+    )
+}
@@ -1,5 +1,6 @@
 pub mod color;
 mod deprecated;
+pub mod export;
 pub mod lint;
 
 pub use deprecated::{IconDeprecation, fetch_deprecated_simple_icons};
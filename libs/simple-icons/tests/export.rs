@@ -0,0 +1,323 @@
+//! Round-trip tests of the conversion of paths to other vector formats
+//!
+//! The paths are converted and parsed back from the documents generated,
+//! then compared with the commands of the original paths.
+
+use simple_icons::export::{
+    PathCommand, absolute_commands, cubic_commands, to_eps, to_vector_drawable,
+    to_xaml,
+};
+use svg_path_cst::svg_path_cst;
+
+/// Path with relative, horizontal, vertical, smooth and arc commands
+const MIXED_PATH: &str = "M12 0a12 12 0 1 0 0 24A12 12 0 0 0 12 0zm-4.5 6h9v3H9.2l-.7 4c1-.6 2.4-.9 3.5-.9 2.8 0 4.6 1.6 4.6 4s-2.1 4.3-5.1 4.3S7 18.8 7 16.3h2.6c0 1 .9 1.7 2.3 1.7s2.3-.8 2.3-1.9-.9-1.8-2.4-1.8q-1.6 0-2.6.8t-2.3.2z";
+
+/// Triangle drawn with the implicit linetos of a moveto
+const CHAINED_MOVETO_PATH: &str = "M0 0 10 10 20 0zm5 5 1 1 1-1z";
+
+/// Circle of radius 10 centered at (12, 12) drawn with two arcs
+const CIRCLE_PATH: &str = "M2 12A10 10 0 1 1 22 12A10 10 0 1 1 2 12Z";
+
+/// Ellipse of radii 10 and 4 rotated 30 degrees around (12, 12)
+const ROTATED_ELLIPSE_PATH: &str =
+    "M3.33975 7A10 4 30 0 1 20.66025 17A10 4 30 0 1 3.33975 7Z";
+
+/// Maximum distance between coordinates considered equal, as the
+/// documents are written with 5 decimals
+const TOLERANCE: f64 = 1e-5;
+
+/// Maximum distance between a cubic curve and the arc it approximates
+const ARC_TOLERANCE: f64 = 1e-2;
+
+fn commands(path: &str) -> Vec<PathCommand> {
+    absolute_commands(&svg_path_cst(path.as_bytes()).unwrap())
+}
+
+/// Value of an XML attribute in a document
+fn attribute<'a>(document: &'a str, name: &str) -> &'a str {
+    document
+        .split_once(&format!("{name}=\""))
+        .unwrap()
+        .1
+        .split_once('"')
+        .unwrap()
+        .0
+}
+
+/// Coordinates of a command, with the flags of arcs as numbers
+fn coordinates(command: &PathCommand) -> Vec<f64> {
+    match *command {
+        PathCommand::MoveTo { x, y } | PathCommand::LineTo { x, y } => {
+            vec![x, y]
+        }
+        PathCommand::CurveTo {
+            x1,
+            y1,
+            x2,
+            y2,
+            x,
+            y,
+        } => vec![x1, y1, x2, y2, x, y],
+        PathCommand::Quadratic { x1, y1, x, y } => vec![x1, y1, x, y],
+        PathCommand::Arc {
+            rx,
+            ry,
+            x_axis_rotation,
+            large_arc,
+            sweep,
+            x,
+            y,
+        } => vec![
+            rx,
+            ry,
+            x_axis_rotation,
+            large_arc as u8 as f64,
+            sweep as u8 as f64,
+            x,
+            y,
+        ],
+        PathCommand::ClosePath => vec![],
+    }
+}
+
+fn assert_similar_commands(actual: &[PathCommand], expected: &[PathCommand]) {
+    assert_eq!(actual.len(), expected.len(), "different number of commands");
+    for (a, e) in actual.iter().zip(expected) {
+        assert_eq!(
+            core::mem::discriminant(a),
+            core::mem::discriminant(e),
+            "{a:?} is not {e:?}"
+        );
+        assert!(
+            coordinates(a)
+                .iter()
+                .zip(coordinates(e))
+                .all(|(a, e)| (a - e).abs() <= TOLERANCE),
+            "{a:?} is not {e:?}"
+        );
+    }
+}
+
+/// Parse the path operators of an EPS file generated
+fn eps_commands(eps: &str) -> Vec<PathCommand> {
+    let operators = eps
+        .split_once("newpath\n")
+        .unwrap()
+        .1
+        .split_once("\nfill")
+        .unwrap()
+        .0;
+    operators
+        .lines()
+        .map(|line| {
+            let (operands, operator) =
+                line.rsplit_once(' ').unwrap_or(("", line));
+            let v = operands
+                .split_whitespace()
+                .map(|operand| operand.parse::<f64>().unwrap())
+                .collect::<Vec<_>>();
+            match operator {
+                "moveto" => PathCommand::MoveTo { x: v[0], y: v[1] },
+                "lineto" => PathCommand::LineTo { x: v[0], y: v[1] },
+                "curveto" => PathCommand::CurveTo {
+                    x1: v[0],
+                    y1: v[1],
+                    x2: v[2],
+                    y2: v[3],
+                    x: v[4],
+                    y: v[5],
+                },
+                "closepath" => PathCommand::ClosePath,
+                operator => panic!("Unexpected operator {operator}"),
+            }
+        })
+        .collect()
+}
+
+/// Points of the cubic curves of some commands, sampled uniformly
+fn curve_points(commands: &[PathCommand]) -> Vec<(f64, f64)> {
+    let mut points = vec![];
+    let (mut x0, mut y0) = (0.0, 0.0);
+    for command in commands {
+        match *command {
+            PathCommand::CurveTo {
+                x1,
+                y1,
+                x2,
+                y2,
+                x,
+                y,
+            } => {
+                for step in 0..=20 {
+                    let t = step as f64 / 20.0;
+                    let mt = 1.0 - t;
+                    let bezier = |p0: f64, p1: f64, p2: f64, p3: f64| {
+                        mt.powi(3) * p0
+                            + 3.0 * mt.powi(2) * t * p1
+                            + 3.0 * mt * t.powi(2) * p2
+                            + t.powi(3) * p3
+                    };
+                    points.push((bezier(x0, x1, x2, x), bezier(y0, y1, y2, y)));
+                }
+                (x0, y0) = (x, y);
+            }
+            PathCommand::MoveTo { x, y } | PathCommand::LineTo { x, y } => {
+                (x0, y0) = (x, y);
+            }
+            _ => {}
+        }
+    }
+    points
+}
+
+#[test]
+fn vector_drawable_round_trip() {
+    let cst = svg_path_cst(MIXED_PATH.as_bytes()).unwrap();
+    let vector_drawable = to_vector_drawable(&cst, "5865f2");
+    assert!(vector_drawable.starts_with("<?xml"));
+    assert_eq!(
+        attribute(&vector_drawable, "android:fillColor"),
+        "#FF5865F2"
+    );
+    assert_eq!(attribute(&vector_drawable, "android:viewportWidth"), "24");
+    assert_similar_commands(
+        &commands(attribute(&vector_drawable, "android:pathData")),
+        &commands(MIXED_PATH),
+    );
+}
+
+#[test]
+fn xaml_round_trip() {
+    let cst = svg_path_cst(MIXED_PATH.as_bytes()).unwrap();
+    let xaml = to_xaml(&cst, "AT&T", "00A8E0");
+    assert_eq!(attribute(&xaml, "ToolTip"), "AT&amp;T");
+    assert_eq!(attribute(&xaml, "Fill"), "#00A8E0");
+    assert_similar_commands(
+        &commands(attribute(&xaml, "Figures")),
+        &commands(MIXED_PATH),
+    );
+}
+
+#[test]
+fn eps_round_trip() {
+    let cst = svg_path_cst(MIXED_PATH.as_bytes()).unwrap();
+    let eps = to_eps(&cst, "Simple Icons", "FFFFFF");
+    assert!(eps.starts_with("%!PS-Adobe-3.0 EPSF-3.0\n"));
+    assert!(eps.contains("%%BoundingBox: 0 0 24 24\n"));
+    assert!(eps.contains("1 1 1 setrgbcolor\n"));
+    assert_similar_commands(
+        &eps_commands(&eps),
+        &cubic_commands(&commands(MIXED_PATH)),
+    );
+}
+
+#[test]
+fn eps_converts_arcs_to_curves_on_the_circle() {
+    let cst = svg_path_cst(CIRCLE_PATH.as_bytes()).unwrap();
+    let eps_commands = eps_commands(&to_eps(&cst, "Circle", "000000"));
+    // Each half of the circle is split in two curves of 90 degrees
+    assert_eq!(
+        eps_commands
+            .iter()
+            .filter(|command| matches!(command, PathCommand::CurveTo { .. }))
+            .count(),
+        4
+    );
+    for (x, y) in curve_points(&eps_commands) {
+        let radius = ((x - 12.0).powi(2) + (y - 12.0).powi(2)).sqrt();
+        assert!((radius - 10.0).abs() < ARC_TOLERANCE, "({x}, {y})");
+    }
+}
+
+#[test]
+fn arcs_of_rotated_ellipses_are_converted_to_curves() {
+    let curves = cubic_commands(&commands(ROTATED_ELLIPSE_PATH));
+    let (sin, cos) = 30f64.to_radians().sin_cos();
+    for (x, y) in curve_points(&curves) {
+        // Coordinates in the axes of the ellipse
+        let (dx, dy) = (x - 12.0, y - 12.0);
+        let (u, v) = (dx * cos + dy * sin, -dx * sin + dy * cos);
+        let distance = (u / 10.0).powi(2) + (v / 4.0).powi(2);
+        assert!((distance - 1.0).abs() < ARC_TOLERANCE, "({x}, {y})");
+    }
+}
+
+#[test]
+fn sweep_flag_selects_the_direction_of_arcs() {
+    // Radii too small to reach the endpoint are scaled up, so both arcs
+    // are half circles of radius 5 centered at (5, 12)
+    for (sweep, expected_y) in [(1, 7.0), (0, 17.0)] {
+        let path = format!("M0 12A1 1 0 0 {sweep} 10 12");
+        let curves = cubic_commands(&commands(&path));
+        let points = curve_points(&curves);
+        let (x, y) = points[points.len() / 2];
+        assert!((x - 5.0).abs() < ARC_TOLERANCE, "({x}, {y})");
+        assert!((y - expected_y).abs() < ARC_TOLERANCE, "({x}, {y})");
+    }
+}
+
+#[test]
+fn arcs_with_zero_radius_are_lines() {
+    assert_eq!(
+        cubic_commands(&commands("M0 0A0 5 0 0 1 10 10")),
+        vec![
+            PathCommand::MoveTo { x: 0.0, y: 0.0 },
+            PathCommand::LineTo { x: 10.0, y: 10.0 },
+        ]
+    );
+}
+
+#[test]
+fn implicit_linetos_of_movetos_are_lines() {
+    assert_eq!(
+        commands(CHAINED_MOVETO_PATH),
+        vec![
+            PathCommand::MoveTo { x: 0.0, y: 0.0 },
+            PathCommand::LineTo { x: 10.0, y: 10.0 },
+            PathCommand::LineTo { x: 20.0, y: 0.0 },
+            PathCommand::ClosePath,
+            // Relative to the start of the previous subpath
+            PathCommand::MoveTo { x: 5.0, y: 5.0 },
+            PathCommand::LineTo { x: 6.0, y: 6.0 },
+            PathCommand::LineTo { x: 7.0, y: 5.0 },
+            PathCommand::ClosePath,
+        ]
+    );
+}
+
+#[test]
+fn eps_keeps_the_implicit_linetos_of_movetos() {
+    let cst = svg_path_cst(CHAINED_MOVETO_PATH.as_bytes()).unwrap();
+    let eps = to_eps(&cst, "Triangles", "000000");
+    let operators = eps_commands(&eps)
+        .iter()
+        .map(|command| match command {
+            PathCommand::MoveTo { .. } => "moveto",
+            PathCommand::LineTo { .. } => "lineto",
+            PathCommand::ClosePath => "closepath",
+            _ => "other",
+        })
+        .collect::<Vec<_>>();
+    assert_eq!(
+        operators,
+        [
+            "moveto",
+            "lineto",
+            "lineto",
+            "closepath",
+            "moveto",
+            "lineto",
+            "lineto",
+            "closepath"
+        ]
+    );
+}
+
+#[test]
+fn vector_drawable_keeps_the_implicit_linetos_of_movetos() {
+    let cst = svg_path_cst(CHAINED_MOVETO_PATH.as_bytes()).unwrap();
+    assert_eq!(
+        attribute(&to_vector_drawable(&cst, "000000"), "android:pathData"),
+        "M0,0 L10,10 L20,0 Z M5,5 L6,6 L7,5 Z"
+    );
+}
@@ -4,10 +4,23 @@ mod third_party_extension;
 pub use deprecation::IconDeprecation;
 pub use third_party_extension::ThirdPartyExtension;
 
+/// Brand that uses the same icon as another brand
+#[derive(Clone)]
+pub struct SimpleIconDuplicate {
+    pub title: &'static str,
+    /// Brand color, if different from the color of the icon
+    pub hex: Option<&'static str>,
+    /// Whether the brand color is relatively light, if any
+    pub hex_is_relatively_light: bool,
+    /// Brand guidelines, if different from the guidelines of the icon
+    pub guidelines: Option<&'static str>,
+    pub loc: Option<&'static [(&'static str, &'static str)]>,
+}
+
 #[derive(Clone)]
 pub struct SimpleIconAliases {
     pub aka: Option<&'static [&'static str]>,
-    pub dup: Option<&'static [&'static str]>,
+    pub dup: Option<&'static [SimpleIconDuplicate]>,
     pub loc: Option<&'static [(&'static str, &'static str)]>,
     pub old: Option<&'static [&'static str]>,
 }
@@ -37,7 +50,12 @@ impl SimpleIcon {
                 aliases.extend_from_slice(aka);
             }
             if let Some(dup) = aliases_.dup {
-                aliases.extend_from_slice(dup);
+                for duplicate in dup {
+                    aliases.push(duplicate.title);
+                    if let Some(loc) = duplicate.loc {
+                        aliases.extend(loc.iter().map(|(_, v)| *v));
+                    }
+                }
             }
             if let Some(loc) = aliases_.loc {
                 aliases.extend(loc.iter().map(|(_, v)| *v));
@@ -6,23 +6,37 @@ use wasm_bindgen::{JsCast, closure::Closure};
 pub async fn copy_canvas_container_as_image(
     container: web_sys::HtmlCanvasElement,
 ) {
-    let closure: Closure<dyn FnMut(web_sys::Blob)> = Closure::new(
-        move |blob: web_sys::Blob| {
-            let clipboard_item_object = js_sys::Object::new();
-            _ = js_sys::Reflect::set(
-                &clipboard_item_object,
-                &blob.type_().into(),
-                &blob.into(),
-            );
-            let clipboard_item = web_sys::ClipboardItem::new_with_record_from_str_to_blob_promise(
-                &clipboard_item_object,
-            ).unwrap();
-            let to_copy_array = js_sys::Array::new();
-            to_copy_array.push(&clipboard_item);
-            let clipboard = web_sys::window().unwrap().navigator().clipboard();
-            let _ = clipboard.write(&to_copy_array);
-        },
-    );
+    let closure: Closure<dyn FnMut(web_sys::Blob)> = Closure::new(copy_blob);
     container.to_blob(closure.as_ref().unchecked_ref()).unwrap();
     closure.forget();
 }
+
+/// Copy a blob to the clipboard, using its type as the type of the item.
+pub fn copy_blob(blob: web_sys::Blob) {
+    let clipboard_item_object = js_sys::Object::new();
+    _ = js_sys::Reflect::set(
+        &clipboard_item_object,
+        &blob.type_().into(),
+        &blob.into(),
+    );
+    let clipboard_item =
+        web_sys::ClipboardItem::new_with_record_from_str_to_blob_promise(
+            &clipboard_item_object,
+        )
+        .unwrap();
+    let to_copy_array = js_sys::Array::new();
+    to_copy_array.push(&clipboard_item);
+    let clipboard = web_sys::window().unwrap().navigator().clipboard();
+    let _ = clipboard.write(&to_copy_array);
+}
+
+/// Copy the bytes of an image to the clipboard.
+pub fn copy_image_bytes(bytes: &[u8], mime_type: &str) {
+    let parts = js_sys::Array::of1(&js_sys::Uint8Array::from(bytes));
+    let options = web_sys::BlobPropertyBag::new();
+    options.set_type(mime_type);
+    let blob =
+        web_sys::Blob::new_with_u8_array_sequence_and_options(&parts, &options)
+            .unwrap();
+    copy_blob(blob);
+}
@@ -0,0 +1,179 @@
+//! Minimal writer of ZIP archives
+//!
+//! Files are stored without compression, which is enough for bundling
+//! icons to be downloaded at once. See the [ZIP file format specification].
+//!
+//! Archives don't use the ZIP64 extensions, so they can't contain more
+//! than 65535 files nor be larger than 4 GiB, which is far more than the
+//! icons need. Files that don't fit are rejected instead of being written
+//! with truncated sizes.
+//!
+//! [ZIP file format specification]: https://pkware.cachefly.net/webdocs/casestudies/APPNOTE.TXT
+
+use snafu::prelude::*;
+
+const LOCAL_FILE_HEADER_SIGNATURE: u32 = 0x0403_4b50;
+const CENTRAL_DIRECTORY_HEADER_SIGNATURE: u32 = 0x0201_4b50;
+const END_OF_CENTRAL_DIRECTORY_SIGNATURE: u32 = 0x0605_4b50;
+/// Version 2.0, the minimum that supports directories
+const VERSION: u16 = 20;
+/// General purpose flag to mark file names as UTF-8
+const UTF8_FLAG: u16 = 1 << 11;
+/// MS-DOS date of 1980-01-01, the minimum representable
+const MODIFICATION_DATE: u16 = (1 << 5) | 1;
+
+/// Errors that can occur writing a ZIP archive
+#[derive(Debug, PartialEq, Snafu, Clone)]
+pub enum ZipError {
+    /// The archive already contains the maximum number of files
+    #[snafu(display(
+        "A ZIP archive can't contain more than {} files",
+        u16::MAX
+    ))]
+    TooManyFiles,
+
+    /// The name of a file doesn't fit in the headers
+    #[snafu(display(
+        "The name of the file {name} is longer than {} bytes",
+        u16::MAX
+    ))]
+    NameTooLong {
+        /// Name of the file
+        name: String,
+    },
+
+    /// The archive doesn't fit in the offsets of the headers
+    #[snafu(display("A ZIP archive can't be larger than {} bytes", u32::MAX))]
+    ArchiveTooLarge,
+}
+
+/// CRC-32 checksum used by ZIP archives
+pub fn crc32(data: &[u8]) -> u32 {
+    let mut crc = !0u32;
+    for byte in data {
+        crc ^= u32::from(*byte);
+        for _ in 0..8 {
+            let mask = (crc & 1).wrapping_neg();
+            crc = (crc >> 1) ^ (0xedb8_8320 & mask);
+        }
+    }
+    !crc
+}
+
+struct ZipEntry {
+    name: String,
+    name_length: u16,
+    crc: u32,
+    size: u32,
+    offset: u32,
+}
+
+/// Writer of ZIP archives in memory
+#[derive(Default)]
+pub struct ZipWriter {
+    buffer: Vec<u8>,
+    entries: Vec<ZipEntry>,
+}
+
+impl ZipWriter {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn write_u16(&mut self, value: u16) {
+        self.buffer.extend_from_slice(&value.to_le_bytes());
+    }
+
+    fn write_u32(&mut self, value: u32) {
+        self.buffer.extend_from_slice(&value.to_le_bytes());
+    }
+
+    /// Add a file to the archive
+    ///
+    /// The name can include directories separated by `/`.
+    pub fn add_file(
+        &mut self,
+        name: &str,
+        data: &[u8],
+    ) -> Result<(), ZipError> {
+        ensure!(
+            self.entries.len() < usize::from(u16::MAX),
+            TooManyFilesSnafu
+        );
+        let entry = ZipEntry {
+            name: name.to_string(),
+            name_length: u16::try_from(name.len())
+                .ok()
+                .context(NameTooLongSnafu { name })?,
+            crc: crc32(data),
+            size: u32::try_from(data.len())
+                .ok()
+                .context(ArchiveTooLargeSnafu)?,
+            offset: u32::try_from(self.buffer.len())
+                .ok()
+                .context(ArchiveTooLargeSnafu)?,
+        };
+
+        self.write_u32(LOCAL_FILE_HEADER_SIGNATURE);
+        self.write_u16(VERSION);
+        self.write_u16(UTF8_FLAG);
+        self.write_u16(0); // stored, without compression
+        self.write_u16(0); // modification time
+        self.write_u16(MODIFICATION_DATE);
+        self.write_u32(entry.crc);
+        self.write_u32(entry.size); // compressed size
+        self.write_u32(entry.size); // uncompressed size
+        self.write_u16(entry.name_length);
+        self.write_u16(0); // extra field length
+        self.buffer.extend_from_slice(entry.name.as_bytes());
+        self.buffer.extend_from_slice(data);
+
+        self.entries.push(entry);
+        Ok(())
+    }
+
+    /// Write the central directory and return the bytes of the archive
+    pub fn finish(mut self) -> Result<Vec<u8>, ZipError> {
+        let central_directory_offset = u32::try_from(self.buffer.len())
+            .ok()
+            .context(ArchiveTooLargeSnafu)?;
+        let entries = core::mem::take(&mut self.entries);
+        for entry in &entries {
+            self.write_u32(CENTRAL_DIRECTORY_HEADER_SIGNATURE);
+            self.write_u16(VERSION); // version made by
+            self.write_u16(VERSION); // version needed to extract
+            self.write_u16(UTF8_FLAG);
+            self.write_u16(0); // stored, without compression
+            self.write_u16(0); // modification time
+            self.write_u16(MODIFICATION_DATE);
+            self.write_u32(entry.crc);
+            self.write_u32(entry.size);
+            self.write_u32(entry.size);
+            self.write_u16(entry.name_length);
+            self.write_u16(0); // extra field length
+            self.write_u16(0); // file comment length
+            self.write_u16(0); // disk number
+            self.write_u16(0); // internal file attributes
+            self.write_u32(0); // external file attributes
+            self.write_u32(entry.offset);
+            self.buffer.extend_from_slice(entry.name.as_bytes());
+        }
+        let central_directory_size = u32::try_from(self.buffer.len())
+            .ok()
+            .context(ArchiveTooLargeSnafu)?
+            - central_directory_offset;
+        // The number of entries is limited when they're added
+        let entries_count = entries.len() as u16;
+
+        self.write_u32(END_OF_CENTRAL_DIRECTORY_SIGNATURE);
+        self.write_u16(0); // number of this disk
+        self.write_u16(0); // disk where the central directory starts
+        self.write_u16(entries_count); // entries on this disk
+        self.write_u16(entries_count); // total entries
+        self.write_u32(central_directory_size);
+        self.write_u32(central_directory_offset);
+        self.write_u16(0); // comment length
+
+        Ok(self.buffer)
+    }
+}
@@ -0,0 +1,91 @@
+//! Tests of the ZIP archives written
+//!
+//! The archives are read back with the `zip` crate to check that other
+//! implementations can extract them.
+
+use std::io::{Cursor, Read};
+use zip::{CompressionMethod, ZipArchive};
+use zip_writer::{ZipError, ZipWriter, crc32};
+
+/// Names and contents of the files of an archive read back
+fn read_archive(bytes: Vec<u8>) -> Vec<(String, Vec<u8>)> {
+    let mut archive = ZipArchive::new(Cursor::new(bytes)).unwrap();
+    (0..archive.len())
+        .map(|index| {
+            let mut file = archive.by_index(index).unwrap();
+            assert_eq!(file.compression(), CompressionMethod::Stored);
+            let mut content = vec![];
+            file.read_to_end(&mut content).unwrap();
+            assert_eq!(file.crc32(), crc32(&content));
+            (file.name().to_string(), content)
+        })
+        .collect()
+}
+
+#[test]
+fn crc32_check_value() {
+    assert_eq!(crc32(b"123456789"), 0xCBF4_3926);
+    assert_eq!(crc32(b""), 0);
+}
+
+#[test]
+fn files_are_read_back() {
+    let metadata = r#"{"title": "Zürich"}"#.as_bytes();
+    let mut zip = ZipWriter::new();
+    zip.add_file("github.svg", b"<svg/>").unwrap();
+    zip.add_file("icons/simpleicons.svg", b"<svg></svg>")
+        .unwrap();
+    zip.add_file("empty.txt", b"").unwrap();
+    zip.add_file("metadata.json", metadata).unwrap();
+
+    assert_eq!(
+        read_archive(zip.finish().unwrap()),
+        vec![
+            ("github.svg".to_string(), b"<svg/>".to_vec()),
+            ("icons/simpleicons.svg".to_string(), b"<svg></svg>".to_vec()),
+            ("empty.txt".to_string(), vec![]),
+            ("metadata.json".to_string(), metadata.to_vec()),
+        ],
+    );
+}
+
+#[test]
+fn names_are_utf8() {
+    let mut zip = ZipWriter::new();
+    zip.add_file("ñandú/大.txt", b"icon").unwrap();
+    assert_eq!(
+        read_archive(zip.finish().unwrap()),
+        vec![("ñandú/大.txt".to_string(), b"icon".to_vec())],
+    );
+}
+
+#[test]
+fn empty_archive() {
+    assert!(read_archive(ZipWriter::new().finish().unwrap()).is_empty());
+}
+
+#[test]
+fn names_longer_than_the_headers_are_rejected() {
+    let name = "a".repeat(usize::from(u16::MAX) + 1);
+    let mut zip = ZipWriter::new();
+    assert_eq!(
+        zip.add_file(&name, b""),
+        Err(ZipError::NameTooLong { name: name.clone() }),
+    );
+
+    // The longest name allowed is still written
+    zip.add_file(&name[1..], b"").unwrap();
+    assert_eq!(read_archive(zip.finish().unwrap())[0].0, name[1..]);
+}
+
+#[test]
+fn files_beyond_the_maximum_count_are_rejected() {
+    let mut zip = ZipWriter::new();
+    for index in 0..u16::MAX {
+        zip.add_file(&index.to_string(), b"").unwrap();
+    }
+    assert_eq!(zip.add_file("overflow", b""), Err(ZipError::TooManyFiles));
+
+    let archive = ZipArchive::new(Cursor::new(zip.finish().unwrap())).unwrap();
+    assert_eq!(archive.len(), usize::from(u16::MAX));
+}
@@ -0,0 +1,65 @@
+use anyhow::{Ok, Result};
+use cucumber::{then, when};
+use end2end_helpers::AppWorld;
+use thirtyfour::prelude::*;
+
+fn filter_xpath(filter: &str) -> String {
+    format!("//div[@class=\"filters\"]/button[text()=\"{filter}\"]")
+}
+
+#[when(regex = "I click on the \"([^\"]+)\" filter")]
+async fn click_filter(world: &mut AppWorld, filter: String) -> Result<()> {
+    let chip = world
+        .driver()
+        .find(By::XPath(filter_xpath(&filter)))
+        .await?;
+    chip.click().await?;
+    Ok(())
+}
+
+#[then(regex = "the \"([^\"]+)\" filter is (enabled|disabled)")]
+async fn filter_is_enabled(
+    world: &mut AppWorld,
+    filter: String,
+    state: String,
+) -> Result<()> {
+    let pressed = world
+        .driver()
+        .find(By::XPath(filter_xpath(&filter)))
+        .await?
+        .attr("aria-pressed")
+        .await?;
+    assert_eq!(
+        pressed.as_deref(),
+        Some((state == "enabled").to_string().as_str())
+    );
+    Ok(())
+}
+
+#[then(regex = "the URL param ([a-z-]+) is \"([^\"]*)\"")]
+async fn url_param_is(
+    world: &mut AppWorld,
+    param: String,
+    value: String,
+) -> Result<()> {
+    let url = world.driver().current_url().await?;
+    let param_value = url
+        .query_pairs()
+        .find(|(key, _)| *key == param)
+        .map(|(_, value)| value.to_string());
+    assert_eq!(param_value, Some(value));
+    Ok(())
+}
+
+#[then("all the icons of the grid are deprecated")]
+async fn all_grid_icons_are_deprecated(world: &mut AppWorld) -> Result<()> {
+    let icons = world
+        .driver()
+        .find_all(By::Css("main > ul > li:not(.grid-spacer)"))
+        .await?;
+    for icon in icons {
+        let deprecated = icon.find_all(By::Css(".deprecated")).await?;
+        assert!(!deprecated.is_empty());
+    }
+    Ok(())
+}
@@ -3,7 +3,7 @@ use cucumber::{then, when};
 use end2end_helpers::{AppWorld, capitalize};
 use thirtyfour::prelude::*;
 
-#[then(regex = "the selected layout is (comfortable|compact)")]
+#[then(regex = "the selected layout is (comfortable|compact|list)")]
 async fn check_layout(world: &mut AppWorld, layout: String) -> Result<()> {
     let grid = world.driver().find(By::Css("main > ul")).await?;
     let class = grid
@@ -17,6 +17,9 @@ async fn check_layout(world: &mut AppWorld, layout: String) -> Result<()> {
         assert!(
             class.contains("layout-compact") && !class.contains("comfortable")
         );
+    } else if layout == "list" {
+        assert!(class.contains("layout-list"));
+        world.driver().find(By::Css("main > .icons-table")).await?;
     } else {
         assert!(!class.contains("comfortable") && !class.contains("compact"));
     }
@@ -25,7 +28,7 @@ async fn check_layout(world: &mut AppWorld, layout: String) -> Result<()> {
 }
 
 #[when(
-    regex = "click on the (comfortable|compact) button of the layout control"
+    regex = "click on the (comfortable|compact|list) button of the layout control"
 )]
 async fn click_layout_button(
     world: &mut AppWorld,
@@ -37,3 +40,60 @@ async fn click_layout_button(
 
     Ok(())
 }
+
+#[when(
+    regex = "I click on the (Brand|Slug|Color|License) column header of the icons table"
+)]
+async fn click_icons_table_header(
+    world: &mut AppWorld,
+    column: String,
+) -> Result<()> {
+    let xpath = format!(
+        "//table[@class=\"icons-table\"]//th/button[text()=\"{column}\"]"
+    );
+    let button = world.driver().find(By::XPath(xpath)).await?;
+    button.click().await?;
+
+    Ok(())
+}
+
+#[then(
+    regex = "the icons table is sorted by (slug|color) in (ascending|descending) order"
+)]
+async fn check_icons_table_sort(
+    world: &mut AppWorld,
+    column: String,
+    order: String,
+) -> Result<()> {
+    let column_index = match column.as_str() {
+        "slug" => 3,
+        _ => 4,
+    };
+    let header = world
+        .driver()
+        .find(By::Css(format!(
+            "main > .icons-table th:nth-child({column_index})"
+        )))
+        .await?;
+    assert_eq!(header.attr("aria-sort").await?, Some(order.clone()));
+
+    let mut values = vec![];
+    for cell in world
+        .driver()
+        .find_all(By::Css(format!(
+            "main > .icons-table tbody td:nth-child({column_index})"
+        )))
+        .await?
+    {
+        values.push(cell.text().await?);
+    }
+    assert!(!values.is_empty());
+    let mut sorted_values = values.clone();
+    sorted_values.sort();
+    if order == "descending" {
+        sorted_values.reverse();
+    }
+    assert_eq!(values, sorted_values);
+
+    Ok(())
+}
@@ -1,3 +1,5 @@
 mod color_scheme;
+mod filters;
 mod layout;
+mod preview_mode;
 mod search;
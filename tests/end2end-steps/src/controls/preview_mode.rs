@@ -0,0 +1,39 @@
+use anyhow::{Ok, Result};
+use cucumber::{then, when};
+use end2end_helpers::AppWorld;
+use thirtyfour::prelude::*;
+
+#[when(
+    regex = r#"click on the "(Monochrome|On brand color|In brand color)" button of the preview mode control"#
+)]
+async fn click_preview_mode_button(
+    world: &mut AppWorld,
+    title: String,
+) -> Result<()> {
+    let xpath = format!(".//button[@title=\"{title}\"]");
+    let button = world.driver().find(By::XPath(xpath)).await?;
+    button.click().await?;
+    Ok(())
+}
+
+#[then(
+    regex = "the preview mode of the grid is (monochrome|brand-background|brand-color)"
+)]
+async fn check_preview_mode(
+    world: &mut AppWorld,
+    preview_mode: String,
+) -> Result<()> {
+    let previews = world
+        .driver()
+        .find_all(By::Css("main > ul > li:not(.grid-spacer) > :first-child"))
+        .await?;
+    assert!(!previews.is_empty());
+    for preview in previews {
+        let class = preview.attr("class").await?.unwrap_or_default();
+        match preview_mode.as_str() {
+            "monochrome" => assert!(!class.contains("preview-brand")),
+            _ => assert!(class.contains(&format!("preview-{preview_mode}"))),
+        }
+    }
+    Ok(())
+}
@@ -1,10 +1,9 @@
 use anyhow::{Ok, Result};
-use core::str::FromStr;
 use cucumber::{given, then, when};
 use end2end_helpers::{AppWorld, Waiter};
-use simple_icons_website_controls_layout_type::Layout;
-use std::time::Duration;
-use thirtyfour::prelude::*;
+use simple_icons_website_controls_layout_type::{GridDimensions, Layout};
+use std::{str::FromStr, time::Duration};
+use thirtyfour::{prelude::*, stringmatch::StringMatch};
 
 #[given("I see the grid")]
 async fn grid_is_displayed(world: &mut AppWorld) -> Result<()> {
@@ -15,7 +14,7 @@ async fn grid_is_displayed(world: &mut AppWorld) -> Result<()> {
 }
 
 #[then(
-    regex = "the (comfortable|compact) number of icons per page have been loaded"
+    regex = "the (comfortable|compact|list) number of icons per page have been loaded"
 )]
 async fn default_number_of_icons_per_page_loaded(
     world: &mut AppWorld,
@@ -25,27 +24,59 @@ async fn default_number_of_icons_per_page_loaded(
 }
 
 #[then(
-    regex = r"the (comfortable|compact) number of icons per page \* (\d+) have been loaded"
+    regex = r"the (comfortable|compact|list) number of icons per page \* (\d+) have been loaded"
 )]
 async fn number_of_icons_per_page_loaded(
     world: &mut AppWorld,
     layout: String,
     multiplicator: String,
 ) -> Result<()> {
-    let expected_number_of_icons =
-        Layout::from_str(&layout).unwrap().icons_per_page() as usize
-            * multiplicator.parse::<usize>()?;
+    let expected_pages = multiplicator.parse::<usize>()?;
+    let layout = Layout::from_str(&layout).unwrap();
 
     let condition = move || {
         let client = world.driver().clone();
         async move {
-            let elements = client.find_all(By::Css("main > ul > li")).await;
-            if let std::result::Result::Ok(elements) = elements {
-                return std::result::Result::Ok(
-                    elements.len() == expected_number_of_icons,
-                );
+            // Only the icons near the viewport are mounted, so the number
+            // of loaded icons and the dimensions of the grid, which depend
+            // on the viewport, are exposed by the grid
+            let Some(grid) = client.find(By::Css("main > ul")).await.ok()
+            else {
+                return std::result::Result::Ok(false);
+            };
+            let mut values = vec![];
+            for name in [
+                "data-loaded-icons",
+                "data-columns",
+                "data-ad-cells",
+                "data-viewport-height",
+            ] {
+                values.push(grid.attr(name).await.ok().flatten());
             }
-            std::result::Result::Ok(false)
+            let number = |index: usize| {
+                values[index]
+                    .as_ref()
+                    .and_then(|value| value.parse::<f64>().ok())
+            };
+            let (
+                Some(loaded_icons),
+                Some(columns),
+                Some(ad_cells),
+                Some(height),
+            ) = (number(0), number(1), number(2), number(3))
+            else {
+                return std::result::Result::Ok(false);
+            };
+
+            // The grid loads the pages one after the other
+            let dimensions = GridDimensions {
+                columns: columns as usize,
+                ad_cells: ad_cells as usize,
+                viewport_height: height,
+            };
+            let expected_icons = (0..expected_pages)
+                .fold(0, |loaded, _| layout.page_end(loaded, &dimensions));
+            std::result::Result::Ok(loaded_icons as usize == expected_icons)
         }
     };
 
@@ -53,7 +84,7 @@ async fn number_of_icons_per_page_loaded(
         Duration::from_secs(6),
         Duration::from_millis(50),
         format!(
-            "The expected number of icons ({expected_number_of_icons}) \
+            "The expected number of pages of icons ({expected_pages}) \
             have not been loaded in time",
         ),
     )
@@ -140,3 +171,458 @@ async fn load_more_icons_button_click(world: &mut AppWorld) -> Result<()> {
     button.click().await?;
     Ok(())
 }
+
+#[then(r#"I see the "No results" state"#)]
+async fn no_results_state_is_displayed(world: &mut AppWorld) -> Result<()> {
+    let no_results = world.driver().find(By::ClassName("no-results")).await?;
+    let is_displayed = no_results.is_displayed().await?;
+    assert!(is_displayed);
+    Ok(())
+}
+
+#[then(regex = "I see the suggestion \"([^\"]+)\"")]
+async fn suggestion_is_displayed(
+    world: &mut AppWorld,
+    title: String,
+) -> Result<()> {
+    let xpath =
+        format!("//div[@class=\"no-results\"]//button[text()=\"{title}\"]");
+    let suggestion = world.driver().find(By::XPath(&xpath)).await?;
+    let is_displayed = suggestion.is_displayed().await?;
+    assert!(is_displayed);
+    Ok(())
+}
+
+#[then(regex = "I don't see the suggestion \"([^\"]+)\"")]
+async fn suggestion_is_not_displayed(
+    world: &mut AppWorld,
+    title: String,
+) -> Result<()> {
+    let xpath =
+        format!("//div[@class=\"no-results\"]//button[text()=\"{title}\"]");
+    let suggestions = world.driver().find_all(By::XPath(&xpath)).await?;
+    assert!(suggestions.is_empty());
+    Ok(())
+}
+
+#[when(regex = "I click on the suggestion \"([^\"]+)\"")]
+async fn click_suggestion(world: &mut AppWorld, title: String) -> Result<()> {
+    let xpath =
+        format!("//div[@class=\"no-results\"]//button[text()=\"{title}\"]");
+    let suggestion = world.driver().find(By::XPath(&xpath)).await?;
+    suggestion.click().await?;
+    Ok(())
+}
+
+#[then(regex = "the icon request link has the title \"([^\"]+)\"")]
+async fn icon_request_link_has_title(
+    world: &mut AppWorld,
+    title: String,
+) -> Result<()> {
+    let href = world
+        .driver()
+        .find(By::Css(".no-results > a"))
+        .await?
+        .attr("href")
+        .await?
+        .unwrap_or_default();
+    assert!(href.contains("template=icon_request.yml"));
+    assert!(href.ends_with(&format!("&title={title}")));
+    Ok(())
+}
+
+#[then("not all the loaded icons are mounted in the grid")]
+async fn not_all_loaded_icons_are_mounted(world: &mut AppWorld) -> Result<()> {
+    let grid = world.driver().find(By::Css("main > ul")).await?;
+    let loaded_icons = grid
+        .attr("data-loaded-icons")
+        .await?
+        .unwrap_or_default()
+        .parse::<usize>()?;
+    let mounted_icons = world
+        .driver()
+        .find_all(By::Css(grid_icons_selector("")))
+        .await?
+        .len();
+    assert!(mounted_icons < loaded_icons);
+    Ok(())
+}
+
+/// Items of the grid and rows of the table of the list layout, excluding
+/// the spacers of the rows not mounted
+const GRID_ICONS_SELECTORS: [&str; 2] = [
+    "main > ul > li:not(.grid-spacer)",
+    "main > .icons-table > tbody > tr:not(.grid-spacer)",
+];
+
+/// CSS selector of the icons of the grid in any layout, followed by a
+/// suffix
+fn grid_icons_selector(suffix: &str) -> String {
+    GRID_ICONS_SELECTORS
+        .map(|selector| format!("{selector}{suffix}"))
+        .join(", ")
+}
+
+#[when("I focus the first icon of the grid")]
+async fn focus_first_grid_icon(world: &mut AppWorld) -> Result<()> {
+    let icon = world
+        .driver()
+        .find(By::Css(grid_icons_selector("[tabindex=\"0\"]")))
+        .await?;
+    icon.focus().await?;
+    Ok(())
+}
+
+#[when(
+    regex = r#"I press the "(ArrowRight|ArrowLeft|ArrowDown|ArrowUp|Enter|Shift\+D)" key"#
+)]
+async fn press_key(world: &mut AppWorld, key: String) -> Result<()> {
+    if key == "Shift+D" {
+        // Typed in the focused element, holding Shift
+        world
+            .driver()
+            .action_chain()
+            .key_down(Key::Shift)
+            .send_keys("D")
+            .key_up(Key::Shift)
+            .perform()
+            .await?;
+        return Ok(());
+    }
+    let key = match key.as_str() {
+        "ArrowRight" => Key::Right,
+        "ArrowLeft" => Key::Left,
+        "ArrowDown" => Key::Down,
+        "ArrowUp" => Key::Up,
+        _ => Key::Enter,
+    };
+    world
+        .driver()
+        .active_element()
+        .await?
+        .send_keys(key)
+        .await?;
+    Ok(())
+}
+
+#[then(regex = r"the icon number (\d+) of the grid is focused")]
+async fn grid_icon_is_focused(
+    world: &mut AppWorld,
+    number: String,
+) -> Result<()> {
+    let icons = world
+        .driver()
+        .find_all(By::Css(grid_icons_selector("")))
+        .await?;
+    let icon = &icons[number.parse::<usize>()? - 1];
+    let focused = world.driver().active_element().await?;
+    assert_eq!(icon.element_id(), focused.element_id());
+    Ok(())
+}
+
+#[then(regex = r"the focused icon of the grid is below the icon number (\d+)")]
+async fn focused_grid_icon_is_below(
+    world: &mut AppWorld,
+    number: String,
+) -> Result<()> {
+    let icons = world
+        .driver()
+        .find_all(By::Css(grid_icons_selector("")))
+        .await?;
+    let icon_rect = icons[number.parse::<usize>()? - 1].rect().await?;
+    let focused_rect = world.driver().active_element().await?.rect().await?;
+    assert_eq!(icon_rect.x, focused_rect.x);
+    assert!(focused_rect.y > icon_rect.y);
+    Ok(())
+}
+
+#[then("only the focused icon of the grid is reachable with the Tab key")]
+async fn only_focused_grid_icon_is_tabbable(
+    world: &mut AppWorld,
+) -> Result<()> {
+    let tabbable_icons = world
+        .driver()
+        .find_all(By::Css(grid_icons_selector("[tabindex=\"0\"]")))
+        .await?;
+    assert_eq!(tabbable_icons.len(), 1);
+    let focused = world.driver().active_element().await?;
+    assert_eq!(tabbable_icons[0].element_id(), focused.element_id());
+    Ok(())
+}
+
+#[then("the details modal of the icon is open")]
+async fn icon_details_modal_is_open(world: &mut AppWorld) -> Result<()> {
+    let url = world.driver().current_url().await?;
+    assert!(url.query_pairs().any(|(key, _)| key == "icon"));
+    Ok(())
+}
+
+#[then(regex = r#"the details modal of the icon "([^"]+)" is open"#)]
+async fn icon_details_modal_of_slug_is_open(
+    world: &mut AppWorld,
+    slug: String,
+) -> Result<()> {
+    let url = world.driver().current_url().await?;
+    let icon_param = url
+        .query_pairs()
+        .find(|(key, _)| key == "icon")
+        .map(|(_, value)| value.to_string());
+    assert_eq!(icon_param, Some(slug.clone()));
+
+    let modal_slug = world
+        .driver()
+        .find(By::Css("#icon-details-modal h3"))
+        .await?
+        .text()
+        .await?;
+    assert_eq!(modal_slug, slug);
+    Ok(())
+}
+
+#[when("I close the details modal of the icon")]
+async fn close_icon_details_modal(world: &mut AppWorld) -> Result<()> {
+    world
+        .driver()
+        .find(By::XPath(
+            "//div[@class=\"modal\"][.//*[@id=\"icon-details-modal\"]]\
+            //button[@title=\"Close\"]",
+        ))
+        .await?
+        .click()
+        .await?;
+    Ok(())
+}
+
+#[then("no details modal of icons is open")]
+async fn no_icon_details_modal_is_open(world: &mut AppWorld) -> Result<()> {
+    let url = world.driver().current_url().await?;
+    assert!(!url.query_pairs().any(|(key, _)| key == "icon"));
+    Ok(())
+}
+
+#[when(
+    regex = r#"I open the "(Details|Snippets|Export)" tab of the details modal"#
+)]
+async fn open_icon_details_modal_tab(
+    world: &mut AppWorld,
+    tab: String,
+) -> Result<()> {
+    let xpath = format!(
+        "//*[@id=\"icon-details-modal\"]/div[@role=\"tablist\"]\
+        /button[text()=\"{tab}\"]"
+    );
+    world.driver().find(By::XPath(xpath)).await?.click().await?;
+    Ok(())
+}
+
+#[when(
+    regex = r#"I select "([^"]+)" as the "(Size|Fill|Background)" export option"#
+)]
+async fn select_export_option(
+    world: &mut AppWorld,
+    value: String,
+    option: String,
+) -> Result<()> {
+    let xpath = format!(
+        "//div[@class=\"export-options\"]/label[span[text()=\"{option}\"]]\
+        /select/option[text()=\"{value}\"]"
+    );
+    world.driver().find(By::XPath(xpath)).await?.click().await?;
+    Ok(())
+}
+
+#[then(regex = r#"the export preview is filled with #([0-9A-F]{6})"#)]
+async fn export_preview_is_filled_with(
+    world: &mut AppWorld,
+    hex: String,
+) -> Result<()> {
+    world
+        .driver()
+        .query(By::Css("#icon-details-modal > .export > img"))
+        .first()
+        .await?
+        .wait_until()
+        .has_attribute(
+            "src",
+            StringMatch::new(format!("fill%3D%22%23{hex}%22")).partial(),
+        )
+        .await?;
+    Ok(())
+}
+
+#[then(
+    regex = r#"the "([^"]+)" snippet of the details modal contains "([^"]+)""#
+)]
+async fn icon_details_modal_snippet_contains(
+    world: &mut AppWorld,
+    snippet: String,
+    expected: String,
+) -> Result<()> {
+    let xpath = format!(
+        "//*[@id=\"icon-details-modal\"]/ul[@class=\"snippets\"]\
+        /li[h4[text()=\"{snippet}\"]]//code"
+    );
+    let code = world
+        .driver()
+        .query(By::XPath(xpath))
+        .first()
+        .await?
+        .text()
+        .await?;
+    assert!(code.contains(&expected), "{expected} not found in {code}");
+    Ok(())
+}
+
+#[then(
+    regex = r#"the details modal of the icon lists "([^"]+)" under "(Also known as|Old names|Localized names|Brands sharing the icon)""#
+)]
+async fn icon_details_modal_lists_alias(
+    world: &mut AppWorld,
+    alias: String,
+    section: String,
+) -> Result<()> {
+    let xpath = format!(
+        "//*[@id=\"icon-details-modal\"]//div[@class=\"aliases\"]\
+        /h4[text()=\"{section}\"]/following-sibling::ul[1]/li"
+    );
+    let items = world.driver().find_all(By::XPath(xpath)).await?;
+    let mut texts = vec![];
+    for item in items {
+        texts.push(item.text().await?);
+    }
+    assert!(
+        texts.iter().any(|text| text.starts_with(&alias)),
+        "{alias} not found in {texts:?}"
+    );
+    Ok(())
+}
+
+#[when(
+    regex = r#"I click on the "(Select icons|Select all results|Clear selection|Download SVG sprite)" button"#
+)]
+async fn click_selection_bar_button(
+    world: &mut AppWorld,
+    button: String,
+) -> Result<()> {
+    let xpath =
+        format!("//div[@class=\"selection-bar\"]/button[text()=\"{button}\"]");
+    world
+        .driver()
+        .find(By::XPath(&xpath))
+        .await?
+        .click()
+        .await?;
+    Ok(())
+}
+
+#[then(regex = r#"the "Download SVG sprite" button is (enabled|disabled)"#)]
+async fn sprite_button_state(
+    world: &mut AppWorld,
+    state: String,
+) -> Result<()> {
+    let xpath = "//div[@class=\"selection-bar\"]/button[text()=\"Download SVG sprite\"]";
+    let is_enabled = world
+        .driver()
+        .find(By::XPath(xpath))
+        .await?
+        .is_enabled()
+        .await?;
+    assert_eq!(is_enabled, state == "enabled");
+    Ok(())
+}
+
+#[when(regex = r"I check the icon number (\d+) of the grid( holding Shift)?")]
+async fn check_grid_icon(
+    world: &mut AppWorld,
+    number: String,
+    shift: String,
+) -> Result<()> {
+    let checkboxes = world
+        .driver()
+        .find_all(By::Css(grid_icons_selector(" .selection-checkbox")))
+        .await?;
+    let checkbox = &checkboxes[number.parse::<usize>()? - 1];
+    match shift.is_empty() {
+        true => checkbox.click().await?,
+        false => {
+            world
+                .driver()
+                .action_chain()
+                .key_down(Key::Shift)
+                .click_element(checkbox)
+                .key_up(Key::Shift)
+                .perform()
+                .await?
+        }
+    }
+    Ok(())
+}
+
+#[then(regex = r"(\d+) icons are selected")]
+async fn number_of_icons_selected(
+    world: &mut AppWorld,
+    number: String,
+) -> Result<()> {
+    let text = world
+        .driver()
+        .find(By::Css(".selection-bar > span"))
+        .await?
+        .text()
+        .await?;
+    assert_eq!(text, format!("{number} selected"));
+    Ok(())
+}
+
+#[when(
+    regex = r"I click on the favorite button of the icon number (\d+) of the grid"
+)]
+async fn click_favorite_button(
+    world: &mut AppWorld,
+    number: String,
+) -> Result<()> {
+    let buttons = world
+        .driver()
+        .find_all(By::Css(grid_icons_selector(" .favorite-button")))
+        .await?;
+    buttons[number.parse::<usize>()? - 1].click().await?;
+    Ok(())
+}
+
+#[then(regex = r"the icon number (\d+) of the grid is a favorite")]
+async fn grid_icon_is_favorite(
+    world: &mut AppWorld,
+    number: String,
+) -> Result<()> {
+    let buttons = world
+        .driver()
+        .find_all(By::Css(grid_icons_selector(" .favorite-button")))
+        .await?;
+    let pressed = buttons[number.parse::<usize>()? - 1]
+        .attr("aria-pressed")
+        .await?;
+    assert_eq!(pressed.as_deref(), Some("true"));
+    Ok(())
+}
+
+#[then(regex = r"the grid has (\d+) icons")]
+async fn grid_has_number_of_icons(
+    world: &mut AppWorld,
+    number: String,
+) -> Result<()> {
+    let grid = world.driver().find(By::Css("main > ul")).await?;
+    let loaded_icons = grid.attr("data-loaded-icons").await?;
+    assert_eq!(loaded_icons, Some(number));
+    Ok(())
+}
+
+#[then(regex = "I see the collection bar with the text \"([^\"]+)\"")]
+async fn collection_bar_has_text(
+    world: &mut AppWorld,
+    text: String,
+) -> Result<()> {
+    let xpath =
+        format!("//div[@class=\"collection-bar\"]/p[text()=\"{text}\"]");
+    let paragraph = world.driver().find(By::XPath(&xpath)).await?;
+    assert!(paragraph.is_displayed().await?);
+    Ok(())
+}
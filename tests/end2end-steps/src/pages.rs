@@ -4,7 +4,7 @@ use end2end_helpers::AppWorld;
 use thirtyfour::prelude::*;
 
 #[given(
-    regex = r"I see the (index|preview generator) page(?:\s+with the url params\s+(.+))?"
+    regex = r"I see the (index|favorites|preview generator) page(?:\s+with the url params\s+(.+))?"
 )]
 async fn open_a_page(
     world: &mut AppWorld,
@@ -13,6 +13,7 @@ async fn open_a_page(
 ) -> Result<()> {
     let (path, selector) = match page.as_str() {
         "index" => ("", "header"),
+        "favorites" => ("/favorites/", "header"),
         _ => ("/preview", ".preview"),
     };
 
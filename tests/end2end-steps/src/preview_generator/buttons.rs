@@ -154,3 +154,18 @@ async fn click_element_and_check_event_is_executed_on_element(
     );
     Ok(())
 }
+
+#[when(regex = "I click on the (undo|redo) button")]
+async fn click_history_button(
+    world: &mut AppWorld,
+    button: String,
+) -> Result<()> {
+    let button = world
+        .driver()
+        .find(By::Id(format!("preview-{button}-button")))
+        .await?;
+    // Changes are recorded in the history after a short delay
+    button.wait_until().enabled().await?;
+    button.click().await?;
+    Ok(())
+}
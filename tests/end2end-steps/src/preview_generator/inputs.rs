@@ -70,13 +70,15 @@ async fn check_input_value(
     Ok(())
 }
 
-#[when(regex = r#"I type "([^"]+)" in the (brand|color|path) input"#)]
+#[when(
+    regex = r#"I type "([^"]+)" in the (brand|color|path|badge label|badge message) input"#
+)]
 async fn type_in_input(
     world: &mut AppWorld,
     value: String,
     input: String,
 ) -> Result<()> {
-    let id = format!("preview-{input}");
+    let id = format!("preview-{}", input.replace(' ', "-"));
     let color_input = world.driver().find(By::Id(id)).await?;
     color_input.clear().await?;
     color_input.send_keys(value).await?;
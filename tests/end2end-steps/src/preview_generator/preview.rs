@@ -109,7 +109,7 @@ async fn check_preview_badges_color(
         .with_filter(move |element: thirtyfour::WebElement| {
             let expected_color = color.clone();
             async move {
-                // flat + plastic + for the badge
+                // flat + flat square + plastic + for the badge
                 let background_badges =
                     element.find_all(By::Css("g > svg > g > rect")).await;
                 let mut colors = vec![];
@@ -159,7 +159,7 @@ async fn check_preview_badges_color(
                 }
 
                 std::result::Result::Ok(
-                    colors.len() == 8
+                    colors.len() == 10
                         && colors.iter().all(|c| c == &expected_color),
                 )
             }
@@ -219,7 +219,7 @@ async fn check_preview_badges_logo(
                         }
                     }
 
-                    let result = paths.len() == 8
+                    let result = paths.len() == 10
                         && paths
                             .iter()
                             .all(|d| predicate_fn(d, &expected_svg_path));
@@ -234,3 +234,36 @@ async fn check_preview_badges_logo(
     assert!(found);
     Ok(())
 }
+
+#[then(regex = r#"the badges in the preview contain the text "([^"]+)""#)]
+async fn check_preview_badges_text(
+    world: &mut AppWorld,
+    text: String,
+) -> Result<()> {
+    let selector = ".preview-figure > svg > g:nth-child(7)";
+    world
+        .driver()
+        .query(By::Css(selector))
+        .first()
+        .await?
+        .wait_until()
+        .condition(element_has_inner_html(StringMatch::new(&text).partial()))
+        .await?;
+    Ok(())
+}
+
+#[then(regex = r#"the path comparison report contains "([^"]+)""#)]
+async fn check_path_comparison_report(
+    world: &mut AppWorld,
+    text: String,
+) -> Result<()> {
+    world
+        .driver()
+        .query(By::Css(".preview-comparison-report"))
+        .first()
+        .await?
+        .wait_until()
+        .condition(element_has_inner_html(StringMatch::new(&text).partial()))
+        .await?;
+    Ok(())
+}
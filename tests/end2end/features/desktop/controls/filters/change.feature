@@ -0,0 +1,23 @@
+Feature: Filter icons by their metadata
+	Ensures that the filter chips narrow the icons of the grid, are
+	reflected in the URL and can be combined with the search.
+
+	Background:
+		Given I see the index page
+
+	Scenario: Filter deprecated icons
+		When I click on the "Deprecated" filter
+		Then the "Deprecated" filter is enabled
+		And the URL param filters is "deprecated"
+		And all the icons of the grid are deprecated
+		When I click on the "Deprecated" filter
+		Then the "Deprecated" filter is disabled
+
+	Scenario: Combine filters with the search
+		When I type "simpleicons" in the search input
+		And I click on the "Deprecated" filter
+		Then the grid has 0 icons
+		And I see the "No results" state
+		And I don't see the suggestion "Simple Icons"
+		When I click on the "Clear filters" filter
+		Then I see the icon "Simple Icons" first
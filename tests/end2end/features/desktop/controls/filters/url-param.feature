@@ -0,0 +1,9 @@
+Feature: Filters via URL parameter
+	Ensures that the filters passed in the URL are enabled on load.
+
+	Scenario: Load filters via URL parameter
+		Given I see the index page with the url params filters=deprecated,guidelines
+		Then the "Deprecated" filter is enabled
+		And the "Has brand guidelines" filter is enabled
+		And the "Has license" filter is disabled
+		And all the icons of the grid are deprecated
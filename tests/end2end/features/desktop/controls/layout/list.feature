@@ -0,0 +1,41 @@
+Feature: List layout
+	Ensures that the list layout displays the icons in a table
+	that can be sorted by its columns.
+
+	Background:
+		Given I see the index page
+
+	Scenario: Change to the list layout and sort the table
+		When click on the list button of the layout control
+		Then the selected layout is list
+		And the list number of icons per page have been loaded
+		When I click on the Slug column header of the icons table
+		Then the icons table is sorted by slug in ascending order
+		When I click on the Slug column header of the icons table
+		Then the icons table is sorted by slug in descending order
+		When I click on the Color column header of the icons table
+		Then the icons table is sorted by color in ascending order
+
+	Scenario: Select and favorite icons in the list layout
+		Given I see the index page with the url params layout=list
+		And I see the grid
+		When I click on the "Select icons" button
+		And I check the icon number 1 of the grid
+		And I check the icon number 3 of the grid
+		Then 2 icons are selected
+		When I click on the favorite button of the icon number 2 of the grid
+		Then the icon number 2 of the grid is a favorite
+
+	Scenario: Unmount the rows of the table far from the viewport
+		Given I see the index page with the url params layout=list
+		And I see the grid
+		When I scroll to the footer
+		And I scroll to the footer
+		And I scroll to the footer
+		Then not all the loaded icons are mounted in the grid
+
+	Scenario: Load the list layout via URL parameter
+		Given I see the index page with the url params layout=list
+		Then the selected layout is list
+		When click on the comfortable button of the layout control
+		Then the selected layout is comfortable
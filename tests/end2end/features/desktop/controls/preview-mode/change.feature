@@ -0,0 +1,18 @@
+Feature: Change preview mode
+	Ensures that the icons are previewed in monochrome by default
+	and changes the preview mode by clicking on the buttons of the
+	preview mode control.
+
+	Background:
+		Given I see the index page
+
+	Scenario: Change the preview mode
+		Then the preview mode of the grid is monochrome
+		When click on the "On brand color" button of the preview mode control
+		Then the preview mode of the grid is brand-background
+		And the URL param preview is "brand-background"
+		When click on the "In brand color" button of the preview mode control
+		Then the preview mode of the grid is brand-color
+		And the URL param preview is "brand-color"
+		When click on the "Monochrome" button of the preview mode control
+		Then the preview mode of the grid is monochrome
@@ -0,0 +1,12 @@
+Feature: Preview mode via URL parameter
+	As a user
+	I want to load a specific preview mode via URL parameter
+	So that I can share the grid with the icons in their brand colors
+
+	Background:
+		Given I see the index page with the url params preview=brand-color
+
+	Scenario: Load the brand color preview mode via URL parameter
+		Then the preview mode of the grid is brand-color
+		When click on the "Monochrome" button of the preview mode control
+		Then the preview mode of the grid is monochrome
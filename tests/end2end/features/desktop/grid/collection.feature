@@ -0,0 +1,20 @@
+Feature: Display collections of icons shared by URL
+	Ensures that only the icons of a collection encoded in the URL are
+	displayed and that invalid collections are handled gracefully.
+
+	Scenario: Display the icons of a collection
+		Given I see the index page with the url params collection=AAE
+		And I see the grid
+		Then the grid has 2 icons
+		And I see the collection bar with the text "Collection of 2 icons"
+
+	Scenario: Report the icons of a collection that do not exist
+		Given I see the index page with the url params collection=AAH__wM
+		And I see the grid
+		Then the grid has 2 icons
+		And I see the collection bar with the text "1 icons of the collection were not found"
+
+	Scenario: Display all the icons for an invalid collection
+		Given I see the index page with the url params collection=not*valid
+		And I see the grid
+		Then I see the collection bar with the text "The collection link is not valid, showing all the icons."
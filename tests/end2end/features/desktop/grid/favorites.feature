@@ -0,0 +1,16 @@
+Feature: Mark icons as favorites
+	Ensures that the icons marked with the star button are persisted
+	and listed in the favorites page.
+
+	Scenario: Favorite icons are listed in the favorites page
+		Given I see the index page
+		And I see the grid
+		When I click on the favorite button of the icon number 1 of the grid
+		And I click on the favorite button of the icon number 2 of the grid
+		Then the icon number 1 of the grid is a favorite
+		Given I see the favorites page
+		And I see the grid
+		Then the grid has 2 icons
+		When I click on the favorite button of the icon number 1 of the grid
+		Given I see the favorites page
+		Then the grid has 1 icons
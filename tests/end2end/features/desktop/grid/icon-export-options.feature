@@ -0,0 +1,21 @@
+Feature: Export options of the icons
+	As a user
+	I want to choose how the icons are exported
+	So that I get images ready to use in my project
+
+	Scenario: Preview the icon exported in its brand color
+		Given I see the index page with the url params icon=github
+		Then the details modal of the icon "github" is open
+		When I open the "Export" tab of the details modal
+		And I select "Brand color" as the "Fill" export option
+		Then the export preview is filled with #181717
+
+	Scenario: Export options are remembered
+		Given I see the index page with the url params icon=github
+		When I open the "Export" tab of the details modal
+		And I select "White" as the "Fill" export option
+		And I close the details modal of the icon
+		And I focus the first icon of the grid
+		And I press the "Enter" key
+		And I open the "Export" tab of the details modal
+		Then the export preview is filled with #FFFFFF
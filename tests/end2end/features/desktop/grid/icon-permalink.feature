@@ -0,0 +1,19 @@
+Feature: Permalinks to the details of icons
+	As a user
+	I want to share a link to the details of an icon
+	So that other people see exactly that icon
+
+	Scenario: Open the details modal of an icon via URL parameter
+		Given I see the index page with the url params icon=github
+		Then the details modal of the icon "github" is open
+		When I close the details modal of the icon
+		Then no details modal of icons is open
+
+	Scenario: Open the details modal of an icon that is not the first search result
+		Given I see the index page with the url params q=git&icon=gitlab
+		Then the details modal of the icon "gitlab" is open
+
+	Scenario: Show the aliases of an icon in its details modal
+		Given I see the index page with the url params icon=x
+		Then the details modal of the icon "x" is open
+		And the details modal of the icon lists "Twitter" under "Old names"
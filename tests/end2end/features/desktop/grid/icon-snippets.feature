@@ -0,0 +1,13 @@
+Feature: Code snippets of the icons
+	As a developer
+	I want to copy code to integrate an icon
+	So that I can use it in my project
+
+	Scenario: Show the code snippets of an icon
+		Given I see the index page with the url params icon=github
+		Then the details modal of the icon "github" is open
+		When I open the "Snippets" tab of the details modal
+		Then the "Markdown" snippet of the details modal contains "![GitHub](https://cdn.jsdelivr.net/npm/simple-icons@"
+		And the "LaTeX" snippet of the details modal contains "\simpleicon{github}"
+		And the "JSX" snippet of the details modal contains "const SiGithub = (props) =>"
+		And the "CSS mask-image" snippet of the details modal contains "background-color: #181717;"
@@ -0,0 +1,40 @@
+Feature: Navigate the grid with the keyboard
+	Ensures that the icons of the grid can be focused with the arrow
+	keys and that the focused icon can be opened and downloaded with the
+	keyboard.
+
+	Scenario Outline: Move the focus with the arrow keys
+		Given I see the index page with the url params layout=<layout>
+		And I see the grid
+		When I focus the first icon of the grid
+		Then only the focused icon of the grid is reachable with the Tab key
+		When I press the "ArrowRight" key
+		Then the icon number 2 of the grid is focused
+		When I press the "ArrowLeft" key
+		Then the icon number 1 of the grid is focused
+		When I press the "ArrowDown" key
+		Then the focused icon of the grid is below the icon number 1
+		When I press the "ArrowUp" key
+		Then the icon number 1 of the grid is focused
+		And only the focused icon of the grid is reachable with the Tab key
+
+		Examples:
+			| layout      |
+			| comfortable |
+			| compact     |
+			| list        |
+
+	Scenario: Open the details of the focused icon with Enter
+		Given I see the index page
+		And I see the grid
+		When I focus the first icon of the grid
+		And I press the "Enter" key
+		Then the details modal of the icon is open
+
+	Scenario: Download the focused icon with Shift+D
+		Given I see the index page
+		And I see the grid
+		When I type "simpleicons" in the search input
+		And I focus the first icon of the grid
+		And I press the "Shift+D" key
+		Then a file named "simpleicons.svg" is downloaded within 3 seconds
@@ -0,0 +1,19 @@
+Feature: Grid shows an empty state when no icons are found
+	Ensures that the app suggests similar icons and links to the
+	new icon request form when a search does not match any icon.
+
+	Background:
+		Given I see the index page
+		And I see the grid
+
+	Scenario: Suggest similar icons for a misspelled search
+		When I type "simpleicnos" in the search input
+		Then I see the "No results" state
+		And I see the suggestion "Simple Icons"
+		When I click on the suggestion "Simple Icons"
+		Then I see the icon "Simple Icons" first
+
+	Scenario: Link to the new icon request form with the brand name
+		When I type "qwzxkjvbrand" in the search input
+		Then I see the "No results" state
+		And the icon request link has the title "qwzxkjvbrand"
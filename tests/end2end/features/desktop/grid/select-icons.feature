@@ -0,0 +1,39 @@
+Feature: Select icons in the grid
+	Ensures that several icons can be selected in the grid to be
+	downloaded at once.
+
+	Background:
+		Given I see the index page
+		And I see the grid
+		When I click on the "Select icons" button
+
+	Scenario: Select icons one by one and clear the selection
+		When I check the icon number 1 of the grid
+		And I check the icon number 3 of the grid
+		Then 2 icons are selected
+		When I click on the "Clear selection" button
+		Then 0 icons are selected
+
+	Scenario: Select a range of icons with Shift
+		When I check the icon number 1 of the grid
+		And I check the icon number 4 of the grid holding Shift
+		Then 4 icons are selected
+
+	Scenario: Select all the icons of the results
+		When I type "simpleicons" in the search input
+		And I click on the "Select all results" button
+		Then 1 icons are selected
+
+	Scenario: Only download a SVG sprite of some icons
+		Then the "Download SVG sprite" button is disabled
+		When I check the icon number 1 of the grid
+		Then the "Download SVG sprite" button is enabled
+		When I click on the "Clear selection" button
+		And I type "simpleicons" in the search input
+		Then the "Download SVG sprite" button is enabled
+
+	Scenario: Download the selected icons as a SVG sprite
+		When I type "simpleicons" in the search input
+		And I click on the "Select all results" button
+		And I click on the "Download SVG sprite" button
+		Then a file named "simple-icons-sprite.zip" is downloaded within 3 seconds
@@ -0,0 +1,15 @@
+Feature: Grid only mounts the icons near the viewport
+	Ensures that the app keeps in the DOM only the rows of icons
+	visible in the viewport plus a buffer of rows.
+
+	Background:
+		Given I see the index page
+		And I see the grid
+
+	Scenario: Unmount the rows of icons far from the viewport
+		When I scroll to the footer
+		And I scroll to the footer
+		And I scroll to the footer
+		And I scroll to the footer
+		Then the comfortable number of icons per page * 5 have been loaded
+		And not all the loaded icons are mounted in the grid
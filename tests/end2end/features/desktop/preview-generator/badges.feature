@@ -0,0 +1,15 @@
+Feature: Badges of the preview
+	As a user
+	I want to customize the badges of the preview
+	So that I can see how the icon renders in every shields.io style
+
+	Background:
+		Given I see the preview generator page
+		Then the brand input value is "Simple Icons"
+
+	Scenario: Change label and message of the badges
+		When I type "build" in the badge label input
+		And I type "passing" in the badge message input
+		Then the badges in the preview contain the text "build"
+		Then the badges in the preview contain the text "passing"
+		Then the color of the badges in the preview is #111111
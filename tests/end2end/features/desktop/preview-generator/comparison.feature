@@ -0,0 +1,16 @@
+Feature: Comparison with the current icon
+	As a reviewer
+	I want to compare the edited path with the current icon of the brand
+	So that I can see what changed in the icon
+
+	Scenario: Report the bounding box of the edited path
+		Given I see the preview generator page with the url params compare=overlay
+		Then the brand input value is "Simple Icons"
+		When I type "M0 0h24v24H0z" in the path input
+		Then the path comparison report contains "[0, 0, 24, 24]"
+
+	Scenario: Compare side by side
+		Given I see the preview generator page with the url params compare=side-by-side
+		Then the brand input value is "Simple Icons"
+		When I type "M0 0h24v24H0z" in the path input
+		Then the path comparison report contains "→ 13 bytes"
@@ -0,0 +1,35 @@
+Feature: History of the preview
+	As a user
+	I want to undo and redo the changes of the preview
+	So that I can go back after overwriting the brand, the color or the path
+
+	Background:
+		Given I see the preview generator page
+		Then the brand input value is "Simple Icons"
+		Then the path input value starts with "M12 0"
+
+	Scenario: Undo and redo a change of the path
+		When I type "M0 0h24v24H0z" in the path input
+		And I click on the undo button
+		Then the path input value starts with "M12 0"
+		Then the SVG paths of the preview start with "M12 0"
+		When I click on the redo button
+		Then the path input value is "M0 0h24v24H0z"
+
+	Scenario: Undo a change of the color
+		When I type "FF0000" in the color input
+		And I click on the undo button
+		Then the color input value is "111111"
+
+	Scenario: Redo a change undone right after making it
+		When I type "FF0000" in the color input
+		And I click on the undo button
+		Then the color input value is "111111"
+		When I click on the redo button
+		Then the color input value is "FF0000"
+
+	Scenario: Click "Undo" button by pressing Ctrl + Z keyboard shortcut
+		When I type "M0 0h24v24H0z" in the path input
+		And I click on the undo button
+		And I click on the redo button
+		And I press the "Ctrl" + "Z" keys, the event "onclick" is executed on the element "#preview-undo-button"
@@ -0,0 +1,16 @@
+Feature: Preview templates
+	As a user
+	I want to select the template of the preview card
+	So that I can share the preview in different places
+
+	Scenario: Save preview of the template in the URL parameter
+		Given I see the preview generator page with the url params template=open-graph
+		Then the brand input value is "Simple Icons"
+		When I click on the element "#preview-save-button"
+		Then a file named "simpleicons-open-graph.png" is downloaded within 3 seconds
+
+	Scenario: Pull request card is the default template
+		Given I see the preview generator page
+		Then the brand input value is "Simple Icons"
+		Then the title in the preview is "Simple Icons Preview"
+		Then the color of the badges in the preview is #111111